@@ -0,0 +1,4 @@
+ALTER TABLE game_info ADD COLUMN allspins        INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE game_info ADD COLUMN allspin_singles INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE game_info ADD COLUMN allspin_doubles INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE game_info ADD COLUMN allspin_triples INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1 @@
+ALTER TABLE game_info ADD COLUMN perfect_clears INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+ALTER TABLE game_info ADD COLUMN max_back_to_back INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE game_info ADD COLUMN max_combo        INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,4 @@
+ALTER TABLE classic ADD COLUMN time_endured_millis INTEGER NOT NULL DEFAULT 0;
+UPDATE classic SET time_endured_millis = time_endured * 1000;
+ALTER TABLE lines ADD COLUMN time_endured_millis INTEGER NOT NULL DEFAULT 0;
+UPDATE lines SET time_endured_millis = time_endured * 1000;
@@ -0,0 +1,9 @@
+CREATE TABLE saved_games (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    saved_at            INTEGER NOT NULL,
+    game_type           TEXT NOT NULL,
+    points              INTEGER NOT NULL,
+    level               INTEGER NOT NULL,
+    time_endured_millis INTEGER NOT NULL,
+    snapshot            TEXT NOT NULL
+);
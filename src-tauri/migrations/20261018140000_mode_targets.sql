@@ -0,0 +1,2 @@
+ALTER TABLE lines ADD COLUMN lines_goal    INTEGER NOT NULL DEFAULT 40;
+ALTER TABLE blitz ADD COLUMN duration_secs INTEGER NOT NULL DEFAULT 120;
@@ -0,0 +1,11 @@
+CREATE TABLE cheese (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    time_endured_millis INTEGER NOT NULL,
+    garbage_goal        INTEGER NOT NULL,
+    height              INTEGER NOT NULL,
+    messiness           INTEGER NOT NULL,
+    garbage_cleared     INTEGER NOT NULL,
+    pieces_used         INTEGER NOT NULL,
+    game_info_id INTEGER NOT NULL,
+    FOREIGN KEY (game_info_id) REFERENCES game_info(id) ON UPDATE CASCADE ON DELETE CASCADE
+);
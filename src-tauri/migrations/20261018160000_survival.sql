@@ -0,0 +1,7 @@
+CREATE TABLE survival (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    time_endured_millis INTEGER NOT NULL,
+    lines_sent          INTEGER NOT NULL,
+    game_info_id INTEGER NOT NULL,
+    FOREIGN KEY (game_info_id) REFERENCES game_info(id) ON UPDATE CASCADE ON DELETE CASCADE
+);
@@ -0,0 +1,17 @@
+CREATE TABLE master (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    time_endured_millis INTEGER NOT NULL,
+    level_reached       INTEGER NOT NULL,
+    grade               TEXT    NOT NULL,
+    points              INTEGER NOT NULL,
+    game_info_id INTEGER NOT NULL,
+    FOREIGN KEY (game_info_id) REFERENCES game_info(id) ON UPDATE CASCADE ON DELETE CASCADE
+);
+
+CREATE TABLE master_sections (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    section     INTEGER NOT NULL,
+    time_millis INTEGER NOT NULL,
+    master_id INTEGER NOT NULL,
+    FOREIGN KEY (master_id) REFERENCES master(id) ON UPDATE CASCADE ON DELETE CASCADE
+);
@@ -0,0 +1 @@
+ALTER TABLE classic ADD COLUMN won INTEGER NOT NULL DEFAULT 0;
@@ -11,8 +11,11 @@ use crate::game::{
     queue::local_queue::LocalQueue,
     strategy::Strategy,
 };
-use crate::models::game_commands::{FirstLevelCommands, SecondLevelCommands};
+use crate::models::game_commands::{FirstLevelCommands, HeldKey, SecondLevelCommands};
 use crate::models::game_options::GameOptions;
+use crate::models::saved_game::SavedGameSummary;
+use crate::persistence::replays::{retreive_replay, retreive_replay_names};
+use crate::persistence::saved_games::{retreive_saved_games, take_saved_game};
 
 pub static FIRST_LEVEL_CHANNEL: OnceCell<Arc<Mutex<Sender<FirstLevelCommands>>>> =
     OnceCell::const_new();
@@ -72,6 +75,28 @@ pub async fn hard_drop() {
     }
 }
 
+#[tauri::command]
+pub async fn key_pressed(key: HeldKey) {
+    if let Some(channel) = FIRST_LEVEL_CHANNEL.get() {
+        let _ = channel
+            .lock()
+            .await
+            .send(FirstLevelCommands::KeyPressed(key))
+            .await;
+    }
+}
+
+#[tauri::command]
+pub async fn key_released(key: HeldKey) {
+    if let Some(channel) = FIRST_LEVEL_CHANNEL.get() {
+        let _ = channel
+            .lock()
+            .await
+            .send(FirstLevelCommands::KeyReleased(key))
+            .await;
+    }
+}
+
 #[tauri::command]
 pub async fn left_move() {
     if let Some(channel) = FIRST_LEVEL_CHANNEL.get() {
@@ -83,6 +108,72 @@ pub async fn left_move() {
     }
 }
 
+#[tauri::command]
+pub async fn list_replays() -> Vec<String> {
+    retreive_replay_names().await
+}
+
+#[tauri::command]
+pub async fn list_saved_games() -> Vec<SavedGameSummary> {
+    retreive_saved_games().await
+}
+
+#[tauri::command]
+pub async fn pause_game() {
+    if let Some(channel) = GAME_CONTROL_CHANNEL.get() {
+        let _ = channel.lock().await.send(GameControl::Pause).await;
+    }
+}
+
+#[tauri::command]
+pub async fn play_replay(app: AppHandle, name: String) -> bool {
+    let Some(replay) = retreive_replay(&name).await else {
+        return false;
+    };
+    let options = replay.options().clone();
+    let Some(seed) = replay.seed() else {
+        return false;
+    };
+    let (tx, rx) = mpsc::channel(256);
+    let (control_tx, control_rx) = mpsc::channel(256);
+    set_local_channels(tx, control_tx).await;
+    tokio::spawn(async move {
+        let queue = LocalQueue::new(seed, options.randomizer());
+        let mut game = LocalGame::new(options, Arc::new(app), rx, None, control_rx, None, queue);
+        game.play_replay(replay);
+        game.start_game().await;
+    });
+    true
+}
+
+#[tauri::command]
+pub async fn resume_game() {
+    if let Some(channel) = GAME_CONTROL_CHANNEL.get() {
+        let _ = channel.lock().await.send(GameControl::Resume).await;
+    }
+}
+
+#[tauri::command]
+pub async fn resume_saved_game(app: AppHandle, id: u32) -> bool {
+    let Some(snapshot) = take_saved_game(id).await else {
+        return false;
+    };
+    let options = snapshot.options().clone();
+    let Some(seed) = options.seed() else {
+        return false;
+    };
+    let (tx, rx) = mpsc::channel(256);
+    let (control_tx, control_rx) = mpsc::channel(256);
+    set_local_channels(tx, control_tx).await;
+    tokio::spawn(async move {
+        let queue = LocalQueue::new(seed, options.randomizer());
+        let mut game = LocalGame::new(options, Arc::new(app), rx, None, control_rx, None, queue);
+        game.restore(snapshot);
+        game.start_game().await;
+    });
+    true
+}
+
 #[tauri::command]
 pub async fn retry_game(app: AppHandle, options: GameOptions) {
     if let Some(channel) = GAME_CONTROL_CHANNEL.get() {
@@ -104,6 +195,13 @@ pub async fn right_move() {
     }
 }
 
+#[tauri::command]
+pub async fn save_game() {
+    if let Some(channel) = GAME_CONTROL_CHANNEL.get() {
+        let _ = channel.lock().await.send(GameControl::Save).await;
+    }
+}
+
 #[tauri::command]
 pub async fn save_piece() {
     if let Some(channel) = FIRST_LEVEL_CHANNEL.get() {
@@ -127,33 +225,14 @@ pub async fn soft_drop() {
 }
 
 #[tauri::command]
-pub async fn start_game(app: AppHandle, options: GameOptions) {
+pub async fn start_game(app: AppHandle, mut options: GameOptions) {
     let (tx, rx) = mpsc::channel(256);
     let (control_tx, control_rx) = mpsc::channel(256);
-    if let Some(channel) = FIRST_LEVEL_CHANNEL.get() {
-        let mut locked = channel.lock().await;
-        *locked = tx;
-    } else {
-        FIRST_LEVEL_CHANNEL.set(Arc::new(Mutex::new(tx))).unwrap();
-    }
-    if let Some(channel) = GAME_CONTROL_CHANNEL.get() {
-        let mut locked = channel.lock().await;
-        *locked = control_tx;
-    } else {
-        GAME_CONTROL_CHANNEL
-            .set(Arc::new(Mutex::new(control_tx)))
-            .unwrap();
-    }
+    set_local_channels(tx, control_tx).await;
+    let seed = options.resolve_seed();
     tokio::spawn(async move {
-        let mut game = LocalGame::new(
-            options,
-            app,
-            rx,
-            None,
-            control_rx,
-            None,
-            LocalQueue::default(),
-        );
+        let queue = LocalQueue::new(seed, options.randomizer());
+        let mut game = LocalGame::new(options, Arc::new(app), rx, None, control_rx, None, queue);
         game.start_game().await;
     });
 }
@@ -201,3 +280,26 @@ pub async fn targeting_strategy_random() {
             .await;
     }
 }
+
+/// Replaces the channels used to talk with the local game.
+///
+/// # Arguments
+///
+/// * `tx` - The sender for the first-level commands.
+/// * `control_tx` - The sender for the game control commands.
+async fn set_local_channels(tx: Sender<FirstLevelCommands>, control_tx: Sender<GameControl>) {
+    if let Some(channel) = FIRST_LEVEL_CHANNEL.get() {
+        let mut locked = channel.lock().await;
+        *locked = tx;
+    } else {
+        FIRST_LEVEL_CHANNEL.set(Arc::new(Mutex::new(tx))).unwrap();
+    }
+    if let Some(channel) = GAME_CONTROL_CHANNEL.get() {
+        let mut locked = channel.lock().await;
+        *locked = control_tx;
+    } else {
+        GAME_CONTROL_CHANNEL
+            .set(Arc::new(Mutex::new(control_tx)))
+            .unwrap();
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
     models::emit_game_info::EmitGameInfo,
     persistence::retreive_game_info::{
-        retreive_last_blitz_info, retreive_last_classic_info, retreive_last_games_info,
-        retreive_last_lines_info,
+        retreive_last_blitz_info, retreive_last_cheese_info, retreive_last_classic_info,
+        retreive_last_games_info, retreive_last_lines_info, retreive_last_master_info,
+        retreive_last_survival_info,
     },
 };
 
@@ -25,3 +26,18 @@ pub async fn retreive_lines_game_info() -> EmitGameInfo {
 pub async fn retreive_blitz_game_info() -> EmitGameInfo {
     retreive_last_blitz_info().await
 }
+
+#[tauri::command]
+pub async fn retreive_cheese_game_info() -> EmitGameInfo {
+    retreive_last_cheese_info().await
+}
+
+#[tauri::command]
+pub async fn retreive_survival_game_info() -> EmitGameInfo {
+    retreive_last_survival_info().await
+}
+
+#[tauri::command]
+pub async fn retreive_master_game_info() -> EmitGameInfo {
+    retreive_last_master_info().await
+}
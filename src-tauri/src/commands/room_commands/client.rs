@@ -5,6 +5,7 @@ use tokio::sync::{Mutex, broadcast, mpsc};
 
 use super::{END_LISTEN_ROOM_UPDATES, END_SEARCH_CHANNEL, ROOM_INFO};
 use crate::{
+    events::EventSink,
     globals::SIZE_FOR_KB,
     models::{
         dummy_room::{DummyPlayer, DummyRoom},
@@ -25,15 +26,16 @@ pub async fn stop_search() {
 #[tauri::command]
 pub async fn join_room(app: AppHandle, room: RoomInfo, player: DummyPlayer) {
     let player = DummyPlayer::fill(player);
+    let events: Arc<dyn EventSink> = Arc::new(app);
     let (tx, rx) = broadcast::channel(SIZE_FOR_KB);
     let Some((room, stream)) =
-        crate::room::client::join_room::join_room(room, player.clone(), &app).await
+        crate::room::client::join_room::join_room(room, player.clone(), &events).await
     else {
         return;
     };
 
     tokio::spawn(async move {
-        ClientRoom::new(stream, app, rx, player).listen().await;
+        ClientRoom::new(stream, events, rx, player).listen().await;
     });
     stop_search().await;
     if let Some(room_old) = ROOM_INFO.get() {
@@ -73,7 +75,7 @@ pub async fn room_info() -> Option<DummyRoom> {
 pub async fn listen_for_rooms(app: AppHandle) {
     let (tx, rx) = mpsc::channel(32);
     tokio::spawn(async move {
-        listen_to_rooms(app, rx).await;
+        listen_to_rooms(Arc::new(app), rx).await;
     });
     if let Some(tx_rec) = END_SEARCH_CHANNEL.get() {
         let mut locked = tx_rec.lock().await;
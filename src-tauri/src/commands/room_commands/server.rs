@@ -41,7 +41,16 @@ pub async fn create_room(app: AppHandle, name: String, player_name: String) -> D
             .set(Arc::new(Mutex::new(tx_command.clone())))
             .unwrap();
     }
-    let mut room = Room::new(name, app, rx_end, rx, player_name, tx_command, rx_command).await;
+    let mut room = Room::new(
+        name,
+        Arc::new(app),
+        rx_end,
+        rx,
+        player_name,
+        tx_command,
+        rx_command,
+    )
+    .await;
     let dummy_room = (&room).into();
     tokio::spawn(async move {
         room.room_start().await;
@@ -0,0 +1,25 @@
+use serde_json::Value;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use super::EventSink;
+
+/// `ChannelSink` sends the events through a channel, so they can be read without a window.
+#[derive(Debug, Clone)]
+pub struct ChannelSink {
+    /// Sender of the event names and payloads.
+    sender: UnboundedSender<(String, Value)>,
+}
+
+impl ChannelSink {
+    /// Creates a new `ChannelSink` and the receiver of its events.
+    pub fn new() -> (Self, UnboundedReceiver<(String, Value)>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        (Self { sender }, receiver)
+    }
+}
+
+impl EventSink for ChannelSink {
+    fn emit_value(&self, event: &str, payload: Value) {
+        let _ = self.sender.send((event.to_string(), payload));
+    }
+}
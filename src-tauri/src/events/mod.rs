@@ -0,0 +1,33 @@
+use std::fmt::Debug;
+
+use serde::Serialize;
+use serde_json::Value;
+
+pub mod channel_sink;
+mod tauri_sink;
+
+/// `EventSink` represents the destination of the events emitted by the games and rooms.
+///
+/// The Tauri application handle is the sink used by the application. Other sinks allow
+/// running the games without a window, like in tests.
+pub trait EventSink: Debug + Send + Sync {
+    /// Emits an event with an already serialized payload.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The name of the event.
+    /// * `payload` - The payload of the event.
+    fn emit_value(&self, event: &str, payload: Value);
+}
+
+impl dyn EventSink {
+    /// Serializes the payload and emits the event.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The name of the event.
+    /// * `payload` - The payload of the event.
+    pub fn emit<S: Serialize>(&self, event: &str, payload: S) {
+        self.emit_value(event, serde_json::to_value(payload).unwrap());
+    }
+}
@@ -0,0 +1,10 @@
+use serde_json::Value;
+use tauri::{AppHandle, Emitter};
+
+use super::EventSink;
+
+impl EventSink for AppHandle {
+    fn emit_value(&self, event: &str, payload: Value) {
+        let _ = Emitter::emit(self, event, payload);
+    }
+}
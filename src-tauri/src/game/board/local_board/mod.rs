@@ -1,19 +1,27 @@
-use std::{cmp::Ordering, collections::HashMap, ops::Range};
+use std::{cmp::Ordering, collections::BTreeMap, ops::Range};
 
-use moving_piece::{MovingPiece, Orientation, moving_piece_t::MovingPieceT};
+use moving_piece::{MovingPiece, Orientation, PieceState, moving_piece_t::MovingPieceT};
 use rand::Rng;
+use rotation_system::{RotationOption, RotationSystem};
+use seeded_rng::SeededRng;
 use serde::{Deserialize, Serialize};
 
-use crate::game::{pieces::Piece, queue::Queue, strategy::Strategy};
+use crate::game::{pieces::Piece, queue::Queue, strategy::Strategy, visibility::CellShown};
 
 use super::{Board, cell::Cell, danger_level::DangerLevel};
 
 mod moving_piece;
+pub mod rotation_system;
+mod seeded_rng;
 
 /// The width of the game board.
 const BOARD_WIDTH: i16 = 10;
 /// The height of the game board.
-const BOARD_HEIGHT: i16 = 20;
+pub(crate) const BOARD_HEIGHT: i16 = 20;
+/// The number of cells of the board each side of a cell of a big field takes.
+pub(crate) const BIG_SCALE: i16 = 2;
+/// The character written for the locked cells shown with a single colour.
+const MONOCHROME_CELL: u8 = b'M';
 
 /// `LocalBoard` represents the game board for a single-player game.
 #[derive(Debug)]
@@ -26,13 +34,18 @@ pub struct LocalBoard {
     cur_piece: Box<dyn MovingPiece>,
     /// The strategy being used by the board.
     strategy: Strategy,
+    /// The rotation system used to kick the pieces.
+    rotation_system: RotationSystem,
     /// The number of pieces that have been placed on the board.
     piece_num: usize,
     /// The queue of trash lines to be added to the board.
     trash_lines_queue: Vec<(u8, u8)>,
-    /// The cells of the main board.
+    /// The random number generator used to choose the trash columns.
+    rng: SeededRng,
+    /// The cells of the main board, of which a big field only uses the first quarter.
     cells: [Cell; (BOARD_HEIGHT * BOARD_WIDTH) as usize],
-    /// The cells of the buffer board (used for pieces above the visible board).
+    /// The cells of the buffer board (used for pieces above the visible board), of which a big
+    /// field only uses the first quarter.
     buffer: [Cell; (BOARD_HEIGHT * BOARD_WIDTH) as usize],
     /// A boolean indicating whether the player has locked out (placed a piece too high).
     lock_out: bool,
@@ -44,14 +57,34 @@ pub struct LocalBoard {
     line_cleared: bool,
     /// The number of lines that have been cleared.
     lines_cleared: u32,
+    /// The number of cleared lines that had trash in them.
+    garbage_cleared: u32,
+    /// The column of the hole of the last cheese line added.
+    cheese_column: Option<u8>,
     /// The pattern of lines that have been cleared.
     clear_pattern: ClearLinePattern,
+    /// A boolean indicating whether the last line clear left the board empty.
+    perfect_clear: bool,
     /// A boolean indicating whether the piece has been rotated.
     rotation: bool,
     /// The rotation option used.
     rotation_option: RotationOption,
     /// The variation of the rotation used.
     rotation_variation: i16,
+    /// A boolean indicating whether the next piece spawns as soon as the current one is fixed.
+    spawn_on_lock: bool,
+    /// A boolean indicating whether the current piece has been fixed and the next one hasn't spawned.
+    spawn_pending: bool,
+    /// The y coordinates of the lines completed by the last fixed piece that are still on the board.
+    pending_lines: Option<[i16; 4]>,
+    /// A boolean indicating whether the pieces are played at double scale, on a field of half
+    /// the width and height of the board.
+    big: bool,
+    /// The number of pieces that have been locked on the board.
+    locks: usize,
+    /// The number of the lock that placed each cell of the buffer and main boards, in the
+    /// order of the board state, or 0 for empty cells and trash.
+    lock_stamps: [usize; (BOARD_HEIGHT * BOARD_WIDTH * 2) as usize],
 }
 impl Board for LocalBoard {
     /// Checks if the game is over based on topping out, locking out, or blocking out.
@@ -72,44 +105,7 @@ impl Board for LocalBoard {
     /// The string representation includes the state of the main board, the buffer board,
     /// and the current piece.
     fn board_state(&self) -> String {
-        let mut buf: Vec<u8> = [Cell::Empty.string_representation() as u8;
-            (BOARD_HEIGHT * BOARD_WIDTH * 2) as usize]
-            .into();
-        let ghost_piece = self.ghost_piece(self.cur_piece.clone());
-        for (x, y) in ghost_piece.get_coords() {
-            if y >= 0 {
-                buf[(y * BOARD_WIDTH + x + (BOARD_HEIGHT * BOARD_WIDTH)) as usize] =
-                    Piece::Ghost.string_representation() as u8;
-            } else {
-                buf[((BOARD_HEIGHT + y) * BOARD_WIDTH + x) as usize] =
-                    Piece::Ghost.string_representation() as u8;
-            }
-        }
-        for (i, el) in self.buffer.iter().enumerate() {
-            if *el == Cell::Empty {
-                continue;
-            }
-            buf[i] = el.string_representation() as u8;
-        }
-
-        for (i, el) in self.cells.iter().enumerate() {
-            if *el == Cell::Empty {
-                continue;
-            }
-            buf[i + (BOARD_HEIGHT * BOARD_WIDTH) as usize] = el.string_representation() as u8;
-        }
-
-        for (x, y) in self.cur_piece.get_coords() {
-            if y >= 0 {
-                buf[(y * BOARD_WIDTH + x + (BOARD_HEIGHT * BOARD_WIDTH)) as usize] =
-                    self.cur_piece.piece().string_representation() as u8;
-            } else {
-                buf[((BOARD_HEIGHT + y) * BOARD_WIDTH + x) as usize] =
-                    self.cur_piece.piece().string_representation() as u8;
-            }
-        }
-
-        String::from_utf8(buf).expect("Should be valid UTF as I just wrote it")
+        self.scaled_state(self.field_state())
     }
 
     /// Returns the strategy being used by the board.
@@ -123,7 +119,8 @@ impl Board for LocalBoard {
             return DangerLevel::Empty;
         }
 
-        let coords = coords.unwrap();
+        // The height is measured in cells of the board, whatever the scale of the field
+        let coords = (coords.unwrap().0, coords.unwrap().1 * self.scale());
         if coords.1 > 14 && coords.1 <= 19 {
             return DangerLevel::VeryLow;
         }
@@ -162,8 +159,10 @@ impl LocalBoard {
             held_piece: None,
             cur_piece: cur_piece.try_into().unwrap(),
             strategy: Strategy::Even,
+            rotation_system: RotationSystem::default(),
             piece_num: 0,
             trash_lines_queue: Vec::new(),
+            rng: SeededRng::new(rand::random()),
             cells: [Cell::Empty; 200],
             buffer: [Cell::Empty; 200],
             lock_out: false,
@@ -171,10 +170,19 @@ impl LocalBoard {
             piece_blocked: false,
             line_cleared: false,
             lines_cleared: 0,
+            garbage_cleared: 0,
+            cheese_column: None,
             clear_pattern: ClearLinePattern::None,
+            perfect_clear: false,
             rotation: false,
             rotation_option: RotationOption::Full,
             rotation_variation: 0,
+            spawn_on_lock: true,
+            spawn_pending: false,
+            pending_lines: None,
+            big: false,
+            locks: 0,
+            lock_stamps: [0; 400],
         }
     }
     /// Moves the current piece to the right if possible.
@@ -183,7 +191,7 @@ impl LocalBoard {
     pub fn move_right(&mut self) -> bool {
         let sides = self.cur_piece.get_right_facing_sides();
         for (x, y) in sides {
-            if x == BOARD_WIDTH - 1 {
+            if x == self.width() - 1 {
                 return false;
             }
             if y >= 0 {
@@ -228,80 +236,74 @@ impl LocalBoard {
     }
 
     /// Rotates the current piece clockwise if possible.
-    pub fn rotation_clockwise(&mut self) {
+    ///
+    /// Returns true if the piece was rotated.
+    pub fn rotation_clockwise(&mut self) -> bool {
         let rotation_piece = self.cur_piece.clone();
-        self.check_rotation(rotation_piece, 1..6, RotationOption::ClockWise);
+        let rotated = self.check_rotation(rotation_piece, RotationOption::ClockWise);
         self.rotation = true;
+        rotated
     }
 
     /// Rotates the current piece counterclockwise if possible.
-    pub fn rotation_counterclockwise(&mut self) {
+    ///
+    /// Returns true if the piece was rotated.
+    pub fn rotation_counterclockwise(&mut self) -> bool {
         let rotation_piece = self.cur_piece.clone();
-        self.check_rotation(rotation_piece, 1..6, RotationOption::CounterClockWise);
+        let rotated = self.check_rotation(rotation_piece, RotationOption::CounterClockWise);
         self.rotation = true;
+        rotated
     }
 
     /// Rotates the current piece 180 degrees if possible.
-    pub fn rotation_full(&mut self) {
+    ///
+    /// Returns true if the piece was rotated.
+    pub fn rotation_full(&mut self) -> bool {
         let rotation_piece = self.cur_piece.clone();
-        self.check_rotation(rotation_piece, 1..3, RotationOption::Full);
+        self.check_rotation(rotation_piece, RotationOption::Full)
     }
     /// Checks if a rotation is possible and performs it.
     ///
-    /// Iterates through a range of possible rotation variations and checks if the rotation is valid.
-    /// If a valid rotation is found, the current piece is updated and the function returns.
-    fn check_rotation(
-        &mut self,
-        piece: Box<dyn MovingPiece>,
-        positibility_iteration_range: Range<u8>,
-        option: RotationOption,
-    ) {
-        let mut continue_in_iteration;
-        for i in positibility_iteration_range {
+    /// Iterates through the kicks given by the rotation system and checks if the rotation is valid.
+    /// If a valid rotation is found, the current piece is updated and the function returns true.
+    fn check_rotation(&mut self, piece: Box<dyn MovingPiece>, option: RotationOption) -> bool {
+        let orientation = option.next_orientation(piece.orientation());
+        let kicks = self
+            .rotation_system
+            .kicks(piece.piece(), piece.orientation(), option);
+        let shift =
+            self.rotation_system
+                .state_shift(piece.piece(), piece.orientation(), orientation);
+        for (i, kick) in kicks.iter().enumerate() {
             let mut piece = piece.clone();
-            continue_in_iteration = false;
-            match option {
-                RotationOption::ClockWise => piece.rotate_clockwise(i.into()),
-                RotationOption::CounterClockWise => piece.rotate_counterclockwise(i.into()),
-                RotationOption::Full => piece.rotate_full(i.into()),
-            }
-            for (x, y) in piece.get_coords() {
-                if !(0..=BOARD_WIDTH - 1).contains(&x) {
-                    // Checks that the coordinate is in the board bounds
-                    continue_in_iteration = true;
-                    break;
-                }
-                if !(-BOARD_HEIGHT..BOARD_HEIGHT).contains(&y) {
-                    continue_in_iteration = true;
-                    break;
-                }
-                if y >= 0 {
-                    match self.get_cell_from_main_board(x, y) {
-                        Cell::Empty => continue,
-                        Cell::Full(_) => {
-                            continue_in_iteration = true;
-                            break;
-                        }
-                    }
-                } else {
-                    match self.get_cell_from_buffer_board(x, y) {
-                        Cell::Empty => continue,
-                        Cell::Full(_) => {
-                            continue_in_iteration = true;
-                            break;
-                        }
+            piece.rotate(orientation, (kick.0 + shift.0, kick.1 + shift.1));
+            let mut coords = piece.get_coords();
+            // The cells are checked from left to right and top to bottom, so the first one
+            // blocking the piece is found
+            coords.sort_by_key(|&(x, y)| (y, x));
+            match coords.into_iter().find(|&(x, y)| self.cell_blocked(x, y)) {
+                None => {
+                    self.cur_piece = piece.clone();
+                    if self.rotation {
+                        self.rotation_option = option;
+                        self.rotation_variation = i as i16 + 1;
                     }
+                    return true;
                 }
-            }
-            if !continue_in_iteration {
-                self.cur_piece = piece.clone();
-                if self.rotation {
-                    self.rotation_option = option;
-                    self.rotation_variation = i as i16;
+                Some((x, _))
+                    if i == 0
+                        && self.rotation_system.centre_column(
+                            piece.piece(),
+                            piece.x(),
+                            orientation,
+                        ) == Some(x) =>
+                {
+                    return false;
                 }
-                break;
+                Some(_) => continue,
             }
         }
+        false
     }
 
     /// Checks if the current piece is at the bottom of the board.
@@ -345,7 +347,7 @@ impl LocalBoard {
         while !bottom_reached {
             let sides = piece.get_bottom_facing_sides();
             for (x, y) in sides {
-                if y >= BOARD_HEIGHT - 1 {
+                if y >= self.height() - 1 {
                     bottom_reached = true;
                     break;
                 }
@@ -382,7 +384,7 @@ impl LocalBoard {
     fn push_down(&mut self, move_piece: bool) -> bool {
         let sides = self.cur_piece.get_bottom_facing_sides();
         for (x, y) in sides {
-            if y >= BOARD_HEIGHT - 1 {
+            if y >= self.height() - 1 {
                 return true;
             }
 
@@ -407,13 +409,17 @@ impl LocalBoard {
 
     /// Performs operations that occur after a piece has been placed.
     ///
-    /// This includes fixing the piece in place, generating a new piece, checking for line clears,
-    /// and setting any trash lines that have been received.
+    /// This includes fixing the piece in place and checking for line clears. If the next piece
+    /// spawns on lock it also clears the lines, generates a new piece and sets any trash lines
+    /// that have been received.
     fn next_piece_operations(&mut self) {
         let coords = self.cur_piece.get_coords();
         let piece = self.cur_piece.clone();
         let mut topped = true;
+        self.locks += 1;
         for (x, y) in coords {
+            let stamp_index = self.stamp_index(x, y);
+            self.lock_stamps[stamp_index] = self.locks;
             if y >= 0 {
                 self.set_cell_in_main_board(x, y, Cell::Full(piece.piece()));
                 topped = false;
@@ -422,13 +428,7 @@ impl LocalBoard {
             }
         }
         self.lock_out = topped;
-        self.piece_num += 1;
-        self.cur_piece = self
-            .queue
-            .get_piece(self.piece_num)
-            .unwrap()
-            .try_into()
-            .unwrap();
+        self.spawn_pending = true;
         self.piece_blocked = false;
         match self.is_line_cleared() {
             None => {
@@ -442,14 +442,44 @@ impl LocalBoard {
                     }
                 });
                 self.lines_cleared += lines as u32;
+                self.garbage_cleared += y_cleared
+                    .iter()
+                    .filter(|y| **y != -128 && self.row_has_trash(**y))
+                    .count() as u32;
                 self.clear_pattern(lines, piece);
-                y_cleared.iter().for_each(|y| {
-                    if *y != -128 {
-                        self.clear_line(*y);
-                    }
-                });
+                self.perfect_clear = self.is_board_empty_except(&y_cleared);
+                self.pending_lines = Some(y_cleared);
             }
         }
+        if self.spawn_on_lock {
+            self.spawn_piece();
+        }
+    }
+
+    /// Removes the lines completed by the last fixed piece from the board.
+    pub fn clear_pending_lines(&mut self) {
+        let Some(y_cleared) = self.pending_lines.take() else {
+            return;
+        };
+        y_cleared.iter().for_each(|y| {
+            if *y != -128 {
+                self.clear_line(*y);
+            }
+        });
+    }
+
+    /// Spawns the next piece after the current one has been fixed.
+    ///
+    /// Clears the lines that are still pending and sets any trash lines that have been received.
+    pub fn spawn_piece(&mut self) {
+        if !self.spawn_pending {
+            return;
+        }
+        self.spawn_pending = false;
+        self.piece_num += 1;
+        let piece = self.queue.get_piece(self.piece_num).unwrap();
+        self.cur_piece = self.spawned(piece);
+        self.clear_pending_lines();
         self.set_trash_in_board();
     }
     /// Checks if any lines have been cleared and returns the y coordinates of the cleared lines.
@@ -457,29 +487,30 @@ impl LocalBoard {
         let mut lines = [-128; 4];
         let mut pieces_cleared = 0;
         let mut cur_position = 0;
-        for (i, el) in self.buffer.iter().enumerate() {
+        let (width, area) = (self.width(), self.area());
+        for (i, el) in self.buffer[..area].iter().enumerate() {
             if let &Cell::Full(_) = el {
                 pieces_cleared += 1;
             }
-            if i % (BOARD_WIDTH) as usize == 9 {
+            if i % width as usize == width as usize - 1 {
                 // Checks if an entire row has been read
-                if pieces_cleared == BOARD_WIDTH {
+                if pieces_cleared == width {
                     // Checks if the number of cleared pieces is equal to the width of the board
-                    lines[cur_position] = BOARD_HEIGHT - ((i / (BOARD_WIDTH) as usize - 1) as i16); // Stores the y position in an array
+                    lines[cur_position] = self.height() - ((i / width as usize - 1) as i16); // Stores the y position in an array
                     cur_position += 1;
                 }
                 pieces_cleared = 0;
             }
         }
-        for (i, el) in self.cells.iter().enumerate() {
+        for (i, el) in self.cells[..area].iter().enumerate() {
             if let &Cell::Full(_) = el {
                 pieces_cleared += 1;
             }
-            if i % (BOARD_WIDTH) as usize == 9 {
+            if i % width as usize == width as usize - 1 {
                 // Checks if an entire row has been read
-                if pieces_cleared == BOARD_WIDTH {
+                if pieces_cleared == width {
                     // Checks if the number of cleared pieces is equal to the width of the board
-                    lines[cur_position] = (i / (BOARD_WIDTH) as usize) as i16; // Stores the y position in an array
+                    lines[cur_position] = (i / width as usize) as i16; // Stores the y position in an array
                     cur_position += 1;
                 }
                 pieces_cleared = 0;
@@ -489,9 +520,13 @@ impl LocalBoard {
     }
     /// Clears a line at the given y coordinate.
     fn clear_line(&mut self, y: i16) {
-        (-BOARD_HEIGHT..=y).rev().for_each(|y| {
-            (0..BOARD_WIDTH).for_each(|x| {
-                if y == -BOARD_HEIGHT {
+        let (width, height) = (self.width(), self.height());
+        let row_start = self.stamp_index(0, y);
+        self.lock_stamps.copy_within(0..row_start, width as usize);
+        self.lock_stamps[..width as usize].fill(0);
+        (-height..=y).rev().for_each(|y| {
+            (0..width).for_each(|x| {
+                if y == -height {
                     self.set_cell_in_buffer_board(x, y, Cell::Empty);
                     return;
                 }
@@ -517,10 +552,47 @@ impl LocalBoard {
             });
         });
     }
+    /// Checks if the line at the given y coordinate has any trash in it.
+    fn row_has_trash(&self, y: i16) -> bool {
+        (0..self.width()).any(|x| {
+            let cell = if y >= 0 {
+                self.get_cell_from_main_board(x, y)
+            } else {
+                self.get_cell_from_buffer_board(x, y)
+            };
+            cell == Cell::Full(Piece::Trash)
+        })
+    }
+    /// Checks if both the main board and the buffer board are empty, without counting the
+    /// lines at the given y coordinates.
+    fn is_board_empty_except(&self, lines: &[i16; 4]) -> bool {
+        (-self.height()..self.height())
+            .filter(|y| !lines.contains(y))
+            .all(|y| {
+                (0..self.width()).all(|x| {
+                    let cell = if y >= 0 {
+                        self.get_cell_from_main_board(x, y)
+                    } else {
+                        self.get_cell_from_buffer_board(x, y)
+                    };
+                    cell == Cell::Empty
+                })
+            })
+    }
     /// Determines the clear pattern based on the lines cleared and the piece that was settled.
     fn clear_pattern(&mut self, lines_cleared: i16, piece_settled: Box<dyn MovingPiece>) {
         match (piece_settled.piece(), self.rotation) {
             (Piece::T, true) => self.t_spin_calculation(lines_cleared, piece_settled),
+            (piece, true) if piece != Piece::O && self.is_immobile(piece_settled.as_ref()) => {
+                match lines_cleared {
+                    0 => self.clear_pattern = ClearLinePattern::Spin(piece),
+                    1 => self.clear_pattern = ClearLinePattern::SpinSingle(piece),
+                    2 => self.clear_pattern = ClearLinePattern::SpinDouble(piece),
+                    3 => self.clear_pattern = ClearLinePattern::SpinTriple(piece),
+                    4 => self.clear_pattern = ClearLinePattern::Tetris,
+                    _ => panic!("Shouldn't arrive here"),
+                }
+            }
             _ => match lines_cleared {
                 0 => self.clear_pattern = ClearLinePattern::None,
                 1 => self.clear_pattern = ClearLinePattern::Single,
@@ -531,6 +603,42 @@ impl LocalBoard {
             },
         }
     }
+    /// Checks if a settled piece can't move left, right or up.
+    ///
+    /// The piece is already fixed in the board, so its own cells are ignored. It is used to detect
+    /// spins for the pieces that don't follow the T-spin 3-corner rule.
+    fn is_immobile(&self, piece_settled: &dyn MovingPiece) -> bool {
+        let coords = piece_settled.get_coords();
+        [(-1, 0), (1, 0), (0, -1)].iter().all(|(dx, dy)| {
+            coords.iter().any(|(x, y)| {
+                let (x, y) = (x + dx, y + dy);
+                !coords.contains(&(x, y)) && self.is_cell_blocked(x, y)
+            })
+        })
+    }
+
+    /// Checks if the cell at the given coordinates is out of the board or full.
+    fn is_cell_blocked(&self, x: i16, y: i16) -> bool {
+        if !(0..self.width()).contains(&x) || !(-self.height()..self.height()).contains(&y) {
+            return true;
+        }
+        let cell = if y >= 0 {
+            self.get_cell_from_main_board(x, y)
+        } else {
+            self.get_cell_from_buffer_board(x, y)
+        };
+        cell != Cell::Empty
+    }
+    /// Returns the cell at the given T-spin slot, a full one if it is out of the board.
+    fn slot_cell(&self, (x, y): (i16, i16)) -> Cell {
+        if !(0..self.width()).contains(&x) || !(-self.height()..self.height()).contains(&y) {
+            Cell::Full(Piece::I) // Piece is irrelevant
+        } else if y >= 0 {
+            self.get_cell_from_main_board(x, y)
+        } else {
+            self.get_cell_from_buffer_board(x, y)
+        }
+    }
     /// Calculates the clear pattern for a T-spin.
     ///
     /// A T-spin is a special type of line clear that occurs when a T-shaped piece is used to clear lines
@@ -541,58 +649,10 @@ impl LocalBoard {
             .as_any()
             .downcast::<MovingPieceT>()
             .expect("Checked that is the correct type");
-        let a_slot = t_piece.get_t_spin_point_a();
-        let a_cell = if a_slot.1 >= 0
-            && a_slot.1 < 20
-            && a_slot.1 >= -20
-            && a_slot.0 >= 0
-            && a_slot.0 < 20
-        {
-            self.get_cell_from_main_board(a_slot.0, a_slot.1)
-        } else if a_slot.1 < 20 && a_slot.1 >= -20 && a_slot.0 >= 0 && a_slot.0 < 20 {
-            self.get_cell_from_buffer_board(a_slot.0, a_slot.1)
-        } else {
-            Cell::Full(Piece::I)
-        };
-        let b_slot = t_piece.get_t_spin_point_b();
-        let b_cell = if b_slot.1 >= 0
-            && b_slot.1 < 20
-            && b_slot.1 >= -20
-            && b_slot.0 >= 0
-            && b_slot.0 < 20
-        {
-            self.get_cell_from_main_board(b_slot.0, b_slot.1)
-        } else if b_slot.1 < 20 && b_slot.1 >= -20 && b_slot.0 >= 0 && b_slot.0 < 20 {
-            self.get_cell_from_buffer_board(b_slot.0, b_slot.1)
-        } else {
-            Cell::Full(Piece::I)
-        };
-        let c_slot = t_piece.get_t_spin_point_c();
-        let c_cell = if c_slot.1 >= 0
-            && c_slot.1 < 20
-            && c_slot.1 >= -20
-            && c_slot.0 >= 0
-            && c_slot.0 < 20
-        {
-            self.get_cell_from_main_board(c_slot.0, c_slot.1)
-        } else if c_slot.1 < 20 && c_slot.1 >= -20 && c_slot.0 >= 0 && c_slot.0 < 20 {
-            self.get_cell_from_buffer_board(c_slot.0, c_slot.1)
-        } else {
-            Cell::Full(Piece::I) // Piece is irrelevant
-        };
-        let d_slot = t_piece.get_t_spin_point_d();
-        let d_cell = if d_slot.1 >= 0
-            && d_slot.1 < 20
-            && d_slot.1 >= -20
-            && d_slot.0 >= 0
-            && d_slot.0 < 20
-        {
-            self.get_cell_from_main_board(d_slot.0, d_slot.1)
-        } else if d_slot.1 < 20 && d_slot.1 >= -20 && d_slot.0 >= 0 && d_slot.0 < 20 {
-            self.get_cell_from_buffer_board(d_slot.0, d_slot.1)
-        } else {
-            Cell::Full(Piece::I)
-        };
+        let a_cell = self.slot_cell(t_piece.get_t_spin_point_a());
+        let b_cell = self.slot_cell(t_piece.get_t_spin_point_b());
+        let c_cell = self.slot_cell(t_piece.get_t_spin_point_c());
+        let d_cell = self.slot_cell(t_piece.get_t_spin_point_d());
 
         if let (Cell::Full(_), Cell::Full(_)) = (a_cell, b_cell) {
             if let Cell::Full(_) = c_cell {
@@ -658,17 +718,21 @@ impl LocalBoard {
             self.top_out = true;
             return;
         }
-        for (i, _) in self.buffer.clone().iter().enumerate() {
-            let modified = i + (BOARD_WIDTH as usize * lines as usize);
-            if modified >= ((BOARD_HEIGHT * BOARD_WIDTH) - 1) as usize {
-                self.buffer[i] = self.cells[modified - ((BOARD_HEIGHT * BOARD_WIDTH - 1) as usize)];
+        let area = self.area();
+        let shifted = self.width() as usize * lines as usize;
+        self.lock_stamps.copy_within(shifted..area * 2, 0);
+        self.lock_stamps[area * 2 - shifted..area * 2].fill(0);
+        for i in 0..area {
+            let modified = i + shifted;
+            if modified >= area - 1 {
+                self.buffer[i] = self.cells[modified - (area - 1)];
             } else {
                 self.buffer[i] = self.buffer[modified];
             }
         }
-        for (i, _) in self.cells.clone().iter().enumerate() {
-            let modified = i + (BOARD_WIDTH as usize * lines as usize);
-            if modified >= ((BOARD_HEIGHT * BOARD_WIDTH) - 1) as usize {
+        for i in 0..area {
+            let modified = i + shifted;
+            if modified >= area - 1 {
                 break;
             } else {
                 self.cells[i] = self.cells[modified];
@@ -677,7 +741,7 @@ impl LocalBoard {
         let mut lines_added = 0;
         for (number, column) in self.trash_lines_queue.clone() {
             for i in 0..number {
-                self.add_lines(column as i16, BOARD_HEIGHT - 1 - lines_added - i as i16);
+                self.add_lines(column as i16, self.height() - 1 - lines_added - i as i16);
             }
             lines_added += number as i16;
         }
@@ -685,7 +749,7 @@ impl LocalBoard {
     }
     /// Adds lines to the board.
     fn add_lines(&mut self, x: i16, y: i16) {
-        for i in 0..BOARD_WIDTH {
+        for i in 0..self.width() {
             match (i == x, y < 0) {
                 (true, true) => self.set_cell_in_buffer_board(i, y, Cell::Empty),
                 (true, false) => self.set_cell_in_main_board(i, y, Cell::Empty),
@@ -698,12 +762,13 @@ impl LocalBoard {
     fn top_out_check(&mut self, lines: u8) -> bool {
         let highest_piece = self.get_highest_piece();
         if let Some((_, y)) = highest_piece {
+            let height = self.height();
             let remaining_pieces = if y < 0 {
-                BOARD_HEIGHT * 2 - (BOARD_HEIGHT + y)
+                height * 2 - (height + y)
             } else if y > 0 {
-                y + BOARD_HEIGHT
+                y + height
             } else {
-                BOARD_HEIGHT
+                height
             };
             lines as i16 >= remaining_pieces
         } else {
@@ -716,24 +781,19 @@ impl LocalBoard {
     /// If there is no held piece, the current piece is saved and a new piece is generated.
     /// If there is a held piece, the current piece is swapped with the held piece.
     pub fn save_piece(&mut self) {
-        if self.piece_blocked {
+        if self.piece_blocked || self.spawn_pending {
             return;
         }
         let cur_piece = self.cur_piece.clone();
         if self.held_piece.is_none() {
             self.piece_num += 1;
-            self.cur_piece = self
+            let piece = self
                 .queue
                 .get_piece(self.piece_num)
-                .expect("Should be pieces")
-                .try_into()
-                .unwrap();
+                .expect("Should be pieces");
+            self.cur_piece = self.spawned(piece);
         } else {
-            self.cur_piece = self
-                .held_piece
-                .expect("Already checked")
-                .try_into()
-                .unwrap();
+            self.cur_piece = self.spawned(self.held_piece.expect("Already checked"));
         }
         self.piece_blocked = true;
         self.held_piece = Some(cur_piece.piece());
@@ -744,6 +804,36 @@ impl LocalBoard {
         self.strategy = strategy;
     }
 
+    /// Changes whether the next piece spawns as soon as the current one is fixed. Otherwise
+    /// it waits for `spawn_piece` to be called.
+    pub fn change_spawn_on_lock(&mut self, spawn_on_lock: bool) {
+        self.spawn_on_lock = spawn_on_lock;
+    }
+
+    /// Changes the rotation system used to turn and kick the pieces. The current piece is spawned
+    /// again in the rotation states of the new system.
+    pub fn change_rotation_system(&mut self, rotation_system: RotationSystem) {
+        self.rotation_system = rotation_system;
+        self.cur_piece = self.spawned(self.cur_piece.piece());
+    }
+
+    /// Returns the rotation system used to turn and kick the pieces.
+    pub fn rotation_system(&self) -> RotationSystem {
+        self.rotation_system
+    }
+
+    /// Changes whether the pieces are played at double scale, on a field of half the width and
+    /// height of the board. The current piece is spawned again on the new field.
+    pub fn change_big(&mut self, big: bool) {
+        self.big = big;
+        self.cur_piece = self.spawned(self.cur_piece.piece());
+    }
+
+    /// Reseeds the random number generator used to choose the trash columns.
+    pub fn change_seed(&mut self, seed: u64) {
+        self.rng = SeededRng::new(seed);
+    }
+
     /// Returns the currently held piece.
     pub fn held_piece(&self) -> Option<Piece> {
         self.held_piece
@@ -763,7 +853,7 @@ impl LocalBoard {
         if self.trash_lines_queue.is_empty() {
             self.trash_lines_queue.push((
                 number_of_trash_received,
-                rand::rng().random_range(0..BOARD_WIDTH) as u8,
+                self.rng.random_range(0..self.width()) as u8,
             ));
             return;
         }
@@ -774,11 +864,11 @@ impl LocalBoard {
         if sum < 5 {
             self.trash_lines_queue.push((
                 number_of_trash_received,
-                rand::rng().random_range(0..BOARD_WIDTH) as u8,
+                self.rng.random_range(0..self.width()) as u8,
             ));
             return;
         }
-        let mut repetitions: HashMap<u8, u8> = HashMap::new();
+        let mut repetitions: BTreeMap<u8, u8> = BTreeMap::new();
         for (lines, column) in &self.trash_lines_queue {
             *repetitions.entry(*column).or_insert(0) += lines;
         }
@@ -788,11 +878,51 @@ impl LocalBoard {
         } else {
             self.trash_lines_queue.push((
                 number_of_trash_received,
-                rand::rng().random_range(0..BOARD_WIDTH) as u8,
+                self.rng.random_range(0..self.width()) as u8,
             ));
         }
     }
 
+    /// Adds cheese lines, trash lines with a hole each, to the queue of trash lines.
+    ///
+    /// The lines are set in the board right away if the next piece has already spawned,
+    /// otherwise they are set when it spawns.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The number of cheese lines to add.
+    /// * `messiness` - The percentage of lines whose hole is in a different column than the
+    ///   one of the line added before.
+    pub fn add_cheese(&mut self, lines: u8, messiness: u8) {
+        for _ in 0..lines {
+            let column = match self.cheese_column {
+                Some(column) if self.rng.random_range(0..100) >= messiness => column,
+                Some(column) => {
+                    let other = self.rng.random_range(0..self.width() - 1) as u8;
+                    if other >= column { other + 1 } else { other }
+                }
+                None => self.rng.random_range(0..self.width()) as u8,
+            };
+            self.cheese_column = Some(column);
+            self.trash_lines_queue.push((1, column));
+        }
+        if !self.spawn_pending {
+            self.set_trash_in_board();
+        }
+    }
+    /// Returns the number of lines with trash on the board or waiting in the queue, without
+    /// counting the completed lines still on the board.
+    pub fn garbage_lines(&self) -> u32 {
+        let pending = self.pending_lines.unwrap_or([-128; 4]);
+        let on_board = (-self.height()..self.height())
+            .filter(|y| !pending.contains(y) && self.row_has_trash(*y))
+            .count() as u32;
+        on_board + self.num_of_trash_lines() as u32
+    }
+    /// Returns the number of cleared lines that had trash in them.
+    pub fn garbage_cleared(&self) -> u32 {
+        self.garbage_cleared
+    }
     /// Counters the trash lines with the lines cleared.
     ///
     /// If the player clears lines, they can be used to reduce the number of trash lines in the queue.
@@ -838,6 +968,17 @@ impl LocalBoard {
         self.piece_blocked
     }
 
+    /// Returns a boolean indicating whether the current piece has been fixed and the next one
+    /// hasn't spawned.
+    pub fn spawn_pending(&self) -> bool {
+        self.spawn_pending
+    }
+
+    /// Returns a boolean indicating whether there are completed lines still on the board.
+    pub fn lines_pending(&self) -> bool {
+        self.pending_lines.is_some()
+    }
+
     /// Returns a boolean indicating whether a line has been cleared.
     pub fn line_cleared(&self) -> bool {
         self.line_cleared
@@ -847,6 +988,9 @@ impl LocalBoard {
     ///
     /// Block out occurs when the current piece is partially above the board and cannot move down any further.
     fn block_out(&self) -> bool {
+        if self.spawn_pending {
+            return false;
+        }
         for (x, y) in self.cur_piece.get_coords() {
             if y >= 0 {
                 return false;
@@ -860,37 +1004,49 @@ impl LocalBoard {
 
     /// Gets the cell at the given coordinates from the main board.
     fn get_cell_from_main_board(&self, x: i16, y: i16) -> Cell {
-        self.cells[(y * BOARD_WIDTH + x) as usize]
+        self.cells[(y * self.width() + x) as usize]
+    }
+
+    /// Returns whether the cell at the given coordinates is outside the field and its buffer or
+    /// is full.
+    fn cell_blocked(&self, x: i16, y: i16) -> bool {
+        if !(0..self.width()).contains(&x) || !(-self.height()..self.height()).contains(&y) {
+            return true;
+        }
+        let cell = if y >= 0 {
+            self.get_cell_from_main_board(x, y)
+        } else {
+            self.get_cell_from_buffer_board(x, y)
+        };
+        matches!(cell, Cell::Full(_))
     }
 
     /// Sets the cell at the given coordinates in the main board.
     fn set_cell_in_main_board(&mut self, x: i16, y: i16, cell: Cell) {
-        self.cells[(y * BOARD_WIDTH + x) as usize] = cell;
+        self.cells[(y * self.width() + x) as usize] = cell;
     }
 
     /// Gets the cell at the given coordinates from the buffer board.
     fn get_cell_from_buffer_board(&self, x: i16, y: i16) -> Cell {
-        self.buffer[((BOARD_HEIGHT + y/* y is less than 0 */) * BOARD_WIDTH + x) as usize]
+        self.buffer[((self.height() + y/* y is less than 0 */) * self.width() + x) as usize]
     }
 
     /// Sets the cell at the given coordinates in the buffer board.
     fn set_cell_in_buffer_board(&mut self, x: i16, y: i16, cell: Cell) {
-        self.buffer[((BOARD_HEIGHT + y) * BOARD_WIDTH + x) as usize] = cell;
+        self.buffer[((self.height() + y) * self.width() + x) as usize] = cell;
     }
 
     /// Gets the highest piece on the board.
     fn get_highest_piece(&self) -> Option<(i16, i16)> {
-        for (i, el) in self.buffer.iter().enumerate() {
+        let (width, area) = (self.width(), self.area());
+        for (i, el) in self.buffer[..area].iter().enumerate() {
             if *el != Cell::Empty {
-                return Some((
-                    i as i16 % BOARD_WIDTH,
-                    (i as i16 / BOARD_WIDTH) - BOARD_HEIGHT,
-                ));
+                return Some((i as i16 % width, (i as i16 / width) - self.height()));
             }
         }
-        for (i, el) in self.cells.iter().enumerate() {
+        for (i, el) in self.cells[..area].iter().enumerate() {
             if *el != Cell::Empty {
-                return Some((i as i16 % BOARD_WIDTH, i as i16 / BOARD_WIDTH));
+                return Some((i as i16 % width, i as i16 / width));
             }
         }
         None
@@ -905,6 +1061,12 @@ impl LocalBoard {
         self.clear_pattern = ClearLinePattern::None;
         pattern_tmp
     }
+    /// Returns whether the last line clear was a perfect clear.
+    pub fn perfect_clear(&mut self) -> bool {
+        let perfect_clear_tmp = self.perfect_clear;
+        self.perfect_clear = false;
+        perfect_clear_tmp
+    }
     /// Returns the number of lines completed.
     pub fn lines_completed(&self) -> u32 {
         self.lines_cleared
@@ -933,17 +1095,275 @@ impl LocalBoard {
     pub fn get_queue(&mut self) -> Vec<Piece> {
         self.queue.get_pieces()
     }
+    /// Returns a snapshot of the state of the board, so the game can be resumed later.
+    pub fn snapshot(&self) -> BoardSnapshot {
+        BoardSnapshot {
+            held_piece: self.held_piece,
+            cur_piece: self.cur_piece.state(),
+            strategy: self.strategy,
+            piece_num: self.piece_num,
+            trash_lines_queue: self.trash_lines_queue.clone(),
+            rng_seed: self.rng.seed(),
+            rng_draws: self.rng.draws(),
+            cells: self.cells.to_vec(),
+            buffer: self.buffer.to_vec(),
+            lock_out: self.lock_out,
+            top_out: self.top_out,
+            piece_blocked: self.piece_blocked,
+            lines_cleared: self.lines_cleared,
+            garbage_cleared: self.garbage_cleared,
+            cheese_column: self.cheese_column,
+            rotation: self.rotation,
+            rotation_option: self.rotation_option,
+            rotation_variation: self.rotation_variation,
+            spawn_pending: self.spawn_pending,
+            pending_lines: self.pending_lines,
+            locks: self.locks,
+            lock_stamps: self.lock_stamps.to_vec(),
+        }
+    }
+    /// Restores the state of the board from a snapshot.
+    ///
+    /// The queue must be the same the board had when the snapshot was taken, as the snapshot
+    /// only keeps the position in it.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - The snapshot to restore.
+    pub fn restore(&mut self, snapshot: BoardSnapshot) {
+        self.held_piece = snapshot.held_piece;
+        self.cur_piece = snapshot
+            .cur_piece
+            .try_into()
+            .expect("Snapshots are only taken of real pieces");
+        self.strategy = snapshot.strategy;
+        self.piece_num = snapshot.piece_num;
+        self.trash_lines_queue = snapshot.trash_lines_queue;
+        self.rng = SeededRng::replayed(snapshot.rng_seed, snapshot.rng_draws);
+        self.cells = snapshot
+            .cells
+            .try_into()
+            .expect("The snapshot must have the size of the board");
+        self.buffer = snapshot
+            .buffer
+            .try_into()
+            .expect("The snapshot must have the size of the board");
+        self.lock_out = snapshot.lock_out;
+        self.top_out = snapshot.top_out;
+        self.piece_blocked = snapshot.piece_blocked;
+        self.lines_cleared = snapshot.lines_cleared;
+        self.garbage_cleared = snapshot.garbage_cleared;
+        self.cheese_column = snapshot.cheese_column;
+        self.rotation = snapshot.rotation;
+        self.rotation_option = snapshot.rotation_option;
+        self.rotation_variation = snapshot.rotation_variation;
+        self.spawn_pending = snapshot.spawn_pending;
+        self.pending_lines = snapshot.pending_lines;
+        self.locks = snapshot.locks;
+        self.lock_stamps = snapshot
+            .lock_stamps
+            .try_into()
+            .expect("The snapshot must have a lock for each cell of the board");
+    }
+    /// Returns the state of the field as bytes, the buffer board followed by the main board,
+    /// with a byte for each cell of the field.
+    fn field_state(&self) -> Vec<u8> {
+        let area = self.area();
+        let mut buf = vec![Cell::Empty.string_representation() as u8; area * 2];
+        // Between a piece being fixed and the next one spawning there's no piece to draw
+        let cur_piece_coords = if self.spawn_pending {
+            vec![]
+        } else {
+            self.cur_piece.get_coords()
+        };
+        let ghost_piece_coords = if self.spawn_pending {
+            vec![]
+        } else {
+            self.ghost_piece(self.cur_piece.clone()).get_coords()
+        };
+        for (x, y) in ghost_piece_coords {
+            buf[self.stamp_index(x, y)] = Piece::Ghost.string_representation() as u8;
+        }
+        for (i, el) in self.buffer[..area].iter().enumerate() {
+            if *el == Cell::Empty {
+                continue;
+            }
+            buf[i] = el.string_representation() as u8;
+        }
+
+        for (i, el) in self.cells[..area].iter().enumerate() {
+            if *el == Cell::Empty {
+                continue;
+            }
+            buf[i + area] = el.string_representation() as u8;
+        }
+
+        for (x, y) in cur_piece_coords {
+            buf[self.stamp_index(x, y)] = self.cur_piece.piece().string_representation() as u8;
+        }
+
+        buf
+    }
+    /// Returns the state of the field as a string at the resolution of the board, each cell of
+    /// a big field taking a square of cells.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_state` - The state of the field, as returned by `field_state`.
+    fn scaled_state(&self, field_state: Vec<u8>) -> String {
+        let scale = self.scale() as usize;
+        let width = self.width() as usize;
+        let buf = if scale == 1 {
+            field_state
+        } else {
+            (0..(BOARD_HEIGHT * BOARD_WIDTH * 2) as usize)
+                .map(|i| {
+                    let (row, column) = (i / BOARD_WIDTH as usize, i % BOARD_WIDTH as usize);
+                    field_state[row / scale * width + column / scale]
+                })
+                .collect()
+        };
+        String::from_utf8(buf).expect("Should be valid UTF as I just wrote it")
+    }
+    /// Returns the number of pieces that have been locked on the board.
+    pub fn locks(&self) -> usize {
+        self.locks
+    }
+    /// Returns the position in the field state of the cell at the given coordinates.
+    fn stamp_index(&self, x: i16, y: i16) -> usize {
+        ((y + self.height()) * self.width() + x) as usize
+    }
+    /// Returns the number of cells of the board each side of a cell of the field takes.
+    fn scale(&self) -> i16 {
+        if self.big { BIG_SCALE } else { 1 }
+    }
+    /// Returns the width of the field the pieces are played on.
+    fn width(&self) -> i16 {
+        BOARD_WIDTH / self.scale()
+    }
+    /// Returns the height of the field the pieces are played on, the same as its buffer.
+    fn height(&self) -> i16 {
+        BOARD_HEIGHT / self.scale()
+    }
+    /// Returns the number of cells of the field, the same as its buffer.
+    fn area(&self) -> usize {
+        (self.width() * self.height()) as usize
+    }
+    /// Returns the given piece at its spawn position, centred on the field, in the orientation
+    /// the rotation system spawns it in.
+    fn spawned(&self, piece: Piece) -> Box<dyn MovingPiece> {
+        let mut moving: Box<dyn MovingPiece> = piece.try_into().unwrap();
+        let offset = (BOARD_WIDTH - self.width()) / 2;
+        moving.place(
+            moving.x() - offset,
+            moving.y(),
+            self.rotation_system.spawn_orientation(piece),
+        );
+        moving
+    }
+    /// Returns the current state of the board as a string, with the locked cells shown as
+    /// given.
+    ///
+    /// Faded cells are written in lowercase, hidden ones as empty and the ones shown with a
+    /// single colour as `M`.
+    ///
+    /// # Arguments
+    ///
+    /// * `shown` - Returns how a locked cell is shown from the number of the lock that placed
+    ///   it, or `None` when it is not known.
+    /// * `ghost` - Whether the ghost piece is shown.
+    pub fn masked_board_state(
+        &self,
+        shown: impl Fn(Option<usize>) -> CellShown,
+        ghost: bool,
+    ) -> String {
+        let mut buf = self.field_state();
+        let area = self.area();
+        let empty = Cell::Empty.string_representation() as u8;
+        let cur_piece_cells: Vec<usize> = if self.spawn_pending {
+            vec![]
+        } else {
+            self.cur_piece
+                .get_coords()
+                .into_iter()
+                .map(|(x, y)| self.stamp_index(x, y))
+                .collect()
+        };
+        for (i, cell) in self.buffer[..area]
+            .iter()
+            .chain(self.cells[..area].iter())
+            .enumerate()
+        {
+            if *cell == Cell::Empty || cur_piece_cells.contains(&i) {
+                continue;
+            }
+            let lock = Some(self.lock_stamps[i]).filter(|lock| *lock != 0);
+            buf[i] = match shown(lock) {
+                CellShown::Normal => buf[i],
+                CellShown::Faded => buf[i].to_ascii_lowercase(),
+                CellShown::Hidden => empty,
+                CellShown::Monochrome => MONOCHROME_CELL,
+            };
+        }
+        if !ghost {
+            let ghost = Piece::Ghost.string_representation() as u8;
+            buf.iter_mut()
+                .filter(|cell| **cell == ghost)
+                .for_each(|cell| *cell = empty);
+        }
+        self.scaled_state(buf)
+    }
 }
 
-/// `RotationOption` represents the different rotation options for a piece.
-#[derive(Debug, Clone, Copy)]
-enum RotationOption {
-    /// Rotate the piece clockwise.
-    ClockWise,
-    /// Rotate the piece counterclockwise.
-    CounterClockWise,
-    /// Rotate the piece 180 degrees.
-    Full,
+/// `BoardSnapshot` represents the state of a `LocalBoard` at a given moment, so it can be stored.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BoardSnapshot {
+    /// The currently held piece.
+    held_piece: Option<Piece>,
+    /// The currently moving piece, with its position and orientation.
+    cur_piece: PieceState,
+    /// The strategy being used by the board.
+    strategy: Strategy,
+    /// The position of the current piece in the queue.
+    piece_num: usize,
+    /// The queue of trash lines to be added to the board.
+    trash_lines_queue: Vec<(u8, u8)>,
+    /// The seed of the random number generator used to choose the trash columns.
+    rng_seed: u64,
+    /// The number of words drawn from the random number generator.
+    rng_draws: u64,
+    /// The cells of the main board.
+    cells: Vec<Cell>,
+    /// The cells of the buffer board.
+    buffer: Vec<Cell>,
+    /// A boolean indicating whether the player has locked out.
+    lock_out: bool,
+    /// A boolean indicating whether the player has topped out.
+    top_out: bool,
+    /// A boolean indicating whether the current piece is blocked.
+    piece_blocked: bool,
+    /// The number of lines that have been cleared.
+    lines_cleared: u32,
+    /// The number of cleared lines that had trash in them.
+    #[serde(default)]
+    garbage_cleared: u32,
+    /// The column of the hole of the last cheese line added.
+    #[serde(default)]
+    cheese_column: Option<u8>,
+    /// A boolean indicating whether the piece has been rotated.
+    rotation: bool,
+    /// The rotation option used.
+    rotation_option: RotationOption,
+    /// The variation of the rotation used.
+    rotation_variation: i16,
+    /// A boolean indicating whether the current piece has been fixed and the next one hasn't spawned.
+    spawn_pending: bool,
+    /// The y coordinates of the completed lines that are still on the board.
+    pending_lines: Option<[i16; 4]>,
+    /// The number of pieces that have been locked on the board.
+    locks: usize,
+    /// The number of the lock that placed each cell of the buffer and the main board.
+    lock_stamps: Vec<usize>,
 }
 
 /// `ClearLinePattern` represents the different patterns of lines that can be cleared.
@@ -971,6 +1391,14 @@ pub enum ClearLinePattern {
     MiniTSpin,
     /// A mini T-spin single line clear was performed.
     MiniTSpinSingle,
+    /// A spin with a piece other than T was performed.
+    Spin(Piece),
+    /// A spin single line clear was performed with a piece other than T.
+    SpinSingle(Piece),
+    /// A spin double line clear was performed with a piece other than T.
+    SpinDouble(Piece),
+    /// A spin triple line clear was performed with a piece other than T.
+    SpinTriple(Piece),
 }
 
 #[cfg(test)]
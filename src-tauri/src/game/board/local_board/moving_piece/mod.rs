@@ -16,10 +16,12 @@ use moving_piece_s::MovingPieceS;
 use moving_piece_t::MovingPieceT;
 use moving_piece_z::MovingPieceZ;
 
+use serde::{Deserialize, Serialize};
+
 use crate::game::pieces::Piece;
 
 /// `MovingPiece` trait defines the behavior of a moving piece on the board.
-pub trait MovingPiece: Send + Sync + std::fmt::Debug + rotations::Rotations + Any {
+pub trait MovingPiece: Send + Sync + std::fmt::Debug + Any {
     /// Moves the piece down by one row.
     fn move_down(&mut self);
     /// Moves the piece up by one row.
@@ -38,29 +40,10 @@ pub trait MovingPiece: Send + Sync + std::fmt::Debug + rotations::Rotations + An
     fn get_coords(&self) -> Vec<(i16, i16)>;
     /// Returns the `Piece` type of the moving piece.
     fn piece(&self) -> Piece;
-    /// Rotates the piece clockwise based on the given rotation option.
-    fn rotate_clockwise(&mut self, option: RotationOption) {
-        match option {
-            RotationOption::First => self.first_option_clockwise(),
-            RotationOption::Second => self.second_option_clockwise(),
-            RotationOption::Third => self.third_option_clockwise(),
-            RotationOption::Fourth => self.fourth_option_clockwise(),
-            RotationOption::Fifth => self.fifth_option_clockwise(),
-        }
-    }
-
-    /// Rotates the piece counterclockwise based on the given rotation option.
-    fn rotate_counterclockwise(&mut self, option: RotationOption) {
-        match option {
-            RotationOption::First => self.first_option_counterclockwise(),
-            RotationOption::Second => self.second_option_counterclockwise(),
-            RotationOption::Third => self.third_option_counterclockwise(),
-            RotationOption::Fourth => self.fourth_option_counterclockwise(),
-            RotationOption::Fifth => self.fifth_option_counterclockwise(),
-        }
-    }
-    /// Rotates the piece 180 degrees based on the given rotation option.
-    fn rotate_full(&mut self, option: RotationOption);
+    /// Rotates the piece to the given orientation, shifting it by the given kick offset.
+    ///
+    /// The kick is applied on top of the shift needed to keep the piece inside its rotation box.
+    fn rotate(&mut self, orientation: Orientation, kick: (i16, i16));
 
     /// Creates a clone of the `MovingPiece` as a boxed trait object.
     fn clone_box(&self) -> Box<dyn MovingPiece>;
@@ -71,45 +54,25 @@ pub trait MovingPiece: Send + Sync + std::fmt::Debug + rotations::Rotations + An
     fn y(&self) -> i16;
     /// Returns the orientation of the piece.
     fn orientation(&self) -> Orientation;
+    /// Places the piece at the given position and orientation, without checking the board.
+    fn place(&mut self, x: i16, y: i16, orientation: Orientation);
+
+    /// Returns the state of the piece, which can be serialized.
+    fn state(&self) -> PieceState {
+        PieceState {
+            piece: self.piece(),
+            x: self.x(),
+            y: self.y(),
+            orientation: self.orientation(),
+        }
+    }
 
     /// Returns a reference to the underlying type as `Any`.
     fn as_any(&self) -> Box<dyn Any>;
 }
 
-mod rotations {
-    /// `Rotations` trait defines the different rotation options for a moving piece.
-    ///
-    /// Rules for implementing:
-    /// Rule 1: the clockwise rotation must cancel out with its counterclockwise rotation counterpart
-    /// Rule 2: all the rotations in one implementation must cancel each other (for example, if the north and east variants add 3 each to x, the south and west ones must substract 3 each).
-    /// This rule has only one exception, the T piece doesn't implement some levels with certain rotations
-    pub trait Rotations {
-        /// Performs the first option for clockwise rotation.
-        fn first_option_clockwise(&mut self);
-        /// Performs the second option for clockwise rotation.
-        fn second_option_clockwise(&mut self);
-        /// Performs the third option for clockwise rotation.
-        fn third_option_clockwise(&mut self);
-        /// Performs the fourth option for clockwise rotation.
-        fn fourth_option_clockwise(&mut self);
-        /// Performs the fifth option for clockwise rotation.
-        fn fifth_option_clockwise(&mut self);
-
-        /// Performs the first option for counterclockwise rotation.
-        fn first_option_counterclockwise(&mut self);
-        /// Performs the second option for counterclockwise rotation.
-        fn second_option_counterclockwise(&mut self);
-        /// Performs the third option for counterclockwise rotation.
-        fn third_option_counterclockwise(&mut self);
-        /// Performs the fourth option for counterclockwise rotation.
-        fn fourth_option_counterclockwise(&mut self);
-        /// Performs the fifth option for counterclockwise rotation.
-        fn fifth_option_counterclockwise(&mut self);
-    }
-}
-
 /// `Orientation` represents the orientation of a moving piece.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Orientation {
     /// The piece is facing north.
     North,
@@ -121,19 +84,16 @@ pub enum Orientation {
     West,
 }
 
-/// `RotationOption` represents the different options for rotating a piece.
-#[derive(Debug, Clone, Copy)]
-pub enum RotationOption {
-    /// The first rotation option.
-    First,
-    /// The second rotation option.
-    Second,
-    /// The third rotation option.
-    Third,
-    /// The fourth rotation option.
-    Fourth,
-    /// The fifth rotation option.
-    Fifth,
+impl Orientation {
+    /// Returns the index of the orientation, in clockwise order starting from north.
+    pub fn index(&self) -> usize {
+        match self {
+            Orientation::North => 0,
+            Orientation::East => 1,
+            Orientation::South => 2,
+            Orientation::West => 3,
+        }
+    }
 }
 
 impl TryFrom<Piece> for Box<dyn MovingPiece> {
@@ -153,47 +113,50 @@ impl TryFrom<Piece> for Box<dyn MovingPiece> {
     }
 }
 
-impl From<u8> for RotationOption {
-    fn from(value: u8) -> Self {
-        match value {
-            0..=1 => RotationOption::First,
-            2 => RotationOption::Second,
-            3 => RotationOption::Third,
-            4 => RotationOption::Fourth,
-            _ => RotationOption::Fifth,
-        }
-    }
+/// `PieceState` represents the position and orientation of a moving piece, so it can be stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PieceState {
+    /// The type of the piece.
+    piece: Piece,
+    /// The x coordinate of the piece.
+    x: i16,
+    /// The y coordinate of the piece.
+    y: i16,
+    /// The orientation of the piece.
+    orientation: Orientation,
 }
 
-impl Clone for Box<dyn MovingPiece> {
-    fn clone(&self) -> Self {
-        self.clone_box()
-    }
-}
+impl TryFrom<PieceState> for Box<dyn MovingPiece> {
+    type Error = ();
 
-fn change_orientation_clockwise(orientation: Orientation) -> Orientation {
-    match orientation {
-        Orientation::North => Orientation::East,
-        Orientation::East => Orientation::South,
-        Orientation::South => Orientation::West,
-        Orientation::West => Orientation::North,
+    fn try_from(value: PieceState) -> Result<Self, Self::Error> {
+        let mut piece: Box<dyn MovingPiece> = value.piece.try_into()?;
+        piece.place(value.x, value.y, value.orientation);
+        Ok(piece)
     }
 }
 
-fn change_orientation_counterclockwise(orientation: Orientation) -> Orientation {
-    match orientation {
-        Orientation::North => Orientation::West,
-        Orientation::East => Orientation::North,
-        Orientation::South => Orientation::East,
-        Orientation::West => Orientation::South,
+impl Clone for Box<dyn MovingPiece> {
+    fn clone(&self) -> Self {
+        self.clone_box()
     }
 }
 
-fn change_rotation_full(orientation: Orientation) -> Orientation {
-    match orientation {
-        Orientation::North => Orientation::South,
-        Orientation::East => Orientation::West,
-        Orientation::South => Orientation::North,
-        Orientation::West => Orientation::East,
-    }
+/// Computes the position of a piece after rotating it from one orientation to another.
+///
+/// `box_offsets` holds, for each orientation, where the piece origin sits inside its rotation box,
+/// so the piece turns around the box instead of around its origin. The kick is added afterwards.
+fn rotated_position(
+    position: (i16, i16),
+    box_offsets: &[(i16, i16); 4],
+    from: Orientation,
+    to: Orientation,
+    kick: (i16, i16),
+) -> (i16, i16) {
+    let from_offset = box_offsets[from.index()];
+    let to_offset = box_offsets[to.index()];
+    (
+        position.0 - from_offset.0 + to_offset.0 + kick.0,
+        position.1 - from_offset.1 + to_offset.1 + kick.1,
+    )
 }
@@ -1,9 +1,11 @@
 use crate::game::pieces::Piece;
 
-use super::{MovingPiece, Orientation, RotationOption, rotations::Rotations};
+use super::{MovingPiece, Orientation};
 
 const START_X: i16 = 3;
 const START_Y: i16 = -2;
+/// Position of the piece origin inside its rotation box, by orientation (north, east, south, west).
+const BOX_OFFSETS: [(i16, i16); 4] = [(0, 0), (1, 0), (0, 1), (0, 0)];
 
 #[derive(Debug, Clone, Copy)]
 pub struct MovingPieceJ {
@@ -130,24 +132,15 @@ impl MovingPiece for MovingPieceJ {
         Piece::J
     }
 
-    fn rotate_full(&mut self, option: RotationOption) {
-        if let RotationOption::First = option {
-            match self.orientation {
-                Orientation::North => {
-                    self.y += 1;
-                }
-                Orientation::South => {
-                    self.y -= 1;
-                }
-                Orientation::East => {
-                    self.x -= 1;
-                }
-                Orientation::West => {
-                    self.x += 1;
-                }
-            }
-        }
-        self.orientation = super::change_rotation_full(self.orientation);
+    fn rotate(&mut self, orientation: Orientation, kick: (i16, i16)) {
+        (self.x, self.y) = super::rotated_position(
+            (self.x, self.y),
+            &BOX_OFFSETS,
+            self.orientation,
+            orientation,
+            kick,
+        );
+        self.orientation = orientation;
     }
 
     fn clone_box(&self) -> Box<dyn MovingPiece> {
@@ -166,197 +159,13 @@ impl MovingPiece for MovingPieceJ {
         self.orientation
     }
 
-    fn as_any(&self) -> Box<dyn std::any::Any> {
-        Box::new(*self)
-    }
-}
-
-impl Rotations for MovingPieceJ {
-    fn first_option_clockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.x += 1;
-            }
-            Orientation::East => {
-                self.x -= 1;
-                self.y += 1;
-            }
-            Orientation::South => {
-                self.y -= 1;
-            }
-            Orientation::West => {}
-        }
-        self.orientation = super::change_orientation_clockwise(self.orientation);
-    }
-
-    fn second_option_clockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {}
-            Orientation::East => {
-                self.y += 1;
-            }
-            Orientation::South => {
-                self.x += 1;
-                self.y -= 1;
-            }
-            Orientation::West => {
-                self.x -= 1;
-            }
-        }
-        self.orientation = super::change_orientation_clockwise(self.orientation);
-    }
-
-    fn third_option_clockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.y -= 1;
-            }
-            Orientation::East => {
-                self.y += 2;
-            }
-            Orientation::South => {
-                self.x += 1;
-                self.y -= 2;
-            }
-            Orientation::West => {
-                self.x -= 1;
-                self.y += 1;
-            }
-        }
-        self.orientation = super::change_orientation_clockwise(self.orientation);
-    }
-
-    fn fourth_option_clockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.x += 1;
-                self.y += 2;
-            }
-            Orientation::East => {
-                self.x -= 1;
-                self.y -= 1;
-            }
-            Orientation::South => {
-                self.y += 1;
-            }
-            Orientation::West => {
-                self.y -= 2;
-            }
-        }
-        self.orientation = super::change_orientation_clockwise(self.orientation);
-    }
-
-    fn fifth_option_clockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.y += 2;
-            }
-            Orientation::East => {
-                self.y -= 1;
-            }
-            Orientation::South => {
-                self.x += 1;
-                self.y += 1;
-            }
-            Orientation::West => {
-                self.x -= 1;
-                self.y -= 2;
-            }
-        }
-        self.orientation = super::change_orientation_clockwise(self.orientation);
-    }
-
-    fn first_option_counterclockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {}
-            Orientation::East => {
-                self.x -= 1;
-            }
-            Orientation::South => {
-                self.x += 1;
-                self.y -= 1;
-            }
-            Orientation::West => {
-                self.y += 1;
-            }
-        }
-        self.orientation = super::change_orientation_counterclockwise(self.orientation);
+    fn place(&mut self, x: i16, y: i16, orientation: Orientation) {
+        self.x = x;
+        self.y = y;
+        self.orientation = orientation;
     }
 
-    fn second_option_counterclockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.x += 1;
-            }
-            Orientation::East => {}
-            Orientation::South => {
-                self.y -= 1;
-            }
-            Orientation::West => {
-                self.x -= 1;
-                self.y += 1;
-            }
-        }
-        self.orientation = super::change_orientation_counterclockwise(self.orientation);
-    }
-
-    fn third_option_counterclockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.x += 1;
-                self.y -= 1;
-            }
-            Orientation::East => {
-                self.y += 1;
-            }
-            Orientation::South => {
-                self.y -= 2;
-            }
-            Orientation::West => {
-                self.x -= 1;
-                self.y += 2;
-            }
-        }
-        self.orientation = super::change_orientation_counterclockwise(self.orientation);
-    }
-
-    fn fourth_option_counterclockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.y += 2;
-            }
-            Orientation::East => {
-                self.x -= 1;
-                self.y -= 2;
-            }
-            Orientation::South => {
-                self.x += 1;
-                self.y += 1;
-            }
-            Orientation::West => {
-                self.y -= 1;
-            }
-        }
-        self.orientation = super::change_orientation_counterclockwise(self.orientation);
-    }
-
-    fn fifth_option_counterclockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.x += 1;
-                self.y += 2;
-            }
-            Orientation::East => {
-                self.y -= 2;
-            }
-            Orientation::South => {
-                self.y += 1;
-            }
-            Orientation::West => {
-                self.x -= 1;
-                self.y -= 1;
-            }
-        }
-        self.orientation = super::change_orientation_counterclockwise(self.orientation);
+    fn as_any(&self) -> Box<dyn std::any::Any> {
+        Box::new(*self)
     }
 }
@@ -1,9 +1,11 @@
 use crate::game::pieces::Piece;
 
-use super::{MovingPiece, Orientation, rotations::Rotations};
+use super::{MovingPiece, Orientation};
 
 const START_X: i16 = 3;
 const START_Y: i16 = -2;
+/// Position of the piece origin inside its rotation box, by orientation (north, east, south, west).
+const BOX_OFFSETS: [(i16, i16); 4] = [(0, 0), (1, 0), (0, 1), (0, 0)];
 
 #[derive(Debug, Clone, Copy)]
 pub struct MovingPieceS {
@@ -104,7 +106,16 @@ impl MovingPiece for MovingPieceS {
         Piece::S
     }
 
-    fn rotate_full(&mut self, _option: super::RotationOption) {}
+    fn rotate(&mut self, orientation: Orientation, kick: (i16, i16)) {
+        (self.x, self.y) = super::rotated_position(
+            (self.x, self.y),
+            &BOX_OFFSETS,
+            self.orientation,
+            orientation,
+            kick,
+        );
+        self.orientation = orientation;
+    }
 
     fn clone_box(&self) -> Box<dyn MovingPiece> {
         Box::new(*self)
@@ -122,195 +133,13 @@ impl MovingPiece for MovingPieceS {
         self.orientation
     }
 
-    fn as_any(&self) -> Box<dyn std::any::Any> {
-        Box::new(*self)
-    }
-}
-
-impl Rotations for MovingPieceS {
-    fn first_option_clockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.x += 1;
-            }
-            Orientation::East => {
-                self.x -= 1;
-                self.y += 1;
-            }
-            Orientation::South => self.x -= 1,
-            Orientation::West => {}
-        }
-        self.orientation = super::change_orientation_clockwise(self.orientation);
-    }
-
-    fn second_option_clockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {}
-            Orientation::East => {
-                self.y += 1;
-            }
-            Orientation::South => {
-                self.x += 1;
-                self.y -= 1;
-            }
-            Orientation::West => {
-                self.x += 1;
-            }
-        }
-        self.orientation = super::change_orientation_clockwise(self.orientation);
-    }
-
-    fn third_option_clockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.y -= 1;
-            }
-            Orientation::East => {
-                self.y += 2;
-            }
-            Orientation::South => {
-                self.x += 1;
-                self.y -= 2;
-            }
-            Orientation::West => {
-                self.x -= 1;
-                self.y += 1;
-            }
-        }
-        self.orientation = super::change_orientation_clockwise(self.orientation);
-    }
-
-    fn fourth_option_clockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.x += 1;
-                self.y += 2;
-            }
-            Orientation::East => {
-                self.x -= 1;
-                self.y -= 1;
-            }
-            Orientation::South => {
-                self.y += 1;
-            }
-            Orientation::West => {
-                self.y -= 2;
-            }
-        }
-        self.orientation = super::change_orientation_clockwise(self.orientation);
+    fn place(&mut self, x: i16, y: i16, orientation: Orientation) {
+        self.x = x;
+        self.y = y;
+        self.orientation = orientation;
     }
 
-    fn fifth_option_clockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.y += 2;
-            }
-            Orientation::East => {
-                self.y -= 1;
-            }
-            Orientation::South => {
-                self.x += 1;
-                self.y += 1;
-            }
-            Orientation::West => {
-                self.x -= 1;
-                self.y -= 2;
-            }
-        }
-        self.orientation = super::change_orientation_clockwise(self.orientation);
-    }
-
-    fn first_option_counterclockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {}
-            Orientation::East => {
-                self.x -= 1;
-            }
-            Orientation::South => {
-                self.x += 1;
-                self.y -= 1;
-            }
-            Orientation::West => {
-                self.y += 1;
-            }
-        }
-        self.orientation = super::change_orientation_counterclockwise(self.orientation);
-    }
-
-    fn second_option_counterclockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.x += 1;
-            }
-            Orientation::East => {}
-            Orientation::South => {
-                self.y -= 1;
-            }
-            Orientation::West => {
-                self.x -= 1;
-                self.y += 1;
-            }
-        }
-        self.orientation = super::change_orientation_counterclockwise(self.orientation)
-    }
-
-    fn third_option_counterclockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.x += 1;
-                self.y -= 1;
-            }
-            Orientation::East => {
-                self.y += 1;
-            }
-            Orientation::South => {
-                self.y -= 2;
-            }
-            Orientation::West => {
-                self.x -= 1;
-                self.y += 2;
-            }
-        }
-        self.orientation = super::change_orientation_counterclockwise(self.orientation);
-    }
-
-    fn fourth_option_counterclockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.y += 2;
-            }
-            Orientation::East => {
-                self.x -= 1;
-                self.y -= 2;
-            }
-            Orientation::South => {
-                self.x += 1;
-                self.y += 1;
-            }
-            Orientation::West => {
-                self.y -= 1;
-            }
-        }
-        self.orientation = super::change_orientation_counterclockwise(self.orientation);
-    }
-
-    fn fifth_option_counterclockwise(&mut self) {
-        match self.orientation {
-            Orientation::North => {
-                self.x += 1;
-                self.y += 2;
-            }
-            Orientation::East => {
-                self.y -= 2;
-            }
-            Orientation::South => {
-                self.y += 1;
-            }
-            Orientation::West => {
-                self.x -= 1;
-                self.y -= 1;
-            }
-        }
-        self.orientation = super::change_orientation_counterclockwise(self.orientation);
+    fn as_any(&self) -> Box<dyn std::any::Any> {
+        Box::new(*self)
     }
 }
@@ -0,0 +1,246 @@
+use serde::{Deserialize, Serialize};
+
+use crate::game::pieces::Piece;
+
+use super::moving_piece::Orientation;
+
+/// A list of kick offsets, tested in order until one of them fits.
+///
+/// Offsets are expressed in board coordinates: x grows to the right and y grows downwards.
+type Kicks = &'static [(i16, i16)];
+
+/// `KickTable` holds the kicks tested for every rotation, indexed by the starting orientation
+/// (north, east, south, west).
+#[derive(Debug)]
+struct KickTable {
+    /// Kicks tested when rotating clockwise.
+    clockwise: [Kicks; 4],
+    /// Kicks tested when rotating counterclockwise.
+    counterclockwise: [Kicks; 4],
+    /// Kicks tested when rotating 180 degrees.
+    full: [Kicks; 4],
+}
+
+/// Guideline SRS kicks for the J, L, S, T and Z pieces.
+const SRS_JLSTZ_KICKS: KickTable = KickTable {
+    clockwise: [
+        &[(0, 0), (-1, 0), (-1, -1), (0, 2), (-1, 2)],
+        &[(0, 0), (1, 0), (1, 1), (0, -2), (1, -2)],
+        &[(0, 0), (1, 0), (1, -1), (0, 2), (1, 2)],
+        &[(0, 0), (-1, 0), (-1, 1), (0, -2), (-1, -2)],
+    ],
+    counterclockwise: [
+        &[(0, 0), (1, 0), (1, -1), (0, 2), (1, 2)],
+        &[(0, 0), (1, 0), (1, 1), (0, -2), (1, -2)],
+        &[(0, 0), (-1, 0), (-1, -1), (0, 2), (-1, 2)],
+        &[(0, 0), (-1, 0), (-1, 1), (0, -2), (-1, -2)],
+    ],
+    full: SRS_FULL_KICKS,
+};
+
+/// Guideline SRS kicks for the I piece.
+const SRS_I_KICKS: KickTable = KickTable {
+    clockwise: [
+        &[(0, 0), (-2, 0), (1, 0), (-2, 1), (1, -2)],
+        &[(0, 0), (-1, 0), (2, 0), (-1, -2), (2, 1)],
+        &[(0, 0), (2, 0), (-1, 0), (2, -1), (-1, 2)],
+        &[(0, 0), (1, 0), (-2, 0), (1, 2), (-2, -1)],
+    ],
+    counterclockwise: [
+        &[(0, 0), (-1, 0), (2, 0), (-1, -2), (2, 1)],
+        &[(0, 0), (2, 0), (-1, 0), (2, -1), (-1, 2)],
+        &[(0, 0), (1, 0), (-2, 0), (1, 2), (-2, -1)],
+        &[(0, 0), (-2, 0), (1, 0), (-2, 1), (1, -2)],
+    ],
+    full: SRS_FULL_KICKS,
+};
+
+/// The guideline has no 180 degrees rotation, so only a step back towards the
+/// previous position is tried when the piece doesn't fit.
+const SRS_FULL_KICKS: [Kicks; 4] = [
+    &[(0, 0), (0, -1)],
+    &[(0, 0), (1, 0)],
+    &[(0, 0), (0, 1)],
+    &[(0, 0), (-1, 0)],
+];
+
+/// SRS+ kicks for the J, L, S, T and Z pieces. Same as SRS, with proper 180 degrees kicks.
+const SRS_PLUS_JLSTZ_KICKS: KickTable = KickTable {
+    clockwise: SRS_JLSTZ_KICKS.clockwise,
+    counterclockwise: SRS_JLSTZ_KICKS.counterclockwise,
+    full: SRS_PLUS_FULL_KICKS,
+};
+
+/// SRS+ kicks for the I piece. The I kicks are mirrored so they behave the same on both walls.
+const SRS_PLUS_I_KICKS: KickTable = KickTable {
+    clockwise: [
+        &[(0, 0), (1, 0), (-2, 0), (-2, 1), (1, -2)],
+        &[(0, 0), (-1, 0), (2, 0), (-1, -2), (2, 1)],
+        &[(0, 0), (2, 0), (-1, 0), (2, -1), (-1, 2)],
+        &[(0, 0), (1, 0), (-2, 0), (1, 2), (-2, -1)],
+    ],
+    counterclockwise: [
+        &[(0, 0), (-1, 0), (2, 0), (2, 1), (-1, -2)],
+        &[(0, 0), (-1, 0), (2, 0), (-1, 2), (2, -1)],
+        &[(0, 0), (-2, 0), (1, 0), (-2, -1), (1, 2)],
+        &[(0, 0), (1, 0), (-2, 0), (1, -2), (-2, 1)],
+    ],
+    full: SRS_PLUS_FULL_KICKS,
+};
+
+/// SRS+ 180 degrees kicks, shared by every piece but the O.
+const SRS_PLUS_FULL_KICKS: [Kicks; 4] = [
+    &[(0, 0), (0, -1), (1, -1), (-1, -1), (1, 0), (-1, 0)],
+    &[(0, 0), (1, 0), (1, -2), (1, -1), (0, -2), (0, -1)],
+    &[(0, 0), (0, 1), (-1, 1), (1, 1), (-1, 0), (1, 0)],
+    &[(0, 0), (-1, 0), (-1, -2), (-1, -1), (0, -2), (0, -1)],
+];
+
+/// Arika kicks: one cell to the right, then one to the left. No kicks for 180 degrees.
+const ARS_KICKS: KickTable = KickTable {
+    clockwise: [&[(0, 0), (1, 0), (-1, 0)]; 4],
+    counterclockwise: [&[(0, 0), (1, 0), (-1, 0)]; 4],
+    full: [&[(0, 0)]; 4],
+};
+
+/// Shift of the Arika rotation states from the SRS ones, by orientation (north, east, south,
+/// west), for the J, L and T pieces. They sit at the bottom of their rotation box when flat.
+const ARS_JLT_SHIFTS: [(i16, i16); 4] = [(0, 1), (0, 0), (0, 0), (0, 0)];
+
+/// Shift of the Arika rotation states from the SRS ones for the S piece, which stands in the
+/// left column of its box.
+const ARS_S_SHIFTS: [(i16, i16); 4] = [(0, 1), (-1, 0), (0, 0), (0, 0)];
+
+/// Shift of the Arika rotation states from the SRS ones for the Z piece, which stands in the
+/// right column of its box.
+const ARS_Z_SHIFTS: [(i16, i16); 4] = [(0, 1), (0, 0), (0, 0), (1, 0)];
+
+/// Shift of the Arika rotation states from the SRS ones for the I piece, which lies in the
+/// second row and stands in the third column of its box.
+const ARS_I_SHIFTS: [(i16, i16); 4] = [(0, 0), (0, 0), (0, -1), (1, 0)];
+
+/// Only the basic rotation is tested.
+const NO_KICKS: KickTable = KickTable {
+    clockwise: [&[(0, 0)]; 4],
+    counterclockwise: [&[(0, 0)]; 4],
+    full: [&[(0, 0)]; 4],
+};
+
+/// `RotationSystem` represents the rotation states and the wall kicks used when rotating pieces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RotationSystem {
+    /// Guideline Super Rotation System.
+    #[default]
+    Srs,
+    /// SRS with symmetric I kicks and 180 degrees kicks.
+    SrsPlus,
+    /// Arika Rotation System, as seen in the TGM series. The J, L and T pieces spawn flat side
+    /// up, the pieces rest at the bottom of their rotation box and they only kick one cell to
+    /// the right or to the left. The I piece never kicks, and the J, L and T pieces don't kick
+    /// when the first cell blocking them is in the centre column of their box.
+    Ars,
+    /// Pieces only rotate if they fit in place.
+    NoKicks,
+}
+
+impl RotationSystem {
+    /// Returns the kicks to test, in order, when rotating the given piece from the given orientation.
+    pub(super) fn kicks(&self, piece: Piece, from: Orientation, option: RotationOption) -> Kicks {
+        let table = self.kick_table(piece);
+        let kicks = match option {
+            RotationOption::ClockWise => table.clockwise,
+            RotationOption::CounterClockWise => table.counterclockwise,
+            RotationOption::Full => table.full,
+        };
+        kicks[from.index()]
+    }
+
+    /// Returns the orientation the given piece spawns in.
+    pub(super) fn spawn_orientation(&self, piece: Piece) -> Orientation {
+        match (self, piece) {
+            (RotationSystem::Ars, Piece::J | Piece::L | Piece::T) => Orientation::South,
+            _ => Orientation::North,
+        }
+    }
+
+    /// Returns the shift added to every kick when rotating the given piece, which moves it from
+    /// the rotation states of SRS, the ones of the pieces, to the ones of the system.
+    pub(super) fn state_shift(
+        &self,
+        piece: Piece,
+        from: Orientation,
+        to: Orientation,
+    ) -> (i16, i16) {
+        let shifts = match (self, piece) {
+            (RotationSystem::Ars, Piece::J | Piece::L | Piece::T) => &ARS_JLT_SHIFTS,
+            (RotationSystem::Ars, Piece::S) => &ARS_S_SHIFTS,
+            (RotationSystem::Ars, Piece::Z) => &ARS_Z_SHIFTS,
+            (RotationSystem::Ars, Piece::I) => &ARS_I_SHIFTS,
+            _ => return (0, 0),
+        };
+        let (from_shift, to_shift) = (shifts[from.index()], shifts[to.index()]);
+        (to_shift.0 - from_shift.0, to_shift.1 - from_shift.1)
+    }
+
+    /// Returns the column at the centre of the rotation box of a piece placed at the given
+    /// column and orientation, if the piece can't kick when the first cell blocking it, from left
+    /// to right and top to bottom, is in that column.
+    pub(super) fn centre_column(
+        &self,
+        piece: Piece,
+        x: i16,
+        orientation: Orientation,
+    ) -> Option<i16> {
+        match (self, piece, orientation) {
+            // Standing on its right side, the piece starts in the centre column
+            (RotationSystem::Ars, Piece::J | Piece::L | Piece::T, Orientation::East) => Some(x),
+            (RotationSystem::Ars, Piece::J | Piece::L | Piece::T, _) => Some(x + 1),
+            _ => None,
+        }
+    }
+
+    /// Returns the kick table of the system for the given piece.
+    fn kick_table(&self, piece: Piece) -> &'static KickTable {
+        match (self, piece) {
+            (_, Piece::O) => &NO_KICKS,
+            (RotationSystem::Srs, Piece::I) => &SRS_I_KICKS,
+            (RotationSystem::Srs, _) => &SRS_JLSTZ_KICKS,
+            (RotationSystem::SrsPlus, Piece::I) => &SRS_PLUS_I_KICKS,
+            (RotationSystem::SrsPlus, _) => &SRS_PLUS_JLSTZ_KICKS,
+            (RotationSystem::Ars, Piece::I) => &NO_KICKS,
+            (RotationSystem::Ars, _) => &ARS_KICKS,
+            (RotationSystem::NoKicks, _) => &NO_KICKS,
+        }
+    }
+}
+
+/// `RotationOption` represents the different rotation options for a piece.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RotationOption {
+    /// Rotate the piece clockwise.
+    ClockWise,
+    /// Rotate the piece counterclockwise.
+    CounterClockWise,
+    /// Rotate the piece 180 degrees.
+    Full,
+}
+
+impl RotationOption {
+    /// Returns the orientation reached when rotating from the given orientation.
+    pub fn next_orientation(&self, orientation: Orientation) -> Orientation {
+        match (self, orientation) {
+            (RotationOption::ClockWise, Orientation::North) => Orientation::East,
+            (RotationOption::ClockWise, Orientation::East) => Orientation::South,
+            (RotationOption::ClockWise, Orientation::South) => Orientation::West,
+            (RotationOption::ClockWise, Orientation::West) => Orientation::North,
+            (RotationOption::CounterClockWise, Orientation::North) => Orientation::West,
+            (RotationOption::CounterClockWise, Orientation::East) => Orientation::North,
+            (RotationOption::CounterClockWise, Orientation::South) => Orientation::East,
+            (RotationOption::CounterClockWise, Orientation::West) => Orientation::South,
+            (RotationOption::Full, Orientation::North) => Orientation::South,
+            (RotationOption::Full, Orientation::East) => Orientation::West,
+            (RotationOption::Full, Orientation::South) => Orientation::North,
+            (RotationOption::Full, Orientation::West) => Orientation::East,
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use rand::{
+    RngCore, SeedableRng,
+    rand_core::impls::{fill_bytes_via_next, next_u64_via_u32},
+};
+use rand_chacha::ChaCha12Rng;
+
+/// `SeededRng` represents a random number generator that keeps its seed and counts the values
+/// drawn from it, so its state can be stored and rebuilt later.
+///
+/// Every value is drawn as 32 bit words, so the state only depends on the number of words drawn.
+#[derive(Debug)]
+pub struct SeededRng {
+    /// The seed the generator was created with.
+    seed: u64,
+    /// The number of 32 bit words drawn since it was created.
+    draws: u64,
+    /// The generator the words are drawn from.
+    rng: ChaCha12Rng,
+}
+
+impl SeededRng {
+    /// Creates a new `SeededRng` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed of the generator.
+    pub fn new(seed: u64) -> Self {
+        SeededRng {
+            seed,
+            draws: 0,
+            rng: ChaCha12Rng::seed_from_u64(seed),
+        }
+    }
+    /// Creates a generator in the state of one that has drawn the given number of words.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed of the generator.
+    /// * `draws` - The number of 32 bit words drawn.
+    pub fn replayed(seed: u64, draws: u64) -> Self {
+        let mut rng = SeededRng::new(seed);
+        for _ in 0..draws {
+            rng.next_u32();
+        }
+        rng
+    }
+    /// Returns the seed the generator was created with.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+    /// Returns the number of 32 bit words drawn since it was created.
+    pub fn draws(&self) -> u64 {
+        self.draws
+    }
+}
+
+impl RngCore for SeededRng {
+    fn next_u32(&mut self) -> u32 {
+        self.draws += 1;
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        next_u64_via_u32(self)
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        fill_bytes_via_next(self, dst)
+    }
+}
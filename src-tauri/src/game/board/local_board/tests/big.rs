@@ -0,0 +1,62 @@
+use crate::{
+    game::{
+        board::{Board, cell::Cell, local_board::LocalBoard},
+        pieces::Piece,
+        queue::local_queue::LocalQueue,
+    },
+    init_trace::initialize,
+};
+
+/// Returns a big board with the given piece as the current one.
+fn big_board(piece: Piece) -> LocalBoard {
+    let mut board = LocalBoard::new(LocalQueue::default());
+    board.change_big(true);
+    board.cur_piece = board.spawned(piece);
+    board
+}
+
+/// Returns the given row of the main board in the state, without the ghost piece.
+fn main_row(state: &str, row: usize) -> String {
+    state[200 + row * 10..200 + (row + 1) * 10].replace('G', "E")
+}
+
+#[test]
+fn big_pieces_move_in_big_cells() {
+    initialize();
+    let mut board = big_board(Piece::T);
+    assert!(board.move_right());
+    assert!(!board.move_right());
+    let mut board = big_board(Piece::I);
+    assert!(!board.move_right());
+    assert!(board.move_left());
+    assert!(!board.move_left());
+}
+
+#[test]
+fn big_pieces_are_shown_at_double_scale() {
+    initialize();
+    let mut board = big_board(Piece::I);
+    board.hard_drop();
+    let state = board.board_state();
+    assert_eq!(state.len(), 400);
+    assert_eq!(main_row(&state, 18), "EEIIIIIIII");
+    assert_eq!(main_row(&state, 19), "EEIIIIIIII");
+    assert_eq!(main_row(&state, 17), "EEEEEEEEEE");
+}
+
+#[test]
+fn big_lines_are_cleared_on_the_small_field() {
+    initialize();
+    let mut board = big_board(Piece::I);
+    for el in board.cells.iter_mut().skip(45).take(4) {
+        *el = Cell::Full(Piece::T);
+    }
+    board.rotation_clockwise();
+    while board.move_right() {}
+    board.hard_drop();
+    assert_eq!(board.lines_cleared, 1);
+    let state = board.board_state();
+    assert_eq!(main_row(&state, 19), "EEEEEEEEII");
+    assert_eq!(main_row(&state, 14), "EEEEEEEEII");
+    assert_eq!(main_row(&state, 13), "EEEEEEEEEE");
+}
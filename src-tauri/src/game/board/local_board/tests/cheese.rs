@@ -0,0 +1,75 @@
+use crate::game::{
+    board::{cell::Cell, local_board::LocalBoard},
+    pieces::Piece,
+    queue::{local_queue::LocalQueue, randomizer::RandomizerType},
+};
+
+fn board_with_seed(seed: u64) -> LocalBoard {
+    let mut board = LocalBoard::new(LocalQueue::new(seed, RandomizerType::SevenBag));
+    board.change_seed(seed);
+    board
+}
+
+/// Returns the column of the hole of each line of the main board, from the bottom up, until
+/// a line without trash.
+fn holes(board: &LocalBoard) -> Vec<usize> {
+    board
+        .cells
+        .chunks(10)
+        .rev()
+        .take_while(|row| row.contains(&Cell::Full(Piece::Trash)))
+        .map(|row| row.iter().position(|cell| *cell == Cell::Empty).unwrap())
+        .collect()
+}
+
+#[test]
+fn cheese_without_messiness_keeps_the_hole() {
+    let mut board = board_with_seed(3);
+    board.add_cheese(6, 0);
+    let holes = holes(&board);
+    assert_eq!(holes.len(), 6);
+    assert!(holes.iter().all(|hole| *hole == holes[0]));
+    assert_eq!(board.garbage_lines(), 6);
+}
+
+#[test]
+fn full_messiness_moves_every_hole() {
+    let mut board = board_with_seed(3);
+    board.add_cheese(9, 100);
+    let holes = holes(&board);
+    assert_eq!(holes.len(), 9);
+    assert!(holes.windows(2).all(|pair| pair[0] != pair[1]));
+}
+
+#[test]
+fn cheese_waits_for_the_next_piece_to_spawn() {
+    let mut board = board_with_seed(3);
+    board.change_spawn_on_lock(false);
+    board.hard_drop();
+    board.add_cheese(2, 50);
+    assert_eq!(board.num_of_trash_lines(), 2);
+    assert_eq!(board.garbage_lines(), 2);
+    board.spawn_piece();
+    assert_eq!(board.num_of_trash_lines(), 0);
+    assert_eq!(holes(&board).len(), 2);
+}
+
+#[test]
+fn only_lines_with_trash_count_as_garbage() {
+    let mut board = LocalBoard::new(LocalQueue::default());
+    for x in (0..3).chain(7..10) {
+        board.cells[190 + x] = Cell::Full(Piece::Trash);
+    }
+    board.cur_piece = Piece::I.try_into().unwrap();
+    board.hard_drop();
+    assert_eq!(board.lines_completed(), 1);
+    assert_eq!(board.garbage_cleared(), 1);
+
+    for x in (0..3).chain(7..10) {
+        board.cells[190 + x] = Cell::Full(Piece::J);
+    }
+    board.cur_piece = Piece::I.try_into().unwrap();
+    board.hard_drop();
+    assert_eq!(board.lines_completed(), 2);
+    assert_eq!(board.garbage_cleared(), 1);
+}
@@ -610,3 +610,49 @@ fn mini_t_spin_7() {
         board.clear_line_pattern()
     )
 }
+
+#[test]
+fn s_spin_double() {
+    initialize();
+    let mut board = LocalBoard::new(LocalQueue::default());
+    board.cells[166] = Cell::Full(Piece::T);
+    for i in 171..180 {
+        if i != 174 {
+            board.cells[i] = Cell::Full(Piece::T);
+        }
+    }
+    for i in 180..190 {
+        if i != 184 && i != 185 {
+            board.cells[i] = Cell::Full(Piece::T);
+        }
+    }
+    for i in 190..200 {
+        if i != 195 {
+            board.cells[i] = Cell::Full(Piece::T);
+        }
+    }
+    board.cur_piece = Piece::S.try_into().unwrap();
+    board.move_right();
+    for _ in 0..20 {
+        board.soft_drop();
+    }
+    board.rotation_counterclockwise();
+    board.next_tick();
+    assert_eq!(
+        ClearLinePattern::SpinDouble(Piece::S),
+        board.clear_line_pattern()
+    );
+}
+
+#[test]
+fn rotated_piece_that_can_move_is_not_a_spin() {
+    initialize();
+    let mut board = LocalBoard::new(LocalQueue::default());
+    board.cur_piece = Piece::J.try_into().unwrap();
+    for _ in 0..20 {
+        board.soft_drop();
+    }
+    board.rotation_clockwise();
+    board.next_tick();
+    assert_eq!(ClearLinePattern::None, board.clear_line_pattern());
+}
@@ -3,7 +3,7 @@
 use crate::game::{
     board::{cell::Cell, local_board::LocalBoard},
     pieces::Piece,
-    queue::local_queue::LocalQueue,
+    queue::{local_queue::LocalQueue, randomizer::RandomizerType},
 };
 
 #[test]
@@ -73,3 +73,16 @@ fn removing_trash_lines() {
     }
     assert_eq!(board.cells, cmp_board);
 }
+
+#[test]
+fn same_seed_same_trash_columns() {
+    let mut board = LocalBoard::new(LocalQueue::new(7, RandomizerType::SevenBag));
+    let mut other_board = LocalBoard::new(LocalQueue::new(7, RandomizerType::SevenBag));
+    board.change_seed(7);
+    other_board.change_seed(7);
+    for _ in 0..3 {
+        board.insert_trash(1);
+        other_board.insert_trash(1);
+    }
+    assert_eq!(board.trash_lines_queue, other_board.trash_lines_queue);
+}
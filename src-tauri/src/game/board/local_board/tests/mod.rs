@@ -1,3 +1,4 @@
+mod cheese;
 mod game_over;
 mod game_won;
 mod hard_drop;
@@ -5,15 +6,21 @@ mod held_piece;
 mod move_left;
 mod move_right;
 mod num_of_trash_lines;
+mod perfect_clear;
 mod rotation_clockwise;
 mod rotation_counterclockwise;
 mod rotation_full;
+mod rotation_system;
+mod snapshot;
 mod soft_drop;
+mod spawn_piece;
 mod strategy;
 
+mod big;
 mod board_state;
 mod clear_line;
 mod clear_pattern;
 mod danger_level;
 mod insert_trash;
 mod next_tick;
+mod visibility;
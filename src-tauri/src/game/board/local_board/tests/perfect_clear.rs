@@ -0,0 +1,43 @@
+use crate::{
+    game::{
+        board::{cell::Cell, local_board::LocalBoard},
+        pieces::Piece,
+        queue::local_queue::LocalQueue,
+    },
+    init_trace::initialize,
+};
+
+#[test]
+fn perfect_clear_detected() {
+    initialize();
+    let mut board = LocalBoard::new(LocalQueue::default());
+    for i in (190..193).chain(197..200) {
+        board.cells[i] = Cell::Full(Piece::I);
+    }
+    board.cur_piece = Piece::I.try_into().unwrap();
+    board.hard_drop();
+    assert!(board.perfect_clear());
+    assert!(!board.perfect_clear());
+}
+
+#[test]
+fn no_perfect_clear_with_cells_left() {
+    initialize();
+    let mut board = LocalBoard::new(LocalQueue::default());
+    for i in (190..193).chain(197..200) {
+        board.cells[i] = Cell::Full(Piece::I);
+    }
+    board.cells[180] = Cell::Full(Piece::I);
+    board.cur_piece = Piece::I.try_into().unwrap();
+    board.hard_drop();
+    assert!(!board.perfect_clear());
+}
+
+#[test]
+fn no_perfect_clear_without_line_clear() {
+    initialize();
+    let mut board = LocalBoard::new(LocalQueue::default());
+    board.cur_piece = Piece::I.try_into().unwrap();
+    board.hard_drop();
+    assert!(!board.perfect_clear());
+}
@@ -0,0 +1,171 @@
+use crate::game::{
+    board::{
+        cell::Cell,
+        local_board::{
+            LocalBoard,
+            moving_piece::{MovingPiece, Orientation},
+            rotation_system::{RotationOption, RotationSystem},
+        },
+    },
+    pieces::Piece,
+    queue::local_queue::LocalQueue,
+};
+
+/// Places a vertical I piece against the left wall.
+fn board_with_i_on_left_wall(rotation_system: RotationSystem) -> LocalBoard {
+    let mut board = LocalBoard::new(LocalQueue::default());
+    board.change_rotation_system(rotation_system);
+    board.cur_piece = Piece::I.try_into().unwrap();
+    board.rotation_clockwise();
+    for _ in 0..10 {
+        board.move_left();
+    }
+    board
+}
+
+#[test]
+fn rotation_system_srs_kicks_off_the_wall() {
+    let mut board = board_with_i_on_left_wall(RotationSystem::Srs);
+    board.rotation_clockwise();
+    assert_eq!(Orientation::South, board.orientation());
+    assert_eq!(0, board.piece_x());
+}
+
+#[test]
+fn rotation_system_no_kicks_stays_on_the_wall() {
+    let mut board = board_with_i_on_left_wall(RotationSystem::NoKicks);
+    board.rotation_clockwise();
+    assert_eq!(Orientation::East, board.orientation());
+    assert_eq!(0, board.piece_x());
+}
+
+#[test]
+fn rotation_system_ars_i_piece_never_kicks() {
+    let mut board = board_with_i_on_left_wall(RotationSystem::Ars);
+    board.rotation_clockwise();
+    assert_eq!(Orientation::East, board.orientation());
+}
+
+#[test]
+fn rotation_system_ars_kicks_right_then_left() {
+    assert_eq!(
+        &[(0, 0), (1, 0), (-1, 0)],
+        RotationSystem::Ars.kicks(Piece::T, Orientation::North, RotationOption::ClockWise)
+    );
+}
+
+#[test]
+fn rotation_system_ars_jlt_spawn_flat_side_up() {
+    let mut board = LocalBoard::new(LocalQueue::default());
+    for piece in [Piece::J, Piece::L, Piece::T] {
+        board.cur_piece = piece.try_into().unwrap();
+        board.change_rotation_system(RotationSystem::Ars);
+        assert_eq!(Orientation::South, board.orientation());
+        assert!(board.cur_piece.get_coords().iter().all(|&(_, y)| y < 0));
+        board.change_rotation_system(RotationSystem::Srs);
+        assert_eq!(Orientation::North, board.orientation());
+    }
+}
+
+#[test]
+fn rotation_system_ars_keeps_the_piece_at_the_bottom_of_its_box() {
+    let mut board = LocalBoard::new(LocalQueue::default());
+    board.cur_piece = Piece::T.try_into().unwrap();
+    board.change_rotation_system(RotationSystem::Ars);
+    for _ in 0..5 {
+        board.soft_drop();
+    }
+    let bottom = |board: &LocalBoard| board.cur_piece.get_coords().iter().map(|c| c.1).max();
+    let flat_bottom = bottom(&board);
+    assert!(board.rotation_clockwise());
+    assert_eq!(Orientation::West, board.orientation());
+    assert_eq!(flat_bottom, bottom(&board));
+    assert!(board.rotation_clockwise());
+    assert_eq!(Orientation::North, board.orientation());
+    assert_eq!(flat_bottom, bottom(&board));
+}
+
+/// Places an ARS T piece at the given position, with the given cells full.
+fn board_with_ars_t(x: i16, y: i16, orientation: Orientation, full: &[(i16, i16)]) -> LocalBoard {
+    let mut board = LocalBoard::new(LocalQueue::default());
+    board.change_rotation_system(RotationSystem::Ars);
+    let mut piece: Box<dyn MovingPiece> = Piece::T.try_into().unwrap();
+    piece.place(x, y, orientation);
+    board.cur_piece = piece;
+    for &(x, y) in full {
+        board.cells[(y * 10 + x) as usize] = Cell::Full(Piece::I);
+    }
+    board
+}
+
+#[test]
+fn rotation_system_ars_kicks_when_blocked_outside_the_centre_column() {
+    let mut board = board_with_ars_t(3, 4, Orientation::West, &[(5, 6)]);
+    assert!(board.rotation_clockwise());
+    assert_eq!(Orientation::North, board.orientation());
+    assert_eq!(2, board.piece_x());
+}
+
+#[test]
+fn rotation_system_ars_doesnt_kick_when_blocked_in_the_centre_column() {
+    // Kicking one cell to the right would leave room for the piece to turn
+    let mut board = board_with_ars_t(3, 5, Orientation::South, &[(4, 4)]);
+    assert!(!board.rotation_clockwise());
+    assert_eq!(Orientation::South, board.orientation());
+    assert_eq!(3, board.piece_x());
+}
+
+#[test]
+fn rotation_system_srs_plus_has_180_kicks() {
+    assert_eq!(
+        2,
+        RotationSystem::Srs
+            .kicks(Piece::T, Orientation::North, RotationOption::Full)
+            .len()
+    );
+    assert_eq!(
+        6,
+        RotationSystem::SrsPlus
+            .kicks(Piece::T, Orientation::North, RotationOption::Full)
+            .len()
+    );
+}
+
+#[test]
+fn rotation_system_full_turn_returns_to_start() {
+    for piece in [
+        Piece::I,
+        Piece::J,
+        Piece::L,
+        Piece::O,
+        Piece::S,
+        Piece::T,
+        Piece::Z,
+    ] {
+        for rotation_system in [
+            RotationSystem::Srs,
+            RotationSystem::SrsPlus,
+            RotationSystem::Ars,
+            RotationSystem::NoKicks,
+        ] {
+            let mut board = LocalBoard::new(LocalQueue::default());
+            board.cur_piece = piece.try_into().unwrap();
+            board.change_rotation_system(rotation_system);
+            for _ in 0..5 {
+                board.soft_drop();
+            }
+            let init_x = board.piece_x();
+            let init_y = board.piece_y();
+            for _ in 0..4 {
+                board.rotation_clockwise();
+            }
+            assert_eq!((init_x, init_y), (board.piece_x(), board.piece_y()));
+            board.rotation_counterclockwise();
+            board.rotation_clockwise();
+            assert_eq!((init_x, init_y), (board.piece_x(), board.piece_y()));
+            board.rotation_full();
+            board.rotation_full();
+            assert_eq!((init_x, init_y), (board.piece_x(), board.piece_y()));
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{
+    game::{
+        board::{Board, local_board::LocalBoard},
+        queue::{local_queue::LocalQueue, randomizer::RandomizerType},
+        visibility::CellShown,
+    },
+    init_trace::initialize,
+};
+
+fn board_with_seed(seed: u64) -> LocalBoard {
+    LocalBoard::new(LocalQueue::new(seed, RandomizerType::SevenBag))
+}
+
+#[test]
+fn restored_board_is_the_same() {
+    initialize();
+    let mut board = board_with_seed(7);
+    board.hard_drop();
+    board.save_piece();
+    board.rotation_clockwise();
+    board.move_left();
+    board.soft_drop();
+    board.insert_trash(2);
+    let snapshot = board.snapshot();
+    let content = serde_json::to_string(&snapshot).unwrap();
+    let mut restored = board_with_seed(7);
+    restored.restore(serde_json::from_str(&content).unwrap());
+    assert_eq!(restored.snapshot(), snapshot);
+    assert_eq!(restored.board_state(), board.board_state());
+    assert_eq!(restored.held_piece(), board.held_piece());
+    assert_eq!(restored.orientation(), board.orientation());
+    assert_eq!(restored.num_of_trash_lines(), 2);
+}
+
+#[test]
+fn restored_board_continues_the_queue() {
+    initialize();
+    let mut board = board_with_seed(3);
+    for _ in 0..10 {
+        board.hard_drop();
+    }
+    let mut restored = board_with_seed(3);
+    restored.restore(board.snapshot());
+    for _ in 0..10 {
+        assert_eq!(restored.cur_piece(), board.cur_piece());
+        board.hard_drop();
+        restored.hard_drop();
+    }
+    assert_eq!(restored.board_state(), board.board_state());
+}
+
+#[test]
+fn restored_board_continues_the_trash_columns_and_the_locks() {
+    initialize();
+    let mut board = board_with_seed(5);
+    board.change_seed(5);
+    board.add_cheese(3, 50);
+    board.hard_drop();
+    board.hard_drop();
+    let mut restored = board_with_seed(5);
+    restored.change_seed(11);
+    restored.restore(board.snapshot());
+    let shown = |lock: Option<usize>| match lock {
+        Some(2) => CellShown::Hidden,
+        _ => CellShown::Normal,
+    };
+    assert_eq!(
+        restored.masked_board_state(shown, true),
+        board.masked_board_state(shown, true)
+    );
+    board.add_cheese(4, 100);
+    restored.add_cheese(4, 100);
+    board.hard_drop();
+    restored.hard_drop();
+    assert_eq!(restored.locks(), 3);
+    assert_eq!(restored.snapshot(), board.snapshot());
+}
@@ -0,0 +1,71 @@
+use crate::{
+    game::{
+        board::{Board, cell::Cell, local_board::LocalBoard},
+        pieces::Piece,
+        queue::local_queue::LocalQueue,
+    },
+    init_trace::initialize,
+};
+
+#[test]
+fn piece_waits_to_spawn() {
+    initialize();
+    let mut board = LocalBoard::new(LocalQueue::default());
+    board.change_spawn_on_lock(false);
+    board.cur_piece = Piece::O.try_into().unwrap();
+    board.hard_drop();
+    assert!(board.spawn_pending());
+    assert_eq!(board.piece_num(), 0);
+    assert!(!board.board_state().contains('G'));
+    assert_eq!(board.board_state().matches('O').count(), 4);
+    board.spawn_piece();
+    assert!(!board.spawn_pending());
+    assert_eq!(board.piece_num(), 1);
+}
+
+#[test]
+fn completed_lines_stay_until_cleared() {
+    initialize();
+    let mut board = LocalBoard::new(LocalQueue::default());
+    board.change_spawn_on_lock(false);
+    for i in (190..193).chain(197..200) {
+        board.cells[i] = Cell::Full(Piece::I);
+    }
+    board.cur_piece = Piece::I.try_into().unwrap();
+    board.hard_drop();
+    assert!(board.lines_pending());
+    assert!(board.perfect_clear());
+    assert!(
+        board.cells[190..200]
+            .iter()
+            .all(|cell| *cell != Cell::Empty)
+    );
+    board.clear_pending_lines();
+    assert!(!board.lines_pending());
+    assert_eq!(board.cells, [Cell::Empty; 200]);
+}
+
+#[test]
+fn spawn_clears_pending_lines() {
+    initialize();
+    let mut board = LocalBoard::new(LocalQueue::default());
+    board.change_spawn_on_lock(false);
+    for i in (190..193).chain(197..200) {
+        board.cells[i] = Cell::Full(Piece::I);
+    }
+    board.cur_piece = Piece::I.try_into().unwrap();
+    board.hard_drop();
+    board.spawn_piece();
+    assert!(!board.lines_pending());
+    assert_eq!(board.cells, [Cell::Empty; 200]);
+}
+
+#[test]
+fn hold_ignored_while_spawn_pending() {
+    initialize();
+    let mut board = LocalBoard::new(LocalQueue::default());
+    board.change_spawn_on_lock(false);
+    board.hard_drop();
+    board.save_piece();
+    assert_eq!(board.held_piece(), None);
+}
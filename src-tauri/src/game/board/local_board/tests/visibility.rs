@@ -0,0 +1,59 @@
+use crate::{
+    game::{
+        board::{Board, cell::Cell, local_board::LocalBoard},
+        pieces::Piece,
+        queue::local_queue::LocalQueue,
+        visibility::CellShown,
+    },
+    init_trace::initialize,
+};
+
+/// Returns a board with an I piece locked on the left wall over a cleared line and an O cell
+/// placed before any lock.
+fn board_after_clear() -> LocalBoard {
+    let mut board = LocalBoard::new(LocalQueue::default());
+    for el in board.cells.iter_mut().skip(191) {
+        *el = Cell::Full(Piece::I);
+    }
+    board.cells[155] = Cell::Full(Piece::O);
+    board.cur_piece = Piece::I.try_into().unwrap();
+    board.rotation_clockwise();
+    for _ in 0..5 {
+        board.move_left();
+    }
+    board.hard_drop();
+    board
+}
+
+#[test]
+fn masked_cells_follow_their_lock_through_clears() {
+    initialize();
+    let board = board_after_clear();
+    assert_eq!(board.locks(), 1);
+    let state = board.masked_board_state(
+        |lock| match lock {
+            Some(1) => CellShown::Hidden,
+            _ => CellShown::Faded,
+        },
+        true,
+    );
+    assert_eq!(&state[370..371], "E");
+    assert_eq!(&state[380..381], "E");
+    assert_eq!(&state[390..391], "E");
+    // The O cell moved down a row with the clear
+    assert_eq!(&state[365..366], "o");
+}
+
+#[test]
+fn monochrome_cells_and_hidden_ghost() {
+    initialize();
+    let board = board_after_clear();
+    let state = board.masked_board_state(|_| CellShown::Monochrome, false);
+    assert_eq!(&state[390..391], "M");
+    assert_eq!(&state[365..366], "M");
+    assert!(!state.contains('G'));
+    assert_eq!(
+        board.masked_board_state(|_| CellShown::Normal, true),
+        board.board_state()
+    );
+}
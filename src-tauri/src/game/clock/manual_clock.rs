@@ -0,0 +1,94 @@
+use std::{future::Future, pin::Pin, time::Duration};
+
+use tokio::sync::mpsc::{self, Receiver, Sender};
+
+use super::Clock;
+
+/// `ManualClock` only moves forward when its `Stepper` allows it.
+///
+/// Every sleep waits for a step and then advances the clock by the time slept, so a game
+/// using it can be run frame by frame.
+#[derive(Debug)]
+pub struct ManualClock {
+    /// The time elapsed since the clock was created.
+    now: Duration,
+    /// Receiver for the steps.
+    steps: Receiver<()>,
+    /// Sender to tell that the clock is waiting for a step.
+    waiting: Sender<()>,
+    /// Flag indicating if the clock has already slept once.
+    started: bool,
+}
+
+/// `Stepper` drives a `ManualClock`.
+#[derive(Debug)]
+pub struct Stepper {
+    /// Sender for the steps.
+    steps: Sender<()>,
+    /// Receiver for the clock waiting notifications.
+    waiting: Receiver<()>,
+}
+
+impl ManualClock {
+    /// Creates a new `ManualClock` and the `Stepper` that drives it.
+    pub fn new() -> (Self, Stepper) {
+        let (tx_steps, rx_steps) = mpsc::channel(1);
+        let (tx_waiting, rx_waiting) = mpsc::channel(1);
+        let clock = Self {
+            now: Duration::ZERO,
+            steps: rx_steps,
+            waiting: tx_waiting,
+            started: false,
+        };
+        let stepper = Stepper {
+            steps: tx_steps,
+            waiting: rx_waiting,
+        };
+        (clock, stepper)
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Duration {
+        self.now
+    }
+
+    fn sleep(&mut self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(async move {
+            if self.started {
+                let _ = self.waiting.send(()).await;
+            }
+            self.started = true;
+            let _ = self.steps.recv().await;
+            self.now += duration;
+        })
+    }
+}
+
+impl Stepper {
+    /// Lets the clock finish its current sleep and waits until it sleeps again.
+    ///
+    /// Returns `false` if the clock was dropped, meaning that the game has finished.
+    pub async fn step(&mut self) -> bool {
+        if self.steps.send(()).await.is_err() {
+            return false;
+        }
+        self.waiting.recv().await.is_some()
+    }
+
+    /// Steps the clock the given number of times.
+    ///
+    /// Returns `false` if the clock was dropped before finishing.
+    ///
+    /// # Arguments
+    ///
+    /// * `steps` - The number of steps.
+    pub async fn step_many(&mut self, steps: usize) -> bool {
+        for _ in 0..steps {
+            if !self.step().await {
+                return false;
+            }
+        }
+        true
+    }
+}
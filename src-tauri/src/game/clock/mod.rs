@@ -0,0 +1,22 @@
+mod manual_clock;
+mod system_clock;
+
+use std::{fmt::Debug, future::Future, pin::Pin, time::Duration};
+
+pub use manual_clock::{ManualClock, Stepper};
+pub use system_clock::SystemClock;
+
+/// `Clock` trait defines the source of time of a game.
+///
+/// Every timing decision of a game, like gravity, lock delay or time limits, goes through its clock.
+pub trait Clock: Send + Debug {
+    /// Returns the time elapsed since the clock was created.
+    fn now(&self) -> Duration;
+
+    /// Waits until the given time has passed.
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The time to wait.
+    fn sleep(&mut self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>>;
+}
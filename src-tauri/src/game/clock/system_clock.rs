@@ -0,0 +1,30 @@
+use std::{future::Future, pin::Pin, time::Duration};
+
+use tokio::time::Instant;
+
+use super::Clock;
+
+/// `SystemClock` follows the real time. It's the clock used when playing.
+#[derive(Debug)]
+pub struct SystemClock {
+    /// The instant the clock was created.
+    start: Instant,
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    fn sleep(&mut self, duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+        Box::pin(tokio::time::sleep(duration))
+    }
+}
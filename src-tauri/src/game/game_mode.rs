@@ -0,0 +1,394 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::master::MasterProgress;
+use crate::models::game_info::{
+    BlitzGameInfo, CheeseGameInfo, ClassicGameInfo, GameTypeInfo, LinesGameInfo, MasterGameInfo,
+    SurvivalGameInfo,
+};
+
+/// Lines to clear to win a lines game if no other goal is given.
+pub const DEFAULT_LINES_GOAL: u32 = 40;
+/// Seconds a blitz game lasts if no other duration is given.
+pub const DEFAULT_BLITZ_DURATION_SECS: u64 = 120;
+/// Garbage lines to clear to win a cheese race if no other goal is given.
+pub const DEFAULT_CHEESE_GOAL: u32 = 18;
+/// Garbage lines kept on the board in a cheese race if no other height is given.
+pub const DEFAULT_CHEESE_HEIGHT: u8 = 10;
+/// Percentage of garbage lines whose hole moves in a cheese race if no other messiness is given.
+pub const DEFAULT_CHEESE_MESSINESS: u8 = 100;
+
+/// Time between the garbage lines of a survival game when it starts.
+const SURVIVAL_START_INTERVAL: Duration = Duration::from_millis(4000);
+/// Shortest time between the garbage lines of a survival game.
+const SURVIVAL_MIN_INTERVAL: Duration = Duration::from_millis(500);
+/// Time taken off the interval between garbage lines each speed-up of a survival game.
+const SURVIVAL_SPEED_UP: Duration = Duration::from_millis(250);
+/// Seconds survived between the speed-ups of a survival game.
+const SURVIVAL_SPEED_UP_SECS: u64 = 20;
+
+/// `GameMode` represents the rules of a single-player game: how it is won or lost, the
+/// information recorded when it ends and where that information is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum GameMode {
+    /// The game goes on until the player tops out, levelling up with the lines cleared.
+    #[default]
+    Classic,
+    /// The game is won once the goal of lines has been cleared, as fast as possible (sprint).
+    Lines {
+        /// The lines to clear.
+        goal: u32,
+    },
+    /// The game is won once its time has passed, scoring as many points as possible (ultra).
+    Blitz {
+        /// The seconds the game lasts.
+        duration_secs: u64,
+    },
+    /// The board starts with garbage lines, refilled as they are cleared, and the game is won
+    /// once the goal of garbage lines has been cleared, as fast as possible (cheese race).
+    Cheese {
+        /// The garbage lines to clear.
+        goal: u32,
+        /// The garbage lines the board starts with, and is refilled up to while any are left
+        /// to reach the goal.
+        height: u8,
+        /// The percentage of garbage lines whose hole is in a different column than the one of
+        /// the line above.
+        messiness: u8,
+    },
+    /// Garbage lines rise on a schedule that speeds up over time, and the game goes on until
+    /// the player tops out, surviving as long as possible.
+    Survival,
+    /// The level advances with each piece and each line up to 999, in sections with their own
+    /// gravity, lock delay and delays, and the player is graded on the points scored (master).
+    /// The gravity, lock delay and delays of the options are ignored.
+    Master,
+}
+
+/// `ModeProgress` represents how far a game has gone, as the modes measure it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ModeProgress {
+    /// The lines cleared in the game.
+    pub lines_cleared: u32,
+    /// The garbage lines cleared in the game.
+    pub garbage_cleared: u32,
+    /// The level of the game.
+    pub level: u16,
+    /// The progress of a master game.
+    pub master: Option<MasterProgress>,
+    /// The time played.
+    pub elapsed: Duration,
+}
+
+impl GameMode {
+    /// Checks if the game has been won.
+    ///
+    /// # Arguments
+    ///
+    /// * `game_over` - Whether the player has topped out.
+    /// * `progress` - How far the game has gone.
+    pub fn won(&self, game_over: bool, progress: &ModeProgress) -> bool {
+        if game_over {
+            return false;
+        }
+        match self {
+            GameMode::Classic | GameMode::Survival => false,
+            GameMode::Lines { goal } => progress.lines_cleared >= *goal,
+            GameMode::Blitz { .. } => self
+                .time_limit()
+                .is_some_and(|limit| progress.elapsed >= limit),
+            GameMode::Cheese { goal, .. } => progress.garbage_cleared >= *goal,
+            GameMode::Master => progress.master.is_some_and(|master| master.finished()),
+        }
+    }
+
+    /// Returns true if topping out finishes the game and its information is recorded.
+    /// Otherwise topping out counts as giving up.
+    pub fn finishes_on_game_over(&self) -> bool {
+        match self {
+            GameMode::Classic | GameMode::Survival | GameMode::Master => true,
+            GameMode::Lines { .. } | GameMode::Blitz { .. } | GameMode::Cheese { .. } => false,
+        }
+    }
+
+    /// Returns the progress towards the goal of the game as the count and the goal, if the
+    /// game has a goal. The goal of a master game is the end of the current section.
+    ///
+    /// # Arguments
+    ///
+    /// * `progress` - How far the game has gone.
+    pub fn goal_progress(&self, progress: &ModeProgress) -> Option<(u32, u32)> {
+        match self {
+            GameMode::Lines { goal } => Some((progress.lines_cleared, *goal)),
+            GameMode::Cheese { goal, .. } => Some((progress.garbage_cleared, *goal)),
+            GameMode::Master => progress
+                .master
+                .map(|master| (master.level() as u32, master.section_stop() as u32)),
+            GameMode::Classic | GameMode::Blitz { .. } | GameMode::Survival => None,
+        }
+    }
+
+    /// Returns the time the game is won at, if it is won once some time has been played.
+    pub fn time_limit(&self) -> Option<Duration> {
+        match self {
+            GameMode::Blitz { duration_secs } => Some(Duration::from_secs(*duration_secs)),
+            _ => None,
+        }
+    }
+
+    /// Returns the mode with the targets it can't be played with brought into range: a cheese
+    /// race clears at least one garbage line, keeps at least one on the board and leaves the top
+    /// row of the field free, and no more than all of its lines are messy.
+    ///
+    /// # Arguments
+    ///
+    /// * `field_height` - The height of the field the game is played on.
+    pub fn clamped(&self, field_height: u8) -> GameMode {
+        match *self {
+            GameMode::Cheese {
+                goal,
+                height,
+                messiness,
+            } => GameMode::Cheese {
+                goal: goal.max(1),
+                height: height.clamp(1, field_height.saturating_sub(1).max(1)),
+                messiness: messiness.min(100),
+            },
+            mode => mode,
+        }
+    }
+
+    /// Returns the time until the next garbage line rises, if garbage rises in the game.
+    ///
+    /// # Arguments
+    ///
+    /// * `elapsed` - The time played.
+    pub fn garbage_interval(&self, elapsed: Duration) -> Option<Duration> {
+        match self {
+            GameMode::Survival => {
+                let speed_ups = (elapsed.as_secs() / SURVIVAL_SPEED_UP_SECS) as u32;
+                Some(
+                    SURVIVAL_START_INTERVAL
+                        .saturating_sub(SURVIVAL_SPEED_UP * speed_ups)
+                        .max(SURVIVAL_MIN_INTERVAL),
+                )
+            }
+            GameMode::Classic
+            | GameMode::Lines { .. }
+            | GameMode::Blitz { .. }
+            | GameMode::Cheese { .. }
+            | GameMode::Master => None,
+        }
+    }
+
+    /// Returns true if the player is shown the lines cleared, otherwise the points are shown.
+    pub fn shows_lines(&self) -> bool {
+        match self {
+            GameMode::Classic
+            | GameMode::Lines { .. }
+            | GameMode::Cheese { .. }
+            | GameMode::Survival
+            | GameMode::Master => true,
+            GameMode::Blitz { .. } => false,
+        }
+    }
+
+    /// Returns the information specific to the mode for a game that has just started, which
+    /// keeps the target of the game so its results are compared with the same target.
+    pub fn type_info(&self) -> GameTypeInfo {
+        match self {
+            GameMode::Classic => GameTypeInfo::Classic(ClassicGameInfo::default()),
+            GameMode::Lines { goal } => GameTypeInfo::Lines(LinesGameInfo::new(0, *goal)),
+            GameMode::Blitz { duration_secs } => {
+                GameTypeInfo::Blitz(BlitzGameInfo::new(0, *duration_secs))
+            }
+            GameMode::Cheese {
+                goal,
+                height,
+                messiness,
+            } => GameTypeInfo::Cheese(CheeseGameInfo::new(0, *goal, *height, *messiness, 0, 0)),
+            GameMode::Survival => GameTypeInfo::Survival(SurvivalGameInfo::default()),
+            GameMode::Master => GameTypeInfo::Master(MasterGameInfo::default()),
+        }
+    }
+
+    /// Returns the name of the table where the information of the mode is stored.
+    pub const fn table_name(&self) -> &'static str {
+        match self {
+            GameMode::Classic => "classic",
+            GameMode::Lines { .. } => "lines",
+            GameMode::Blitz { .. } => "blitz",
+            GameMode::Cheese { .. } => "cheese",
+            GameMode::Survival => "survival",
+            GameMode::Master => "master",
+        }
+    }
+
+    /// Returns the mode whose information is stored in the given table, with its default target.
+    ///
+    /// # Arguments
+    ///
+    /// * `table_name` - The name of the table.
+    pub fn from_table_name(table_name: &str) -> Option<GameMode> {
+        Self::defaults()
+            .into_iter()
+            .find(|mode| mode.table_name() == table_name)
+    }
+
+    /// Returns every mode with its default target.
+    fn defaults() -> [GameMode; 6] {
+        [
+            GameMode::Classic,
+            GameMode::Lines {
+                goal: DEFAULT_LINES_GOAL,
+            },
+            GameMode::Blitz {
+                duration_secs: DEFAULT_BLITZ_DURATION_SECS,
+            },
+            GameMode::Cheese {
+                goal: DEFAULT_CHEESE_GOAL,
+                height: DEFAULT_CHEESE_HEIGHT,
+                messiness: DEFAULT_CHEESE_MESSINESS,
+            },
+            GameMode::Survival,
+            GameMode::Master,
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{GameMode, ModeProgress};
+    use crate::game::master::MasterProgress;
+
+    fn progress(lines_cleared: u32, garbage_cleared: u32, elapsed: Duration) -> ModeProgress {
+        ModeProgress {
+            lines_cleared,
+            garbage_cleared,
+            level: 1,
+            master: None,
+            elapsed,
+        }
+    }
+
+    #[test]
+    fn classic_is_never_won() {
+        let progress = progress(1000, 1000, Duration::from_secs(3600));
+        assert!(!GameMode::Classic.won(false, &progress));
+        assert!(GameMode::Classic.finishes_on_game_over());
+    }
+
+    #[test]
+    fn lines_is_won_with_its_goal() {
+        let sprint = GameMode::Lines { goal: 20 };
+        assert!(!sprint.won(false, &progress(19, 0, Duration::ZERO)));
+        assert!(sprint.won(false, &progress(20, 0, Duration::ZERO)));
+        assert!(!sprint.won(true, &progress(20, 0, Duration::ZERO)));
+        assert_eq!(
+            sprint.goal_progress(&progress(7, 0, Duration::ZERO)),
+            Some((7, 20))
+        );
+    }
+
+    #[test]
+    fn blitz_is_won_after_its_duration() {
+        let ultra = GameMode::Blitz { duration_secs: 180 };
+        assert!(!ultra.won(false, &progress(0, 0, Duration::from_secs(179))));
+        assert!(ultra.won(false, &progress(0, 0, Duration::from_secs(180))));
+        assert!(!ultra.won(true, &progress(0, 0, Duration::from_secs(180))));
+    }
+
+    #[test]
+    fn cheese_is_won_with_its_garbage_goal() {
+        let cheese = GameMode::Cheese {
+            goal: 18,
+            height: 10,
+            messiness: 100,
+        };
+        assert!(!cheese.won(false, &progress(40, 17, Duration::ZERO)));
+        assert!(cheese.won(false, &progress(18, 18, Duration::ZERO)));
+        assert!(!cheese.won(true, &progress(18, 18, Duration::ZERO)));
+        assert!(!cheese.finishes_on_game_over());
+        assert_eq!(
+            cheese.goal_progress(&progress(25, 12, Duration::ZERO)),
+            Some((12, 18))
+        );
+    }
+
+    #[test]
+    fn cheese_is_clamped_to_the_field() {
+        let cheese = |goal, height, messiness| GameMode::Cheese {
+            goal,
+            height,
+            messiness,
+        };
+        assert_eq!(cheese(0, 0, 101).clamped(20), cheese(1, 1, 100));
+        assert_eq!(cheese(18, 20, 100).clamped(20), cheese(18, 19, 100));
+        assert_eq!(cheese(18, 10, 50).clamped(20), cheese(18, 10, 50));
+        assert_eq!(
+            GameMode::Lines { goal: 0 }.clamped(20),
+            GameMode::Lines { goal: 0 }
+        );
+    }
+
+    #[test]
+    fn survival_garbage_speeds_up_over_time() {
+        assert_eq!(GameMode::Classic.garbage_interval(Duration::ZERO), None);
+        let start = GameMode::Survival.garbage_interval(Duration::ZERO).unwrap();
+        let later = GameMode::Survival
+            .garbage_interval(Duration::from_secs(60))
+            .unwrap();
+        assert!(later < start);
+        assert_eq!(
+            GameMode::Survival.garbage_interval(Duration::from_secs(3600)),
+            Some(super::SURVIVAL_MIN_INTERVAL)
+        );
+        assert!(!GameMode::Survival.won(false, &progress(1000, 0, Duration::from_secs(3600))));
+        assert!(GameMode::Survival.finishes_on_game_over());
+    }
+
+    #[test]
+    fn master_is_won_at_the_last_level() {
+        let mut master = MasterProgress::default();
+        let mut progress = progress(0, 0, Duration::ZERO);
+        progress.master = Some(master);
+        assert!(!GameMode::Master.won(false, &progress));
+        assert_eq!(GameMode::Master.goal_progress(&progress), Some((0, 100)));
+        while !master.finished() {
+            master.piece_fixed(4, false, Duration::from_secs(1));
+        }
+        progress.master = Some(master);
+        assert!(GameMode::Master.won(false, &progress));
+        assert!(!GameMode::Master.won(true, &progress));
+        assert!(GameMode::Master.finishes_on_game_over());
+    }
+
+    #[test]
+    fn type_info_keeps_the_target() {
+        for mode in [
+            GameMode::Classic,
+            GameMode::Lines { goal: 100 },
+            GameMode::Blitz { duration_secs: 60 },
+            GameMode::Cheese {
+                goal: 100,
+                height: 4,
+                messiness: 30,
+            },
+            GameMode::Survival,
+            GameMode::Master,
+        ] {
+            assert_eq!(mode.type_info().mode(), mode);
+        }
+    }
+
+    #[test]
+    fn table_names_go_back_to_their_mode() {
+        for mode in GameMode::defaults() {
+            assert_eq!(GameMode::from_table_name(mode.table_name()), Some(mode));
+        }
+        assert_eq!(GameMode::from_table_name("games"), None);
+    }
+}
@@ -1,7 +1,6 @@
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::{sync::Arc, time::Duration};
 
-use tauri::{AppHandle, Emitter};
 use tokio::sync::Mutex;
 use tokio::{
     net::TcpStream,
@@ -11,6 +10,7 @@ use tokio::{
 use crate::models::dummy_room::DummyPlayer;
 use crate::{
     commands::game_commands::{FIRST_LEVEL_CHANNEL, GAME_CONTROL_CHANNEL, SECOND_LEVEL_CHANNEL},
+    events::EventSink,
     game::{pieces::Piece, queue::remote_queue::RemoteQueue, strategy::Strategy},
     globals::SIZE_FOR_KB,
     helpers::game_net_helpers::{read_enum_from_server, send_enum_from_client},
@@ -43,8 +43,8 @@ pub struct ClientOnlineGame {
     game_responses: Receiver<GameResponses>,
     /// Sender for second-level commands to the local game.
     tx_commands_second: Sender<SecondLevelCommands>,
-    /// Tauri application handle.
-    app: AppHandle,
+    /// Sink for emitting events.
+    events: Arc<dyn EventSink>,
     /// The strategy used by the client.
     strategy: Strategy,
     /// A flag indicating whether the game is currently being played.
@@ -63,7 +63,7 @@ impl ClientOnlineGame {
         socket: Arc<tokio::sync::Mutex<TcpStream>>,
         pieces_buffer: Vec<Piece>,
         game_options: GameOptions,
-        app: AppHandle,
+        events: Arc<dyn EventSink>,
         delay: u64,
         playing: Arc<Mutex<bool>>,
         player: DummyPlayer,
@@ -76,7 +76,7 @@ impl ClientOnlineGame {
         let (tx_responses, rx_responses) = mpsc::channel(SIZE_FOR_KB);
         let mut local_game = LocalGame::new(
             game_options,
-            app.clone(),
+            events.clone(),
             rx_first_level,
             Some(rx_second_level),
             rx_control,
@@ -93,7 +93,7 @@ impl ClientOnlineGame {
             running: true,
             tx_commands_second: tx_second_level,
             game_responses: rx_responses,
-            app,
+            events,
             strategy: Strategy::Random,
             playing,
             deaths: 0,
@@ -201,7 +201,7 @@ impl ClientOnlineGame {
                 .as_secs();
             if cur_time - time >= 7 {
                 self.running = false;
-                let _ = self.app.emit(OTHER_PLAYER_WON_UNKNOWN, false);
+                self.events.emit(OTHER_PLAYER_WON_UNKNOWN, false);
             }
         }
         let mut lock = self.playing.lock().await;
@@ -240,7 +240,7 @@ impl ClientOnlineGame {
             }
             ServerOnlineGameCommands::Won(_) => {
                 let _ = self.tx_commands_second.send(SecondLevelCommands::Won).await;
-                let _ = self.app.emit(
+                self.events.emit(
                     OTHER_PLAYER_WON,
                     WonSignal {
                         player: self.self_player.clone(),
@@ -251,13 +251,13 @@ impl ClientOnlineGame {
             }
             ServerOnlineGameCommands::PlayerLost(dummy_player) => {
                 self.deaths += 1;
-                let _ = self.app.emit(OTHER_PLAYER_LOST, dummy_player);
+                self.events.emit(OTHER_PLAYER_LOST, dummy_player);
             }
             ServerOnlineGameCommands::GameEnded(dummy_player) => {
                 if dummy_player == self.self_player {
                     let _ = self.tx_commands_second.send(SecondLevelCommands::Won).await;
                 }
-                let _ = self.app.emit(
+                self.events.emit(
                     OTHER_PLAYER_WON,
                     WonSignal {
                         player: dummy_player,
@@ -267,7 +267,7 @@ impl ClientOnlineGame {
                 self.running = false;
             }
             ServerOnlineGameCommands::State(dummy_player, state) => {
-                let _ = self.app.emit(
+                self.events.emit(
                     STATE_EMIT_OTHER_PLAYERS,
                     OtherPlayerState {
                         player: dummy_player,
@@ -1,30 +1,42 @@
 use std::{
+    collections::VecDeque,
     fmt::Debug,
     ops::Range,
+    sync::Arc,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use super::super::pieces::Piece;
-use super::super::queue::local_queue::LocalQueue;
+use super::super::{
+    clock::{Clock, SystemClock},
+    game_mode::{GameMode, ModeProgress},
+    gravity_curve::GravityCurve,
+    handling::AutoShift,
+    lock_delay::LockDown,
+    master::{self, MasterProgress, MasterTimings},
+    pieces::Piece,
+    visibility::Visibility,
+};
 
 use super::super::{
     board::{
         Board, // remote_board::RemoteBoard,
-        local_board::{ClearLinePattern, LocalBoard},
+        local_board::{BoardSnapshot, ClearLinePattern, LocalBoard},
     },
     queue::Queue,
 };
-use tauri::{AppHandle, Emitter};
-use tokio::sync::mpsc::{self, Receiver, Sender};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{Receiver, Sender};
 
 use crate::{
+    events::EventSink,
     models::{
-        game_commands::{FirstLevelCommands, SecondLevelCommands},
+        game_commands::{FirstLevelCommands, HeldKey, SecondLevelCommands},
         game_info::GameInfo,
         game_options::GameOptions,
         game_responses::GameResponses,
+        replay::{Replay, ReplayCommand, ReplayInput},
     },
-    persistence::store_game_info,
+    persistence::{replays, saved_games, store_game_info},
 };
 
 const HELD_PIECE_EMIT: &str = "held_piece_emit";
@@ -37,43 +49,65 @@ const POINTS_EMIT: &str = "points";
 const GAME_OVER_EMIT: &str = "game_over";
 const GAME_WON_EMIT: &str = "game_won";
 const TIME_EMIT: &str = "time_emit";
+const PERFECT_CLEAR_EMIT: &str = "perfect_clear";
+const BACK_TO_BACK_EMIT: &str = "back_to_back";
+const COMBO_EMIT: &str = "combo";
+const PHASE_START_EMIT: &str = "phase_start";
+const PHASE_END_EMIT: &str = "phase_end";
+const PAUSED_EMIT: &str = "paused";
+const GAME_SAVED_EMIT: &str = "game_saved";
+const GRADE_EMIT: &str = "grade";
+/// The version of the game snapshot format written by this build.
+pub const GAME_SNAPSHOT_VERSION: u32 = 3;
 const NUMBER_OF_PIECES_IN_QUEUE_TO_EMIT: u128 = 5;
+/// Duration of a frame of the game loop, it executes at most 120 times per second.
+const FRAME_DURATION: Duration = Duration::from_micros(8_333);
+/// Duration of each number of the countdown before the game starts.
+const COUNTDOWN_STEP: Duration = Duration::from_secs(1);
+/// Time between the time emits of the game loop.
+const TIME_EMIT_INTERVAL: Duration = Duration::from_millis(100);
+/// Step the time of the game moves forward with, the timers are checked once for each.
+const TICK_DURATION: Duration = Duration::from_millis(1);
 
 const BUFFER_STATE_FOR_NUMBERS: &str = "EEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEE";
 const STATE_FOR_NUMBER_3: &str = "EEEEEEEEEEEEEGGGGEEEEEGEEEEGEEEGEEEEEEGEEEEEEEEEGEEEEEEEEEGEEEEEEEEEGEEEEEEEEGEEEEEEEEEGEEEEEEEEGEEEEEEEEEGEEEEEEEEEEGEEEEEEEEEGEEEEEEEEEEGEEEEEEEEEGEEEEEEEEEGEEGEEEEEEGEEEGEEEEGEEEEEGGGGEEEEEEEEEEEEE";
 const STATE_FOR_NUMBER_2: &str = "EEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEGGGGEEEEEGEEEEGEEEGEEEEEEGEEEEEEEEEGEEEEEEEEEGEEEEEEEEGEEEEEEEEGEEEEEEEEGEEEEEEEEGEEEEEEEEGEEEEEEEEGEEEEEEEEGEEEEEEEEEGEEEEEEEEEGGGGGGGGEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEE";
 const STATE_FOR_NUMBER_1: &str = "EEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEGEEEEEEEEGGEEEEEEEGEGEEEEEEGEEGEEEEEEEEEGEEEEEEEEEGEEEEEEEEEGEEEEEEEEEGEEEEEEEEEGEEEEEEEEEGEEEEEEEEEGEEEEEEEEEGEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEEE";
 
-const EXTENDED_TIME_LOCK_MILIS: u64 = 500;
-const MOVEMENTS_LEFT_RESET: u8 = 15;
+const PERFECT_CLEAR_LINES_AWARDED: u16 = 10;
+const COMBO_POINTS: u32 = 50;
+/// Lines awarded by combo, indexed by the combo chain. Longer combos use the last value.
+const COMBO_LINES_AWARDED: [u16; 11] = [0, 1, 1, 2, 2, 3, 3, 4, 4, 4, 5];
 
 #[derive(Debug)]
 /// `LocalGame` represents a single-player game instance.
 pub struct LocalGame {
-    /// Tauri application handle for emitting events.
-    app: AppHandle,
+    /// Sink for emitting events.
+    events: Arc<dyn EventSink>,
+    /// The options the game was created with.
+    options: GameOptions,
     /// The game board.
     local_board: LocalBoard,
-    /// Flag indicating if the game is in normal mode.
-    normal: bool,
-    /// Flag indicating if the game is in 40 lines mode.
-    lines_40: bool,
-    /// Flag indicating if the game is in blitz mode.
-    blitz: bool,
+    /// The mode of the game.
+    mode: GameMode,
     /// Start time of the game in seconds since the UNIX epoch.
     start_time: u64,
     /// The player's current score.
     points: u32,
     /// Flag indicating if the game has started.
     game_started: bool,
-    /// The previously cleared line pattern.
-    prev_clear_line_pattern: ClearLinePattern,
+    /// The back-to-back chain, `None` if there isn't any, `Some(0)` after the first difficult clear.
+    back_to_back: Option<u16>,
+    /// The combo chain, `None` if there isn't any, `Some(0)` after the first clear.
+    combo: Option<u16>,
     /// The current game level.
     level: u16,
     /// The number of lines cleared in the current level.
     line_clears: u16,
     /// The total number of lines cleared in the game.
     real_line_clears: u16,
+    /// The level, section times and grade of a master game.
+    master: Option<MasterProgress>,
     /// Receiver for game control commands.
     game_control: Receiver<GameControl>,
     /// Receiver for first-level commands.
@@ -84,18 +118,45 @@ pub struct LocalGame {
     run: bool,
     /// The last piece that was placed on the board.
     last_piece: Piece,
-    /// The lowest y-coordinate the piece has reached.
-    piece_lowest_y: i16,
-    /// Flag indicating if movement counting is enabled.
-    count_movements_enabled: bool,
-    /// The number of movements left before the piece is fixed.
-    movements_left: u8,
+    /// The lock delay state of the current piece.
+    lock_down: LockDown,
+    /// The delay phase running, with the time of the clock when it ends.
+    delay_phase: Option<(DelayPhase, Duration)>,
+    /// The state of the keys that repeat their action while held.
+    auto_shift: AutoShift,
     /// The game information.
     game_info: GameInfo,
     /// Flag indicating if the game info should be registered.
     register_info: bool,
     /// Optional sender for game responses.
     responder: Option<Sender<GameResponses>>,
+    /// The clock every timing decision is taken with.
+    clock: Box<dyn Clock>,
+    /// The time of the clock when the game loop started.
+    loop_start: Duration,
+    /// The time of the game, up to which the timers have been checked.
+    game_time: Duration,
+    /// The time played when the next garbage line rises, if garbage rises in the game.
+    next_garbage: Option<Duration>,
+    /// The time of the clock when the piece falls the next row.
+    next_gravity: Duration,
+    /// The time of the clock when the game was paused, if it is.
+    paused_since: Option<Duration>,
+    /// The time the game has been paused, before the current pause.
+    paused_time: Duration,
+    /// The time played before the game was resumed from a save.
+    elapsed_offset: Duration,
+    /// The record of the inputs received in the game.
+    replay: Replay,
+    /// The inputs left to execute if a replay is being played.
+    playback: Option<VecDeque<ReplayInput>>,
+    /// The number and the time played of the locks whose cells are still to be shown
+    /// differently.
+    lock_times: VecDeque<(usize, Duration)>,
+    /// The number of locks recorded in the lock times.
+    recorded_locks: usize,
+    /// The time played when a locked cell is next shown differently, if any is.
+    next_visibility_change: Option<Duration>,
 }
 
 impl LocalGame {
@@ -104,7 +165,7 @@ impl LocalGame {
     /// # Arguments
     ///
     /// * `options` - The game options.
-    /// * `app` - The Tauri application handle.
+    /// * `events` - Sink for emitting events.
     /// * `first_level_commands` - Receiver for first-level commands.
     /// * `second_level_commands` - Optional receiver for second-level commands.
     /// * `game_control_receiver` - Receiver for game control commands.
@@ -112,42 +173,76 @@ impl LocalGame {
     /// * `queue` - The queue implementation to use.
     pub fn new(
         options: GameOptions,
-        app: AppHandle,
+        events: Arc<dyn EventSink>,
         first_level_commands: Receiver<FirstLevelCommands>,
         second_level_commands: Option<Receiver<SecondLevelCommands>>,
         game_control_receiver: Receiver<GameControl>,
         responder: Option<Sender<GameResponses>>,
         queue: impl Queue + 'static,
     ) -> Self {
-        LocalGame {
-            app,
-            local_board: LocalBoard::new(queue),
+        let master = (options.mode() == GameMode::Master).then(MasterProgress::default);
+        let lock_delay = match master {
+            Some(master) => MasterTimings::of_level(master.level()).lock_delay(),
+            None => options.lock_delay(),
+        };
+        let mut local_board = LocalBoard::new(queue);
+        local_board.change_rotation_system(options.rotation_system());
+        local_board.change_big(options.big());
+        local_board.change_spawn_on_lock(
+            master.is_none()
+                && options.entry_delay_millis() == 0
+                && options.line_clear_delay_millis() == 0,
+        );
+        if let Some(seed) = options.seed() {
+            local_board.change_seed(seed);
+        }
+        let game_info = GameInfo::new(&options);
+        let replay = Replay::new(options.clone());
+        let mut game = LocalGame {
+            events,
+            local_board,
             // remote_boards: Vec::new(),
-            normal: options.is_normal(),
-            lines_40: options.is_lines_40(),
-            blitz: options.is_blitz(),
+            mode: options.mode(),
             start_time: SystemTime::now()
                 .duration_since(UNIX_EPOCH)
                 .expect("Time went backwards 🗿🤙")
                 .as_secs(),
             points: 0,
             game_started: false,
-            prev_clear_line_pattern: ClearLinePattern::None,
-            level: 1,
+            back_to_back: None,
+            combo: None,
+            level: options.marathon().starting_level(),
             line_clears: 0,
             real_line_clears: 0,
+            master,
             first_level_commands,
             second_level_commands,
             game_control: game_control_receiver,
             run: true,
             last_piece: Piece::Ghost,
-            piece_lowest_y: -20,
-            count_movements_enabled: false,
-            movements_left: MOVEMENTS_LEFT_RESET,
-            game_info: GameInfo::new(options),
+            lock_down: LockDown::new(lock_delay),
+            delay_phase: None,
+            auto_shift: AutoShift::new(options.handling()),
+            game_info,
             register_info: false,
             responder,
-        }
+            clock: Box::new(SystemClock::default()),
+            loop_start: Duration::ZERO,
+            game_time: Duration::ZERO,
+            next_gravity: Duration::ZERO,
+            next_garbage: None,
+            paused_since: None,
+            paused_time: Duration::ZERO,
+            elapsed_offset: Duration::ZERO,
+            replay,
+            playback: None,
+            lock_times: VecDeque::new(),
+            recorded_locks: 0,
+            next_visibility_change: None,
+            options,
+        };
+        game.refill_garbage();
+        game
     }
 
     /// Starts the game.
@@ -158,28 +253,21 @@ impl LocalGame {
             return;
         }
         self.run = true;
-        self.local_board = LocalBoard::new(LocalQueue::default());
-        self.app
-            .emit(
-                BOARD_STATE_EMIT,
-                format!("{}{}", BUFFER_STATE_FOR_NUMBERS, STATE_FOR_NUMBER_3),
-            )
-            .unwrap();
-        tokio::time::sleep(Duration::from_secs(1)).await;
-        self.app
-            .emit(
-                BOARD_STATE_EMIT,
-                format!("{}{}", BUFFER_STATE_FOR_NUMBERS, STATE_FOR_NUMBER_2),
-            )
-            .unwrap();
-        tokio::time::sleep(Duration::from_secs(1)).await;
-        self.app
-            .emit(
-                BOARD_STATE_EMIT,
-                format!("{}{}", BUFFER_STATE_FOR_NUMBERS, STATE_FOR_NUMBER_1),
-            )
-            .unwrap();
-        tokio::time::sleep(Duration::from_secs(1)).await;
+        self.events.emit(
+            BOARD_STATE_EMIT,
+            format!("{}{}", BUFFER_STATE_FOR_NUMBERS, STATE_FOR_NUMBER_3),
+        );
+        self.clock.sleep(COUNTDOWN_STEP).await;
+        self.events.emit(
+            BOARD_STATE_EMIT,
+            format!("{}{}", BUFFER_STATE_FOR_NUMBERS, STATE_FOR_NUMBER_2),
+        );
+        self.clock.sleep(COUNTDOWN_STEP).await;
+        self.events.emit(
+            BOARD_STATE_EMIT,
+            format!("{}{}", BUFFER_STATE_FOR_NUMBERS, STATE_FOR_NUMBER_1),
+        );
+        self.clock.sleep(COUNTDOWN_STEP).await;
         self.game_loop().await;
     }
 
@@ -187,30 +275,51 @@ impl LocalGame {
     ///
     /// This function handles game logic, processes commands, and emits events.
     async fn game_loop(&mut self) {
-        let (tx, mut rx) = mpsc::channel(32);
-        let (tx_points, rx_points) = mpsc::channel(32);
-        let (tx_extended_lock, mut rx_extended_lock) = mpsc::channel(32);
-        Self::tick_loop(tx, rx_points).await;
         self.queue_emit();
         self.state_emit().await;
-        self.game_started = true;
         let mut forfeited = false;
+        let mut saved = false;
         self.start_time = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .expect("Time went backwards 🗿🤙")
             .as_secs();
-        let mut prev_time = self.start_time;
-        while self.first_level_commands.try_recv().is_ok() {} // Empty possible orders given before start
+        self.game_time = self.clock_time();
+        self.loop_start = self.game_time;
+        self.next_gravity = self.loop_start + self.row_interval().unwrap_or_default();
+        if !self.elapsed_offset.is_zero() {
+            self.resumed_emit();
+        }
+        let mut next_time_emit = self.elapsed() + TIME_EMIT_INTERVAL;
+        self.next_garbage = self
+            .mode
+            .garbage_interval(self.elapsed())
+            .map(|interval| self.elapsed() + interval);
+        // Empty possible orders given before start, keeping track of the keys held
+        while let Ok(command) = self.first_level_commands.try_recv() {
+            if self.playback.is_none()
+                && matches!(
+                    command,
+                    FirstLevelCommands::KeyPressed(_) | FirstLevelCommands::KeyReleased(_)
+                )
+            {
+                self.first_level_command(command).await;
+            }
+        }
+        // The inputs of the replay given before the start
+        self.playback_checks().await;
+        self.game_started = true;
+        // A game saved between pieces goes through the delays again
+        if self.local_board.spawn_pending() {
+            self.start_delay_phases(self.now()).await;
+        } else {
+            self.initial_actions();
+        }
         // Game loop
         while self.run {
-            // First of all execute the critical checks first
-            self.critical_checks(
-                &tx_points,
-                &mut rx_extended_lock,
-                tx_extended_lock.clone(),
-                &mut rx,
-            )
-            .await;
+            // First of all execute the checks of the time passed, nothing happens while paused
+            if self.paused_since.is_none() {
+                self.tick_checks().await;
+            }
             // Control operations such as forfeit and retry
             // If one of these execute it doesnt make sense to check for the rest
             while let Ok(control) = self.game_control.try_recv() {
@@ -232,287 +341,660 @@ impl LocalGame {
                             self.run = false;
                         }
                     }
+                    GameControl::Pause => self.pause().await,
+                    GameControl::Resume => self.resume().await,
+                    GameControl::Save => saved = self.save(),
                 }
             }
-            // Checks of the movements
-            self.first_level_checks(
-                &tx_points,
-                &mut rx_extended_lock,
-                tx_extended_lock.clone(),
-                &mut rx,
-            )
-            .await;
+            // Checks of the movements, once the game time has moved so the inputs taken at the
+            // start of the game are only the ones given before it
+            if self.game_time > self.loop_start {
+                self.first_level_checks().await;
 
-            self.second_level_checks(
-                &tx_points,
-                &mut rx_extended_lock,
-                tx_extended_lock.clone(),
-                &mut rx,
-            )
-            .await;
+                self.second_level_checks().await;
+            }
             // Makes sure that executes max 120 times per second, no need for more
             // and it avoids CPU overuse
-            tokio::time::sleep(Duration::from_micros(8_333)).await;
+            self.clock.sleep(FRAME_DURATION).await;
             // Time calculations
-            let cur_time = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .expect("Time went backwards 🗿🤙")
-                .as_secs();
-            if cur_time > prev_time {
-                prev_time = cur_time;
-                self.time_emit(cur_time);
-                // if self.blitz && self.local_board.game_won(self.get_win_condition()) {
-                // self.game_won_emit();
-                // self.run = false;
-                // }
+            let elapsed = self.elapsed();
+            if elapsed >= next_time_emit {
+                next_time_emit += TIME_EMIT_INTERVAL;
+                self.time_emit(elapsed);
+            }
+            // The time limit is reached even if no piece is locked in the meantime
+            if self.run
+                && self.paused_since.is_none()
+                && self.mode.time_limit().is_some_and(|limit| elapsed >= limit)
+            {
+                self.game_end_checks().await;
             }
         }
         if forfeited {
             self.game_over_emit(true);
         }
+        // A saved game isn't over, it's registered once it ends after being resumed
+        if self.playback.is_some() || saved {
+            return;
+        }
         if self.register_info && self.second_level_commands.is_none() {
             self.register_info().await;
         }
+        self.register_replay().await;
+    }
+    /// Moves the time of the game a tick at a time up to the time of the clock, executing the
+    /// critical checks and the inputs of the replay being played at each tick.
+    ///
+    /// The timers go through the same ticks however often the loop runs, so a replay
+    /// reproduces the game exactly.
+    async fn tick_checks(&mut self) {
+        let clock_time = self.clock_time();
+        while self.run && self.game_time + TICK_DURATION <= clock_time {
+            self.game_time += TICK_DURATION;
+            self.critical_checks().await;
+            self.playback_checks().await;
+        }
     }
     /// In this method there are checks crucial for the gameplay,
     /// related to piece fixation and countdown. Generally, anything
     /// that can make you lose the game
-    ///
-    /// # Arguments
-    ///
-    /// * `tx_points` - The sender for points.
-    /// * `rx_extended_lock` - The receiver for extended lock.
-    /// * `tx_extended_lock` - The sender for extended lock.
-    /// * `rx` - The receiver for the tick.
-    async fn critical_checks(
-        &mut self,
-        tx_points: &Sender<u16>,
-        rx_extended_lock: &mut Receiver<i16>,
-        tx_extended_lock: Sender<i16>,
-        rx: &mut Receiver<bool>,
-    ) {
-        // Register the lowest point of y
-        if self.local_board.piece_y() > self.piece_lowest_y {
-            self.piece_lowest_y = self.local_board.piece_y();
+    async fn critical_checks(&mut self) {
+        self.rising_garbage_checks();
+        self.visibility_checks().await;
+        // While a delay phase runs there's no piece to move
+        let now = self.now();
+        if self.delay_checks(now).await {
+            return;
         }
-        // If the movements left drop to 0 and are enabled, piece gets fixed
-        if self.movements_left == 0 && self.count_movements_enabled {
+        // If the piece is at bottom (that meeaning that it cannot go down) the lock delay
+        // decides when it gets fixed
+        let at_bottom = self.local_board.piece_at_bottom();
+        if self
+            .lock_down
+            .update(now, self.local_board.piece_y(), at_bottom)
+        {
             self.local_board.hard_drop();
             self.state_emit().await;
-            self.piece_fixed(tx_points).await;
-        }
-        // If the max time for the piece fixed has passed, and the current y coordinate is
-        // greater than or equal than the lowest point of y then the piece gets fixed
-        while let Ok(y) = rx_extended_lock.try_recv() {
-            if y >= self.piece_lowest_y && self.count_movements_enabled {
-                self.local_board.hard_drop();
-                self.state_emit().await;
-                self.piece_fixed(tx_points).await;
-            }
+            self.piece_fixed().await;
         }
-        // If the piece is at bottom (that meeaning that it cannot go down) it starts the countdown
-        // for movements and time
-        if self.local_board.piece_at_bottom() {
-            self.count_movements_enabled = true;
-            self.movements_left = MOVEMENTS_LEFT_RESET;
-            self.piece_lowest_y = self.local_board.piece_y();
-            Self::extended_lock_down(tx_extended_lock.clone(), self.piece_lowest_y).await;
+        // Auto repeat of the held direction
+        if let Some((key, columns)) = self.auto_shift.shifts(now)
+            && self.shift(key, columns)
+        {
+            self.state_emit().await;
         }
-        // Game tick
-        if rx.try_recv().is_ok() && !self.count_movements_enabled {
+        // Game tick, with high gravity the piece falls more than a row in a frame
+        let rows = self.gravity_rows(now);
+        if rows > 0 && !self.local_board.piece_at_bottom() {
             self.last_piece = self.local_board.cur_piece();
-            if self.local_board.next_tick() {
-                self.piece_fixed(tx_points).await;
+            for _ in 0..rows {
+                self.local_board.next_tick();
+                if self.local_board.piece_at_bottom() {
+                    break;
+                }
             }
             self.state_emit().await;
         }
     }
-    /// Checks for piece movements
+    /// Queues the next garbage line once its time has come, set in the board when the next
+    /// piece spawns unless the clears counter it first.
+    fn rising_garbage_checks(&mut self) {
+        let Some(next_garbage) = self.next_garbage else {
+            return;
+        };
+        let elapsed = self.elapsed();
+        if elapsed < next_garbage {
+            return;
+        }
+        self.local_board.insert_trash(1);
+        self.next_garbage = self
+            .mode
+            .garbage_interval(elapsed)
+            .map(|interval| next_garbage + interval);
+    }
+    /// Emits the board again once a locked cell is to be shown differently.
+    async fn visibility_checks(&mut self) {
+        let Some(next_visibility_change) = self.next_visibility_change else {
+            return;
+        };
+        let elapsed = self.elapsed();
+        if elapsed < next_visibility_change {
+            return;
+        }
+        self.update_visibility_changes(elapsed);
+        self.state_emit().await;
+    }
+    /// Records the time played of the pieces locked since the last call.
+    fn record_locks(&mut self) {
+        let locks = self.local_board.locks();
+        if locks == self.recorded_locks {
+            return;
+        }
+        let elapsed = self.elapsed();
+        (self.recorded_locks + 1..=locks)
+            .for_each(|lock| self.lock_times.push_back((lock, elapsed)));
+        self.recorded_locks = locks;
+        self.update_visibility_changes(elapsed);
+    }
+    /// Forgets the locks whose cells won't be shown differently anymore and sets when the next
+    /// locked cell will be.
     ///
     /// # Arguments
     ///
-    /// * `tx_points` - The sender for points.
-    /// * `rx_extended_lock` - The receiver for extended lock.
-    /// * `tx_extended_lock` - The sender for extended lock.
-    /// * `rx` - The receiver for the tick.
-    async fn first_level_checks(
-        &mut self,
-        tx_points: &Sender<u16>,
-        rx_extended_lock: &mut Receiver<i16>,
-        tx_extended_lock: Sender<i16>,
-        rx: &mut Receiver<bool>,
-    ) {
+    /// * `elapsed` - The time played.
+    fn update_visibility_changes(&mut self, elapsed: Duration) {
+        let visibility = self.options.visibility();
+        self.lock_times.retain(|(_, locked)| {
+            visibility
+                .next_change(elapsed.saturating_sub(*locked))
+                .is_some()
+        });
+        self.next_visibility_change = self
+            .lock_times
+            .iter()
+            .filter_map(|(_, locked)| {
+                visibility
+                    .next_change(elapsed.saturating_sub(*locked))
+                    .map(|change| *locked + change)
+            })
+            .min();
+    }
+    /// Returns the state of the board as the player is allowed to see it.
+    ///
+    /// The visibility is applied here rather than in the frontend so the hidden cells can't be
+    /// read from the state. The cells of the locks already forgotten are shown as if they had
+    /// been locked long ago.
+    fn visible_board_state(&mut self) -> String {
+        let visibility = self.options.visibility();
+        if visibility == Visibility::Visible {
+            return self.local_board.board_state();
+        }
+        self.record_locks();
+        let elapsed = self.elapsed();
+        self.local_board.masked_board_state(
+            |lock| {
+                let since_locked = lock.and_then(|lock| {
+                    self.lock_times
+                        .iter()
+                        .find(|(recorded, _)| *recorded == lock)
+                        .map(|(_, locked)| elapsed.saturating_sub(*locked))
+                });
+                visibility.shown(since_locked)
+            },
+            !visibility.hides_ghost(),
+        )
+    }
+    /// Checks for piece movements
+    async fn first_level_checks(&mut self) {
         while let Ok(command) = self.first_level_commands.try_recv() {
-            if !self.game_started {
+            // While playing a replay the player input is ignored
+            if !self.game_started || self.playback.is_some() {
                 continue;
             }
-            match command {
-                FirstLevelCommands::RightMove => {
-                    if self.local_board.move_right() {
-                        self.count_movements();
-                        if self.second_level_commands.is_none() {
-                            self.game_info.piece_moved();
-                        }
-                    }
-                }
-                FirstLevelCommands::LeftMove => {
-                    if self.local_board.move_left() {
-                        self.count_movements();
-                        if self.second_level_commands.is_none() {
-                            self.game_info.piece_moved();
-                        }
-                    }
-                }
-                FirstLevelCommands::ClockWiseRotation => {
-                    self.local_board.rotation_clockwise();
+            // Between pieces and while paused only the held keys are tracked
+            if (self.delay_phase.is_some() || self.paused_since.is_some())
+                && !matches!(
+                    command,
+                    FirstLevelCommands::KeyPressed(_) | FirstLevelCommands::KeyReleased(_)
+                )
+            {
+                continue;
+            }
+            self.first_level_command(command).await;
+        }
+    }
+
+    /// Executes a first level command and records it in the replay
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command to execute.
+    async fn first_level_command(&mut self, command: FirstLevelCommands) {
+        self.replay.record(
+            self.elapsed_millis(),
+            ReplayCommand::FirstLevel(command.clone()),
+        );
+        match command {
+            FirstLevelCommands::RightMove => {
+                if self.local_board.move_right() {
+                    self.lock_down.piece_moved(self.now());
                     if self.second_level_commands.is_none() {
-                        self.game_info.spinned();
+                        self.game_info.piece_moved();
                     }
                 }
-                FirstLevelCommands::CounterClockWiseRotation => {
-                    self.local_board.rotation_counterclockwise();
+            }
+            FirstLevelCommands::LeftMove => {
+                if self.local_board.move_left() {
+                    self.lock_down.piece_moved(self.now());
                     if self.second_level_commands.is_none() {
-                        self.game_info.spinned();
+                        self.game_info.piece_moved();
                     }
                 }
-                FirstLevelCommands::HardDrop => {
-                    self.local_board.hard_drop();
-                    self.piece_fixed(tx_points).await;
-                    self.state_emit().await;
-                }
-                FirstLevelCommands::SoftDrop => {
-                    self.local_board.soft_drop();
+            }
+            FirstLevelCommands::ClockWiseRotation => {
+                self.rotate(HeldKey::ClockWiseRotation);
+            }
+            FirstLevelCommands::CounterClockWiseRotation => {
+                self.rotate(HeldKey::CounterClockWiseRotation);
+            }
+            FirstLevelCommands::HardDrop => {
+                self.local_board.hard_drop();
+                self.piece_fixed().await;
+                self.state_emit().await;
+            }
+            FirstLevelCommands::SoftDrop => {
+                self.local_board.soft_drop();
+            }
+            FirstLevelCommands::SavePiece => {
+                self.save_piece();
+            }
+            FirstLevelCommands::FullRotation => {
+                self.rotate(HeldKey::FullRotation);
+            }
+            FirstLevelCommands::KeyPressed(key) => {
+                let now = self.now();
+                // Before the game starts and between pieces the keys are only tracked
+                let piece_active = self.game_started
+                    && self.paused_since.is_none()
+                    && !self.local_board.spawn_pending();
+                if self.auto_shift.press(key, now) && piece_active {
+                    self.shift(key, 1);
                 }
-                FirstLevelCommands::SavePiece => {
-                    let piece = self.local_board.held_piece();
-                    self.local_board.save_piece();
-                    self.count_movements_enabled = false;
-                    if piece != self.local_board.held_piece() {
-                        self.emit_held_piece();
-                        self.queue_emit();
+                match key {
+                    HeldKey::SoftDrop if piece_active => {
+                        // The piece falls a row at once and then at the soft drop speed
+                        self.next_gravity = now;
                     }
-                }
-                FirstLevelCommands::FullRotation => {
-                    self.local_board.rotation_full();
-                    if self.second_level_commands.is_none() {
-                        self.game_info.spinned();
+                    HeldKey::ClockWiseRotation
+                    | HeldKey::CounterClockWiseRotation
+                    | HeldKey::FullRotation
+                        if piece_active =>
+                    {
+                        self.rotate(key);
                     }
+                    HeldKey::SavePiece if piece_active => self.save_piece(),
+                    _ => (),
                 }
             }
-            self.state_emit().await;
-            self.critical_checks(tx_points, rx_extended_lock, tx_extended_lock.clone(), rx)
-                .await;
+            FirstLevelCommands::KeyReleased(key) => {
+                let now = self.now();
+                self.auto_shift.release(key, now);
+                if key == HeldKey::SoftDrop {
+                    self.next_gravity = now + self.row_interval().unwrap_or_default();
+                }
+            }
+        }
+        self.state_emit().await;
+        // Nothing happens before the game starts or while it's paused
+        if self.game_started && self.paused_since.is_none() {
+            self.critical_checks().await;
         }
     }
 
     /// Checks for second level commands
-    ///
-    /// # Arguments
-    ///
-    /// * `tx_points` - The sender for points.
-    /// * `rx_extended_lock` - The receiver for extended lock.
-    /// * `tx_extended_lock` - The sender for extended lock.
-    /// * `rx` - The receiver for the tick.
-    async fn second_level_checks(
-        &mut self,
-        tx_points: &Sender<u16>,
-        rx_extended_lock: &mut Receiver<i16>,
-        tx_extended_lock: Sender<i16>,
-        rx: &mut Receiver<bool>,
-    ) {
+    async fn second_level_checks(&mut self) {
         if self.second_level_commands.is_none() {
             return;
         }
         if let Ok(command) = self.second_level_commands.as_mut().unwrap().try_recv() {
-            match command {
-                SecondLevelCommands::QueueSync(pieces) => {
-                    self.local_board.insert_in_queue(pieces);
-                }
-                SecondLevelCommands::TrashReceived(amount) => {
-                    self.local_board.insert_trash(amount as u8);
-                }
-                SecondLevelCommands::StrategyChange(strategy) => {
-                    self.responder
-                        .as_mut()
-                        .unwrap()
-                        .send(GameResponses::Strategy(strategy))
-                        .await
-                        .unwrap();
-                }
-                SecondLevelCommands::Won => {
-                    self.game_won_emit();
-                    self.run = false;
+            self.second_level_command(command).await;
+            self.first_level_checks().await;
+        }
+    }
+
+    /// Executes a second level command and records it in the replay
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The command to execute.
+    async fn second_level_command(&mut self, command: SecondLevelCommands) {
+        self.replay.record(
+            self.elapsed_millis(),
+            ReplayCommand::SecondLevel(command.clone()),
+        );
+        match command {
+            SecondLevelCommands::QueueSync(pieces) => {
+                self.local_board.insert_in_queue(pieces);
+            }
+            SecondLevelCommands::TrashReceived(amount) => {
+                self.local_board.insert_trash(amount as u8);
+            }
+            SecondLevelCommands::StrategyChange(strategy) => {
+                self.responder
+                    .as_mut()
+                    .unwrap()
+                    .send(GameResponses::Strategy(strategy))
+                    .await
+                    .unwrap();
+            }
+            SecondLevelCommands::Won => {
+                self.game_won_emit();
+                self.run = false;
+            }
+            SecondLevelCommands::AskForQueue => {
+                let _ = self
+                    .responder
+                    .as_mut()
+                    .unwrap()
+                    .send(GameResponses::Queue(self.local_board.get_queue()))
+                    .await;
+            }
+        }
+    }
+
+    /// Executes the inputs of the replay being played up to the current tick.
+    ///
+    /// Only the second level commands that change the board are executed, the rest
+    /// depend on the online game and are skipped.
+    async fn playback_checks(&mut self) {
+        let elapsed = self.elapsed_millis();
+        loop {
+            let Some(inputs) = self.playback.as_mut() else {
+                return;
+            };
+            if inputs.front().is_none_or(|input| input.time() > elapsed) {
+                return;
+            }
+            let input = inputs.pop_front().expect("Checked that there is an input");
+            match input.command().clone() {
+                ReplayCommand::FirstLevel(command) => {
+                    self.first_level_command(command).await;
                 }
-                SecondLevelCommands::AskForQueue => {
-                    let _ = self
-                        .responder
-                        .as_mut()
-                        .unwrap()
-                        .send(GameResponses::Queue(self.local_board.get_queue()))
-                        .await;
+                ReplayCommand::SecondLevel(
+                    command @ (SecondLevelCommands::TrashReceived(_) | SecondLevelCommands::Won),
+                ) => {
+                    self.second_level_command(command).await;
                 }
+                ReplayCommand::SecondLevel(_) => (),
             }
-            self.first_level_checks(tx_points, rx_extended_lock, tx_extended_lock, rx)
-                .await;
         }
     }
 
-    /// Counts movements for the piece fixation
-    fn count_movements(&mut self) {
-        if self.count_movements_enabled {
-            self.movements_left -= 1;
+    /// Returns the time of the game, which moves a tick at a time as the timers are checked.
+    fn now(&self) -> Duration {
+        self.game_time
+    }
+
+    /// Returns the time of the clock without the time paused.
+    /// It stays the same while the game is paused, so every timer freezes.
+    fn clock_time(&self) -> Duration {
+        let now = self.clock.now();
+        let paused = self.paused_time
+            + self
+                .paused_since
+                .map_or(Duration::ZERO, |since| now - since);
+        now.saturating_sub(paused)
+    }
+
+    /// Returns the time elapsed since the game loop started, including the time played
+    /// before the game was saved.
+    fn elapsed(&self) -> Duration {
+        self.now().saturating_sub(self.loop_start) + self.elapsed_offset
+    }
+
+    /// Pauses the game, hiding the board. Online games can't be paused.
+    async fn pause(&mut self) {
+        if self.second_level_commands.is_some() || self.paused_since.is_some() || !self.game_started
+        {
+            return;
         }
+        self.paused_since = Some(self.clock.now());
+        self.events.emit(PAUSED_EMIT, true);
+        self.state_emit().await;
     }
-    /// This keeps track of the time. Receives the level of the game to execute the loop faster
-    ///
-    /// # Arguments
+
+    /// Resumes the game if it is paused.
+    async fn resume(&mut self) {
+        let Some(since) = self.paused_since.take() else {
+            return;
+        };
+        self.paused_time += self.clock.now() - since;
+        self.events.emit(PAUSED_EMIT, false);
+        self.state_emit().await;
+    }
+
+    /// Saves the game and ends it, so it can be resumed later. Online games and replays
+    /// can't be saved.
     ///
-    /// * `sender` - The sender for the tick.
-    /// * `receiver` - The receiver for the level.
-    async fn tick_loop(sender: Sender<bool>, mut receiver: Receiver<u16>) {
-        // !  This thread shouldn't abruptly die, it's not fine if it dies
+    /// Returns true if the game has been saved.
+    fn save(&mut self) -> bool {
+        if self.second_level_commands.is_some() || self.playback.is_some() || !self.game_started {
+            return false;
+        }
+        let snapshot = self.snapshot();
         tokio::spawn(async move {
-            let mut level = 1;
-            loop {
-                if let Ok(level_received) = receiver.try_recv() {
-                    level = level_received;
-                }
-                let duration = (level - 1) as f64 * 0.007;
-                let duration = 0.8 - duration;
-                let duration = (duration.powf((level - 1) as f64) * 1000.0).round() as u64;
-                tokio::time::sleep(Duration::from_millis(duration)).await;
-                match sender.send(true).await {
-                    Ok(_) => (),
-                    Err(_) => break,
-                }
-            }
+            saved_games::store_saved_game(snapshot).await;
         });
+        self.run = false;
+        self.events.emit(GAME_SAVED_EMIT, true);
+        true
+    }
+
+    /// Returns a snapshot of the state of the game, so it can be resumed later.
+    pub fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            version: GAME_SNAPSHOT_VERSION,
+            options: self.options.clone(),
+            board: self.local_board.snapshot(),
+            elapsed_millis: self.elapsed_millis(),
+            points: self.points,
+            level: self.level,
+            line_clears: self.line_clears,
+            real_line_clears: self.real_line_clears,
+            master: self.master,
+            back_to_back: self.back_to_back,
+            combo: self.combo,
+            game_info: self.game_info,
+            replay: self.replay.clone(),
+            lock_times: self
+                .lock_times
+                .iter()
+                .map(|(lock, locked)| (*lock, locked.as_millis() as u64))
+                .collect(),
+        }
     }
-    /// This keeps the track for the extended piece lock down
+
+    /// Restores the state of a saved game, so it continues where it was saved.
+    ///
+    /// The game must have been created with the options and the queue of the snapshot, and
+    /// the current piece starts its lock delay again.
     ///
     /// # Arguments
     ///
-    /// * `sender` - The sender for the extended lock.
-    /// * `lowest_y` - The lowest y coordinate of the piece.
-    async fn extended_lock_down(sender: Sender<i16>, lowest_y: i16) {
-        tokio::spawn(async move {
-            tokio::time::sleep(Duration::from_millis(EXTENDED_TIME_LOCK_MILIS)).await;
-            let _ = sender.send(lowest_y).await;
-        });
+    /// * `snapshot` - The snapshot of the saved game.
+    pub fn restore(&mut self, snapshot: GameSnapshot) {
+        self.local_board.restore(snapshot.board);
+        self.elapsed_offset = Duration::from_millis(snapshot.elapsed_millis);
+        self.points = snapshot.points;
+        self.level = snapshot.level;
+        self.line_clears = snapshot.line_clears;
+        self.real_line_clears = snapshot.real_line_clears;
+        self.master = snapshot.master;
+        if let Some(master) = self.master {
+            self.lock_down
+                .set_policy(MasterTimings::of_level(master.level()).lock_delay());
+        }
+        self.back_to_back = snapshot.back_to_back;
+        self.combo = snapshot.combo;
+        self.game_info = snapshot.game_info;
+        self.replay = snapshot.replay;
+        self.lock_times = snapshot
+            .lock_times
+            .into_iter()
+            .map(|(lock, locked)| (lock, Duration::from_millis(locked)))
+            .collect();
+        self.recorded_locks = self.local_board.locks();
+        self.update_visibility_changes(self.elapsed_offset);
     }
-    /// Checks for piece fixed. Emits the state necessary to the frontend, checks if game has been los or won,
-    /// and performs operations for, checking lines cleareance
+
+    /// Returns the milliseconds elapsed since the game loop started.
+    fn elapsed_millis(&self) -> u64 {
+        self.elapsed().as_millis() as u64
+    }
+
+    /// Replaces the clock of the game, so its time can be controlled.
+    ///
+    /// Must be called before starting the game.
+    ///
+    /// # Arguments
+    ///
+    /// * `clock` - The clock to use.
+    pub fn set_clock(&mut self, clock: Box<dyn Clock>) {
+        self.clock = clock;
+    }
+
+    /// Plays the given replay instead of listening to the player.
+    ///
+    /// The game must have been created with the options of the replay.
+    ///
+    /// # Arguments
+    ///
+    /// * `replay` - The replay to play.
+    pub fn play_replay(&mut self, replay: Replay) {
+        self.playback = Some(replay.into_inputs().into());
+    }
+
+    /// Returns the rows the piece has to fall at the given time, following the gravity curve
+    /// of the game. With instant gravity the piece falls until it reaches the floor.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time of the clock.
+    fn gravity_rows(&mut self, now: Duration) -> u32 {
+        if GravityCurve::instant(self.current_gravity()) {
+            self.next_gravity = now;
+            return u32::MAX;
+        }
+        let Some(interval) = self.row_interval() else {
+            self.next_gravity = now;
+            return 0;
+        };
+        let mut rows: u32 = 0;
+        while now >= self.next_gravity {
+            rows = rows.saturating_add(1);
+            self.next_gravity += interval;
+        }
+        rows
+    }
+    /// Returns the gravity the piece falls with, faster while soft drop is held.
+    fn current_gravity(&self) -> f64 {
+        let gravity = match self.master {
+            Some(master) => master::gravity(master.level()),
+            None => self.options.gravity().gravity(self.level),
+        };
+        if self.auto_shift.soft_dropping() {
+            gravity * self.options.handling().soft_drop_factor() as f64
+        } else {
+            gravity
+        }
+    }
+
+    /// Returns the time the completed lines stay on the board, following the section of a
+    /// master game.
+    fn current_line_clear_delay(&self) -> Duration {
+        Duration::from_millis(match self.master {
+            Some(master) => MasterTimings::of_level(master.level()).line_clear_delay_millis(),
+            None => self.options.line_clear_delay_millis(),
+        })
+    }
+
+    /// Returns the time before the next piece spawns, following the section of a master game.
+    fn current_entry_delay(&self) -> Duration {
+        Duration::from_millis(match self.master {
+            Some(master) => MasterTimings::of_level(master.level()).entry_delay_millis(),
+            None => self.options.entry_delay_millis(),
+        })
+    }
+
+    /// Returns the time the piece takes to fall a row, `None` if it doesn't fall.
+    fn row_interval(&self) -> Option<Duration> {
+        GravityCurve::interval(self.current_gravity())
+    }
+
+    /// Shifts the piece the given columns in the direction of the key, until it gets blocked.
+    ///
+    /// Returns true if the piece has moved.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The direction to shift the piece to.
+    /// * `columns` - The columns to shift the piece.
+    fn shift(&mut self, key: HeldKey, columns: u32) -> bool {
+        let mut moved = false;
+        for _ in 0..columns {
+            let shifted = match key {
+                HeldKey::Left => self.local_board.move_left(),
+                HeldKey::Right => self.local_board.move_right(),
+                _ => false,
+            };
+            if !shifted {
+                break;
+            }
+            moved = true;
+            self.lock_down.piece_moved(self.now());
+            if self.second_level_commands.is_none() {
+                self.game_info.piece_moved();
+            }
+        }
+        moved
+    }
+
+    /// Rotates the piece in the direction of the key.
     ///
     /// # Arguments
     ///
-    /// * `sender` - The sender for the level.
-    async fn piece_fixed(&mut self, sender: &Sender<u16>) {
-        self.piece_lowest_y = -20;
-        self.count_movements_enabled = false;
-        self.movements_left = MOVEMENTS_LEFT_RESET;
+    /// * `key` - The rotation key.
+    fn rotate(&mut self, key: HeldKey) {
+        let rotated = match key {
+            HeldKey::ClockWiseRotation => self.local_board.rotation_clockwise(),
+            HeldKey::CounterClockWiseRotation => self.local_board.rotation_counterclockwise(),
+            HeldKey::FullRotation => self.local_board.rotation_full(),
+            _ => return,
+        };
+        if rotated {
+            self.lock_down.piece_moved(self.now());
+            self.auto_shift.cut(self.now());
+        }
+        if self.second_level_commands.is_none() {
+            self.game_info.spinned();
+        }
+    }
+
+    /// Saves the current piece, swapping it with the held one.
+    fn save_piece(&mut self) {
+        let piece = self.local_board.held_piece();
+        self.local_board.save_piece();
+        self.lock_down.new_piece();
+        if piece != self.local_board.held_piece() {
+            self.emit_held_piece();
+            self.queue_emit();
+        }
+    }
+
+    /// Applies the hold and rotation keys held while the piece spawns (IHS and IRS), checked
+    /// against its spawn position.
+    fn initial_actions(&mut self) {
+        if !self.options.initial_actions() {
+            return;
+        }
+        if self.auto_shift.hold_held() {
+            self.save_piece();
+        }
+        if let Some(rotation) = self.auto_shift.rotation_held() {
+            self.rotate(rotation);
+        }
+    }
+
+    /// Checks for piece fixed. Emits the state necessary to the frontend, checks if game has been los or won,
+    /// and performs operations for, checking lines cleareance
+    async fn piece_fixed(&mut self) {
+        self.lock_down.new_piece();
+        self.record_locks();
+        self.auto_shift.cut(self.now());
         self.queue_emit();
         self.piece_fixed_emit();
         self.check_line_cleared().await;
+        self.refill_garbage();
         if self.second_level_commands.is_none() {
             self.game_info.piece_used();
         }
@@ -526,23 +1008,30 @@ impl LocalGame {
                 .await;
         }
 
+        self.game_end_checks().await;
+        if !self.run {
+            return;
+        }
+        if self.local_board.spawn_pending() {
+            self.start_delay_phases(self.now()).await;
+        } else {
+            self.initial_actions();
+        }
+    }
+
+    /// Checks if the game has been lost or won, and ends it if so.
+    async fn game_end_checks(&mut self) {
         let game_over = self.local_board.game_over();
         let game_won = self.local_board.game_won(self.get_win_condition());
+        if game_won || game_over {
+            // The exact final time, the loop only emits it from time to time
+            self.time_emit(self.final_elapsed());
+        }
         if game_won {
             self.game_won_emit();
             self.run = false;
             self.register_info = true;
-            let now_time = SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .expect("Time went backwards 🗿🤙")
-                .as_secs();
-            if self.second_level_commands.is_none() {
-                self.game_info.register_final_info(
-                    now_time - self.start_time,
-                    self.points,
-                    self.level,
-                );
-            }
+            self.register_final_info(true);
         } else if game_over {
             if self.responder.is_some() {
                 let _ = self
@@ -553,82 +1042,255 @@ impl LocalGame {
                     .await;
             }
             self.run = false;
-            if self.normal {
+            if self.mode.finishes_on_game_over() {
                 self.game_over_emit(false);
                 self.register_info = true;
-                let now_time = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .expect("Time went backwards 🗿🤙")
-                    .as_secs();
-                if self.second_level_commands.is_none() {
-                    self.game_info.register_final_info(
-                        now_time - self.start_time,
-                        self.points,
-                        self.level,
-                    );
-                }
+                self.register_final_info(false);
             } else {
                 self.game_over_emit(true);
             }
         }
-        sender.send(self.level).await.unwrap();
     }
 
-    /// Gets the win condition for the game.
-    fn get_win_condition(&self) -> impl Fn(bool, u32) -> bool {
-        let seconds = self.start_time;
-        let normal_win_condition = move |_game_over, _lines_cleared| false;
-        let blitz_time_condition = move |game_over, _lines_cleared| {
-            if game_over {
-                return false;
-            }
-            SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap()
-                .as_secs()
-                - seconds
-                >= 120
-        };
-        let lines_40_win_condition = move |game_over, lines_cleared| {
-            if game_over {
-                return false;
-            }
-            lines_cleared >= 40
-        };
-        move |game_over, lines_cleared| match (self.normal, self.blitz, self.lines_40) {
-            (true, false, false) => normal_win_condition(game_over, lines_cleared),
-            (false, true, false) => blitz_time_condition(game_over, lines_cleared),
-            (false, false, true) => lines_40_win_condition(game_over, lines_cleared),
-            _ => panic!("Invalid state"),
+    /// Registers the final information of the game, unless it's an online game.
+    ///
+    /// # Arguments
+    ///
+    /// * `won` - Whether the game has been won.
+    fn register_final_info(&mut self, won: bool) {
+        if self.second_level_commands.is_some() {
+            return;
+        }
+        if let Some(master) = &self.master {
+            self.game_info.register_master(master);
         }
+        self.game_info.register_final_info(
+            self.final_elapsed().as_millis() as u64,
+            self.points,
+            self.level,
+            won,
+        );
     }
 
-    /// Checks if the line has been cleared and performs the neccesary calculations
-    async fn check_line_cleared(&mut self) {
-        let pattern = self.local_board.clear_line_pattern();
-        if pattern != ClearLinePattern::None {
-            self.points_calculation(pattern);
-            self.lines_awarded_calculation(pattern).await;
-            if self.line_clears >= self.level * 5 {
-                self.level += 1;
-                self.line_clears = 0;
-            }
-            if self.normal || self.lines_40 {
-                self.line_emit(pattern);
-            } else {
-                self.points_emit();
+    /// Returns the time played when the game ends, which is never past the time limit of the
+    /// game even if the limit is noticed some time after it's reached.
+    fn final_elapsed(&self) -> Duration {
+        let elapsed = self.elapsed();
+        self.mode
+            .time_limit()
+            .map_or(elapsed, |limit| elapsed.min(limit))
+    }
+
+    /// Starts the delay phases after a piece has been fixed. Without delays the next piece
+    /// has already spawned.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time of the clock.
+    async fn start_delay_phases(&mut self, now: Duration) {
+        if !self.local_board.spawn_pending() {
+            return;
+        }
+        let line_clear_delay = self.current_line_clear_delay();
+        if self.local_board.lines_pending() && !line_clear_delay.is_zero() {
+            self.start_delay_phase(DelayPhase::LineClear, now + line_clear_delay);
+            return;
+        }
+        self.local_board.clear_pending_lines();
+        self.entry_delay(now).await;
+    }
+
+    /// Starts the entry delay, or spawns the next piece if there isn't any.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time of the clock.
+    async fn entry_delay(&mut self, now: Duration) {
+        let entry_delay = self.current_entry_delay();
+        if entry_delay.is_zero() {
+            self.spawn_piece().await;
+        } else {
+            self.start_delay_phase(DelayPhase::Entry, now + entry_delay);
+        }
+    }
+
+    /// Starts a delay phase and emits its start.
+    ///
+    /// # Arguments
+    ///
+    /// * `phase` - The phase to start.
+    /// * `end` - The time of the clock when the phase ends.
+    fn start_delay_phase(&mut self, phase: DelayPhase, end: Duration) {
+        self.delay_phase = Some((phase, end));
+        self.events.emit(PHASE_START_EMIT, phase);
+    }
+
+    /// Ends the delay phase running once its time has come.
+    ///
+    /// Returns true while a delay phase is running or the game has ended.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time of the clock.
+    async fn delay_checks(&mut self, now: Duration) -> bool {
+        let Some((phase, end)) = self.delay_phase else {
+            return false;
+        };
+        if now < end {
+            return true;
+        }
+        self.delay_phase = None;
+        self.events.emit(PHASE_END_EMIT, phase);
+        match phase {
+            DelayPhase::LineClear => {
+                self.local_board.clear_pending_lines();
+                self.state_emit().await;
+                self.entry_delay(end).await;
+            }
+            DelayPhase::Entry => self.spawn_piece().await,
+        }
+        self.delay_phase.is_some() || !self.run
+    }
+
+    /// Spawns the next piece once the delays are over.
+    async fn spawn_piece(&mut self) {
+        self.local_board.spawn_piece();
+        let elapsed = self.elapsed();
+        if let Some(master) = &mut self.master {
+            master.piece_spawned(elapsed);
+            self.events.emit(LINE_CLEARED_INFO_EMIT, self.lines_info());
+        }
+        // The gravity of the new piece starts counting when it spawns
+        let now = self.now();
+        self.next_gravity = now + self.row_interval().unwrap_or_default();
+        self.auto_shift.cut(now);
+        self.initial_actions();
+        self.queue_emit();
+        self.state_emit().await;
+        self.game_end_checks().await;
+    }
+
+    /// Refills the garbage of a cheese race up to its height, without adding more lines than
+    /// the ones left to reach the goal.
+    fn refill_garbage(&mut self) {
+        let GameMode::Cheese {
+            goal,
+            height,
+            messiness,
+        } = self.mode
+        else {
+            return;
+        };
+        let garbage_lines = self.local_board.garbage_lines();
+        let left = goal.saturating_sub(self.local_board.garbage_cleared() + garbage_lines);
+        let missing = (height as u32).saturating_sub(garbage_lines).min(left);
+        if missing > 0 {
+            self.local_board.add_cheese(missing as u8, messiness);
+        }
+    }
+
+    /// Gets the win condition for the game, decided by its mode and the end of a classic game.
+    fn get_win_condition(&self) -> impl Fn(bool, u32) -> bool {
+        let progress = self.mode_progress();
+        let mode = self.mode;
+        let marathon = self.options.marathon();
+        move |game_over, lines_cleared| {
+            let progress = ModeProgress {
+                lines_cleared,
+                ..progress
+            };
+            mode.won(game_over, &progress) || (!game_over && marathon.finished(&progress))
+        }
+    }
+
+    /// Returns how far the game has gone, as the modes measure it.
+    fn mode_progress(&self) -> ModeProgress {
+        ModeProgress {
+            lines_cleared: self.real_line_clears as u32,
+            garbage_cleared: self.local_board.garbage_cleared(),
+            level: self.level,
+            master: self.master,
+            elapsed: self.elapsed(),
+        }
+    }
+
+    /// Checks if the line has been cleared and performs the neccesary calculations
+    async fn check_line_cleared(&mut self) {
+        let pattern = self.local_board.clear_line_pattern();
+        let perfect_clear = self.local_board.perfect_clear();
+        self.update_chains(pattern);
+        if self.master.is_some() {
+            self.master_calculation(pattern, perfect_clear);
+        }
+        if pattern != ClearLinePattern::None {
+            if self.master.is_none() {
+                self.points_calculation(pattern, perfect_clear);
+            }
+            let lines_awarded = self.lines_awarded_calculation(pattern, perfect_clear).await;
+            if self.master.is_none() {
+                self.level_up_checks(lines_awarded, Self::pattern_lines(pattern));
+            }
+            if self.mode.shows_lines() {
+                self.line_emit(pattern);
+            } else {
+                self.points_emit();
+            }
+
+            if perfect_clear {
+                self.perfect_clear_emit(pattern);
             }
 
             if self.second_level_commands.is_none() {
                 self.game_info.line_cleared(pattern);
+                self.game_info
+                    .register_garbage_cleared(self.local_board.garbage_cleared());
+                if perfect_clear {
+                    self.game_info.perfect_cleared();
+                }
+                self.game_info
+                    .register_chains(self.back_to_back, self.combo);
             }
         }
-        self.prev_clear_line_pattern = pattern;
+    }
+
+    /// Updates the back-to-back and combo chains after a piece has been fixed.
+    ///
+    /// Any clear extends the combo, and a piece that doesn't clear lines breaks it.
+    /// Difficult clears extend the back-to-back chain, other clears break it, and placements
+    /// that don't clear lines keep it as it is.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern of the cleared lines.
+    fn update_chains(&mut self, pattern: ClearLinePattern) {
+        let prev_back_to_back = self.back_to_back;
+        let prev_combo = self.combo;
+        if Self::pattern_lines(pattern) == 0 {
+            self.combo = None;
+        } else {
+            self.combo = Some(self.combo.map_or(0, |combo| combo + 1));
+            self.back_to_back = if Self::is_difficult(pattern) {
+                Some(self.back_to_back.map_or(0, |back_to_back| back_to_back + 1))
+            } else {
+                None
+            };
+        }
+        if prev_back_to_back != self.back_to_back {
+            self.back_to_back_emit();
+        }
+        if prev_combo != self.combo {
+            self.combo_emit();
+        }
     }
 
     /// Calculates the points for the cleared lines.
-    fn points_calculation(&mut self, pattern: ClearLinePattern) {
-        self.points += match pattern {
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern of the cleared lines.
+    /// * `perfect_clear` - Whether the clear left the board empty.
+    fn points_calculation(&mut self, pattern: ClearLinePattern, perfect_clear: bool) {
+        let mut points = match pattern {
             ClearLinePattern::None => 0,
             ClearLinePattern::Single => 100,
             ClearLinePattern::Double => 300,
@@ -640,25 +1302,79 @@ impl LocalGame {
             ClearLinePattern::TSpinTriple => 1600,
             ClearLinePattern::MiniTSpin => 100,
             ClearLinePattern::MiniTSpinSingle => 200,
+            ClearLinePattern::Spin(_) => 100,
+            ClearLinePattern::SpinSingle(_) => 200,
+            ClearLinePattern::SpinDouble(_) => 400,
+            ClearLinePattern::SpinTriple(_) => 600,
         } * self.level as u32;
-        if pattern == self.prev_clear_line_pattern {
-            self.points += match pattern {
-                ClearLinePattern::None => 0,
-                ClearLinePattern::Single => 50,
-                ClearLinePattern::Double => 150,
-                ClearLinePattern::Triple => 250,
-                ClearLinePattern::Tetris => 400,
-                ClearLinePattern::TSpin => 200,
-                ClearLinePattern::TSpinSingle => 400,
-                ClearLinePattern::TSpinDouble => 600,
-                ClearLinePattern::TSpinTriple => 1600,
-                ClearLinePattern::MiniTSpin => 50,
-                ClearLinePattern::MiniTSpinSingle => 100,
+        if self.back_to_back_active(pattern) {
+            points += points / 2;
+        }
+        self.points += points;
+        if let Some(combo) = self.combo {
+            self.points += COMBO_POINTS * combo as u32 * self.level as u32;
+        }
+        if perfect_clear {
+            self.points += match Self::pattern_lines(pattern) {
+                1 => 800,
+                2 => 1200,
+                3 => 1800,
+                _ => 2000,
             } * self.level as u32;
         }
     }
+    /// Scores a fixed piece of a master game, advancing its level and grade, and follows the
+    /// lock delay of the section it reaches.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern of the cleared lines.
+    /// * `perfect_clear` - Whether the clear left the board empty.
+    fn master_calculation(&mut self, pattern: ClearLinePattern, perfect_clear: bool) {
+        let elapsed = self.elapsed();
+        let Some(master) = &mut self.master else {
+            return;
+        };
+        let previous_timings = MasterTimings::of_level(master.level());
+        let previous_grade = master.grade();
+        self.points += master.piece_fixed(Self::pattern_lines(pattern), perfect_clear, elapsed);
+        master.update_grade(self.points);
+        let timings = MasterTimings::of_level(master.level());
+        if timings != previous_timings {
+            self.lock_down.set_policy(timings.lock_delay());
+        }
+        if master.grade() != previous_grade {
+            self.grade_emit();
+        }
+    }
+    /// Counts the lines of a clear towards the goal of the level, following the goal system
+    /// of the game. Levels up each time the goal is reached, carrying over the lines left.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines_awarded` - The lines awarded by the clear.
+    /// * `lines_cleared` - The lines actually cleared.
+    fn level_up_checks(&mut self, lines_awarded: u16, lines_cleared: u16) {
+        let goal = self.options.marathon().goal();
+        self.line_clears += goal.counted(lines_awarded, lines_cleared);
+        while self.line_clears >= goal.lines(self.level) {
+            self.line_clears -= goal.lines(self.level);
+            self.level += 1;
+        }
+    }
     /// Calculates the lines awarded for the cleared lines.
-    async fn lines_awarded_calculation(&mut self, pattern: ClearLinePattern) {
+    ///
+    /// Returns the lines awarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern of the cleared lines.
+    /// * `perfect_clear` - Whether the clear left the board empty.
+    async fn lines_awarded_calculation(
+        &mut self,
+        pattern: ClearLinePattern,
+        perfect_clear: bool,
+    ) -> u16 {
         let mut lines_cleared = match pattern {
             ClearLinePattern::None => 0,
             ClearLinePattern::Single => 1,
@@ -671,26 +1387,29 @@ impl LocalGame {
             ClearLinePattern::TSpinTriple => 16,
             ClearLinePattern::MiniTSpin => 1,
             ClearLinePattern::MiniTSpinSingle => 2,
+            ClearLinePattern::Spin(_) => 1,
+            ClearLinePattern::SpinSingle(_) => 2,
+            ClearLinePattern::SpinDouble(_) => 4,
+            ClearLinePattern::SpinTriple(_) => 6,
         };
-        if pattern == self.prev_clear_line_pattern {
-            lines_cleared += match pattern {
-                ClearLinePattern::None => 0,
-                ClearLinePattern::Single => 1,
-                ClearLinePattern::Double => 2,
-                ClearLinePattern::Triple => 3,
-                ClearLinePattern::Tetris => 4,
-                ClearLinePattern::TSpin => 1,
-                ClearLinePattern::TSpinSingle => 4,
-                ClearLinePattern::TSpinDouble => 6,
-                ClearLinePattern::TSpinTriple => 16,
-                ClearLinePattern::MiniTSpin => 1,
-                ClearLinePattern::MiniTSpinSingle => 1,
-            };
+        if self.back_to_back_active(pattern) {
+            lines_cleared += lines_cleared / 2;
         }
-        self.line_clears = lines_cleared;
-        if self.responder.is_some() {
+        if let Some(combo) = self.combo {
+            lines_cleared +=
+                COMBO_LINES_AWARDED[(combo as usize).min(COMBO_LINES_AWARDED.len() - 1)];
+        }
+        if perfect_clear {
+            lines_cleared += PERFECT_CLEAR_LINES_AWARDED;
+        }
+        // Garbage rising in a single-player game is countered like the one received online, only
+        // the lines left over are sent back
+        if self.responder.is_some() || self.next_garbage.is_some() {
             let send_lines = self.local_board.counter_trash(lines_cleared as u8);
-            if send_lines > 0 {
+            if self.second_level_commands.is_none() {
+                self.game_info.lines_sent(send_lines as u32);
+            }
+            if send_lines > 0 && self.responder.is_some() {
                 let _ = self
                     .responder
                     .as_mut()
@@ -700,7 +1419,13 @@ impl LocalGame {
             }
         }
 
-        self.real_line_clears += match pattern {
+        self.real_line_clears += Self::pattern_lines(pattern);
+        lines_cleared
+    }
+
+    /// Returns the number of lines actually cleared by the pattern.
+    fn pattern_lines(pattern: ClearLinePattern) -> u16 {
+        match pattern {
             ClearLinePattern::None => 0,
             ClearLinePattern::Single => 1,
             ClearLinePattern::Double => 2,
@@ -712,32 +1437,66 @@ impl LocalGame {
             ClearLinePattern::TSpinTriple => 3,
             ClearLinePattern::MiniTSpin => 0,
             ClearLinePattern::MiniTSpinSingle => 1,
-        };
+            ClearLinePattern::Spin(_) => 0,
+            ClearLinePattern::SpinSingle(_) => 1,
+            ClearLinePattern::SpinDouble(_) => 2,
+            ClearLinePattern::SpinTriple(_) => 3,
+        }
+    }
+
+    /// Checks if the pattern is a difficult clear, the ones that build a back-to-back chain.
+    fn is_difficult(pattern: ClearLinePattern) -> bool {
+        match pattern {
+            ClearLinePattern::Tetris
+            | ClearLinePattern::TSpinSingle
+            | ClearLinePattern::TSpinDouble
+            | ClearLinePattern::TSpinTriple
+            | ClearLinePattern::MiniTSpinSingle
+            | ClearLinePattern::SpinSingle(_)
+            | ClearLinePattern::SpinDouble(_)
+            | ClearLinePattern::SpinTriple(_) => true,
+            ClearLinePattern::None
+            | ClearLinePattern::Single
+            | ClearLinePattern::Double
+            | ClearLinePattern::Triple
+            | ClearLinePattern::TSpin
+            | ClearLinePattern::MiniTSpin
+            | ClearLinePattern::Spin(_) => false,
+        }
+    }
+
+    /// Checks if the back-to-back bonus applies to the pattern.
+    fn back_to_back_active(&self, pattern: ClearLinePattern) -> bool {
+        Self::is_difficult(pattern)
+            && self
+                .back_to_back
+                .is_some_and(|back_to_back| back_to_back > 0)
     }
 
     /// Emits the held piece.
     fn emit_held_piece(&self) {
-        self.app
-            .emit(
-                HELD_PIECE_EMIT,
-                self.local_board
-                    .held_piece()
-                    .expect("Isn't called until there is a held piece"),
-            )
-            .unwrap();
+        self.events.emit(
+            HELD_PIECE_EMIT,
+            self.local_board
+                .held_piece()
+                .expect("Isn't called until there is a held piece"),
+        );
     }
 
     /// Emits the queue.
     fn queue_emit(&mut self) {
         let range: Range<u128> = self.local_board.piece_num() as u128 + 1
             ..self.local_board.piece_num() as u128 + NUMBER_OF_PIECES_IN_QUEUE_TO_EMIT + 1;
-        self.app
-            .emit(QUEUE_EMIT, self.local_board.get_pieces(range))
-            .unwrap();
+        self.events
+            .emit(QUEUE_EMIT, self.local_board.get_pieces(range));
     }
-    /// Emits the board state.
+    /// Emits the board state, an empty one while the game is paused.
     async fn state_emit(&mut self) {
-        let state = self.local_board.board_state();
+        let state = if self.paused_since.is_some() {
+            format!("{}{}", BUFFER_STATE_FOR_NUMBERS, BUFFER_STATE_FOR_NUMBERS)
+        } else {
+            self.visible_board_state()
+        };
         if self.responder.is_some() {
             let _ = self
                 .responder
@@ -746,46 +1505,95 @@ impl LocalGame {
                 .send(GameResponses::BoardState(state.clone()))
                 .await;
         }
-        self.app.emit(BOARD_STATE_EMIT, state).unwrap();
+        self.events.emit(BOARD_STATE_EMIT, state);
     }
     /// Emits the cleared line.
     fn line_emit(&self, pattern: ClearLinePattern) {
-        self.app.emit(LINE_CLEARED_EMIT, pattern).unwrap();
-        let payload = if self.lines_40 {
-            format!("{}/{}", self.real_line_clears, 40)
+        self.events.emit(LINE_CLEARED_EMIT, pattern);
+        self.events.emit(LINE_CLEARED_INFO_EMIT, self.lines_info());
+    }
+    /// Returns the lines cleared towards the goal of the game, as shown to the player.
+    fn lines_info(&self) -> String {
+        match self.mode.goal_progress(&self.mode_progress()) {
+            Some((lines, goal)) => format!("{}/{}", lines, goal),
+            None => format!(
+                "{}/{}",
+                self.line_clears,
+                self.options.marathon().goal().lines(self.level)
+            ),
+        }
+    }
+    /// Emits the progress of a game resumed from a save, the rest is emitted as it changes.
+    fn resumed_emit(&self) {
+        if self.local_board.held_piece().is_some() {
+            self.emit_held_piece();
+        }
+        if self.mode.shows_lines() {
+            self.events.emit(LINE_CLEARED_INFO_EMIT, self.lines_info());
         } else {
-            format!("{}/{}", self.line_clears, self.level * 5)
-        };
-        self.app.emit(LINE_CLEARED_INFO_EMIT, payload).unwrap();
+            self.points_emit();
+        }
+        if self.master.is_some() {
+            self.grade_emit();
+        }
+        self.back_to_back_emit();
+        self.combo_emit();
+        self.time_emit(self.elapsed());
+    }
+    /// Emits the perfect clear event.
+    fn perfect_clear_emit(&self, pattern: ClearLinePattern) {
+        self.events.emit(PERFECT_CLEAR_EMIT, pattern);
+    }
+    /// Emits the back-to-back chain.
+    fn back_to_back_emit(&self) {
+        self.events.emit(BACK_TO_BACK_EMIT, self.back_to_back);
+    }
+    /// Emits the combo chain.
+    fn combo_emit(&self) {
+        self.events.emit(COMBO_EMIT, self.combo);
     }
     /// Emits the piece fixed event.
     fn piece_fixed_emit(&self) {
-        self.app.emit(PIECE_FIXED_EMIT, self.last_piece).unwrap();
+        self.events.emit(PIECE_FIXED_EMIT, self.last_piece);
     }
     /// Emits the points.
     fn points_emit(&self) {
-        self.app.emit(POINTS_EMIT, self.points).unwrap();
+        self.events.emit(POINTS_EMIT, self.points);
+    }
+    /// Emits the grade of a master game.
+    fn grade_emit(&self) {
+        if let Some(master) = self.master {
+            self.events.emit(GRADE_EMIT, master.grade());
+        }
     }
     /// Emits the game won event.
     fn game_won_emit(&self) {
-        self.app.emit(GAME_WON_EMIT, true).unwrap();
+        self.events.emit(GAME_WON_EMIT, true);
     }
     /// Emits the game over event.
     fn game_over_emit(&self, forfeited: bool) {
-        self.app.emit(GAME_OVER_EMIT, forfeited).unwrap();
+        self.events.emit(GAME_OVER_EMIT, forfeited);
     }
-    /// Emits the time.
-    fn time_emit(&self, now_secs: u64) {
-        let total_secs = now_secs - self.start_time;
+    /// Emits the time, with milliseconds.
+    fn time_emit(&self, elapsed: Duration) {
+        let total_secs = elapsed.as_secs();
+        let millis = elapsed.subsec_millis();
         let seconds = total_secs % 60;
         let minutes = (total_secs / 60) % 60;
         let hours = total_secs / 3600;
-        self.app
-            .emit(
-                TIME_EMIT,
-                format!("{:02}:{:02}:{:02}", hours, minutes, seconds),
-            )
-            .unwrap();
+        self.events.emit(
+            TIME_EMIT,
+            format!("{:02}:{:02}:{:02}.{:03}", hours, minutes, seconds, millis),
+        );
+    }
+    /// Registers the replay of the game.
+    async fn register_replay(&mut self) {
+        let replay = std::mem::replace(&mut self.replay, Replay::new(self.options.clone()));
+        let name = self.start_time.to_string();
+
+        tokio::spawn(async move {
+            replays::store_replay(replay, name).await;
+        });
     }
     /// Registers the game info.
     async fn register_info(&mut self) {
@@ -796,6 +1604,15 @@ impl LocalGame {
         });
     }
 }
+/// `DelayPhase` represents the phases between a piece being fixed and the next one spawning.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum DelayPhase {
+    /// The completed lines stay on the board before being cleared.
+    LineClear,
+    /// The wait before the next piece spawns (ARE).
+    Entry,
+}
+
 #[derive(Debug)]
 /// `GameControl` represents the control commands for the game.
 pub enum GameControl {
@@ -803,4 +1620,786 @@ pub enum GameControl {
     Retry,
     /// Forfeits the game.
     Forfeit,
+    /// Pauses the game, only single-player games can be paused.
+    Pause,
+    /// Resumes the paused game.
+    Resume,
+    /// Saves the game and ends it, only single-player games can be saved.
+    Save,
+}
+
+/// `GameSnapshot` represents the state of a single-player game at a given moment, so it can
+/// be stored and resumed later.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GameSnapshot {
+    /// The version of the snapshot format.
+    version: u32,
+    /// The options the game was created with, including the seed of the queue.
+    options: GameOptions,
+    /// The state of the board.
+    board: BoardSnapshot,
+    /// The milliseconds played.
+    elapsed_millis: u64,
+    /// The player's score.
+    points: u32,
+    /// The game level.
+    level: u16,
+    /// The number of lines cleared in the level.
+    line_clears: u16,
+    /// The total number of lines cleared in the game.
+    real_line_clears: u16,
+    /// The level, section times and grade of a master game.
+    #[serde(default)]
+    master: Option<MasterProgress>,
+    /// The back-to-back chain.
+    back_to_back: Option<u16>,
+    /// The combo chain.
+    combo: Option<u16>,
+    /// The game information gathered so far.
+    game_info: GameInfo,
+    /// The record of the inputs received so far.
+    replay: Replay,
+    /// The number and the milliseconds played of the locks whose cells are still to be shown
+    /// differently.
+    lock_times: Vec<(usize, u64)>,
+}
+
+impl GameSnapshot {
+    /// Returns the version of the snapshot format.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+    /// Returns the options the game was created with.
+    pub fn options(&self) -> &GameOptions {
+        &self.options
+    }
+    /// Returns the milliseconds played.
+    pub fn elapsed_millis(&self) -> u64 {
+        self.elapsed_millis
+    }
+    /// Returns the player's score.
+    pub fn points(&self) -> u32 {
+        self.points
+    }
+    /// Returns the game level.
+    pub fn level(&self) -> u16 {
+        self.level
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{future::Future, pin::Pin, sync::Arc, time::Duration};
+
+    use serde_json::Value;
+    use tokio::{sync::mpsc, task::JoinHandle};
+
+    use tokio::sync::mpsc::{Receiver, Sender, UnboundedReceiver};
+
+    use crate::{
+        events::channel_sink::ChannelSink,
+        game::{
+            board::Board,
+            clock::{Clock, ManualClock, Stepper, SystemClock},
+            game_mode::{DEFAULT_CHEESE_HEIGHT, GameMode},
+            gravity_curve::GravityCurve,
+            handling::Handling,
+            lock_delay::LockDelay,
+            marathon::{LevelGoal, Marathon, MarathonEnd},
+            queue::{local_queue::LocalQueue, randomizer::RandomizerType},
+            visibility::Visibility,
+        },
+        init_trace::initialize,
+        models::{
+            game_commands::{FirstLevelCommands, HeldKey},
+            game_info::GameTypeInfo,
+            game_options::GameOptions,
+            replay::Replay,
+        },
+    };
+
+    use super::{
+        BOARD_STATE_EMIT, ClearLinePattern, FRAME_DURATION, GAME_OVER_EMIT, GAME_SAVED_EMIT,
+        GAME_WON_EMIT, GameControl, GameSnapshot, HELD_PIECE_EMIT, LINE_CLEARED_INFO_EMIT,
+        LocalGame, PHASE_END_EMIT, PHASE_START_EMIT, PIECE_FIXED_EMIT, TIME_EMIT,
+    };
+
+    /// Frames needed for a second to pass, 8.333 ms each.
+    const FRAMES_IN_A_SECOND: usize = 121;
+    /// Frames needed for the extended lock time to pass after an input, as the game time moves
+    /// in whole milliseconds.
+    const FRAMES_IN_LOCK_TIME: usize = 60;
+    /// Soft drops that take any piece from the spawn to the floor of an empty board.
+    const SOFT_DROPS_TO_FLOOR: usize = 24;
+
+    /// Spawns a game driven by a manual clock and steps it through the countdown.
+    async fn stepped_game(
+        options: GameOptions,
+    ) -> (
+        Stepper,
+        Sender<FirstLevelCommands>,
+        Sender<GameControl>,
+        UnboundedReceiver<(String, Value)>,
+    ) {
+        stepped_game_with(options, |_| ()).await
+    }
+
+    /// Spawns a game like `stepped_game`, letting it be prepared before it starts.
+    async fn stepped_game_with(
+        options: GameOptions,
+        prepare: impl FnOnce(&mut LocalGame),
+    ) -> (
+        Stepper,
+        Sender<FirstLevelCommands>,
+        Sender<GameControl>,
+        UnboundedReceiver<(String, Value)>,
+    ) {
+        initialize();
+        let (sink, events) = ChannelSink::new();
+        let (tx, rx) = mpsc::channel(32);
+        let (control_tx, control_rx): (_, Receiver<GameControl>) = mpsc::channel(32);
+        let (clock, mut stepper) = ManualClock::new();
+        let mut game = LocalGame::new(
+            options,
+            Arc::new(sink),
+            rx,
+            None,
+            control_rx,
+            None,
+            LocalQueue::new(1, RandomizerType::SevenBag),
+        );
+        game.set_clock(Box::new(clock));
+        prepare(&mut game);
+        tokio::spawn(async move {
+            game.start_game().await;
+        });
+        // The three numbers of the countdown
+        assert!(stepper.step_many(3).await);
+        (stepper, tx, control_tx, events)
+    }
+
+    /// `FrameClock` is a manual clock that moves the same time on every frame, so a game can run
+    /// at another frame rate than the one of the game loop.
+    #[derive(Debug)]
+    struct FrameClock {
+        /// The clock driven by the stepper.
+        clock: ManualClock,
+        /// The time moved on every frame.
+        frame: Duration,
+    }
+
+    impl Clock for FrameClock {
+        fn now(&self) -> Duration {
+            self.clock.now()
+        }
+
+        fn sleep(&mut self, _duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + '_>> {
+            self.clock.sleep(self.frame)
+        }
+    }
+
+    /// Spawns a game whose frames last the given time, playing the given replay if any, and
+    /// steps it through the countdown. The game is given back once it ends.
+    async fn framed_game(
+        options: GameOptions,
+        replay: Option<Replay>,
+        frame: Duration,
+    ) -> (
+        Stepper,
+        Sender<FirstLevelCommands>,
+        Sender<GameControl>,
+        JoinHandle<LocalGame>,
+    ) {
+        initialize();
+        let (sink, _events) = ChannelSink::new();
+        let (tx, rx) = mpsc::channel(32);
+        let (control_tx, control_rx) = mpsc::channel(32);
+        let (clock, mut stepper) = ManualClock::new();
+        let mut game = LocalGame::new(
+            options,
+            Arc::new(sink),
+            rx,
+            None,
+            control_rx,
+            None,
+            LocalQueue::new(1, RandomizerType::SevenBag),
+        );
+        game.set_clock(Box::new(FrameClock { clock, frame }));
+        if let Some(replay) = replay {
+            game.play_replay(replay);
+        }
+        let handle = tokio::spawn(async move {
+            game.start_game().await;
+            // Dropping the manual clock lets the stepper know that the game has finished
+            game.set_clock(Box::new(SystemClock::default()));
+            game
+        });
+        assert!(stepper.step_many(3).await);
+        (stepper, tx, control_tx, handle)
+    }
+
+    /// Counts the events with the given name received so far.
+    fn count_events(events: &mut UnboundedReceiver<(String, Value)>, name: &str) -> usize {
+        let mut count = 0;
+        while let Ok((event, _)) = events.try_recv() {
+            if event == name {
+                count += 1;
+            }
+        }
+        count
+    }
+    /// Returns the last board state emitted, if any.
+    fn last_state(events: &mut UnboundedReceiver<(String, Value)>) -> Option<String> {
+        let mut state = None;
+        while let Ok((event, payload)) = events.try_recv() {
+            if event == BOARD_STATE_EMIT {
+                state = payload.as_str().map(str::to_string);
+            }
+        }
+        state
+    }
+    /// Returns true if the current piece is on the leftmost column of the state.
+    fn piece_on_left_wall(state: &str) -> bool {
+        state
+            .chars()
+            .step_by(10)
+            .any(|cell| cell != 'E' && cell != 'G')
+    }
+
+    #[tokio::test]
+    async fn piece_falls_a_row_each_second_on_first_level() {
+        let (mut stepper, _tx, _control_tx, mut events) =
+            stepped_game(GameOptions::default()).await;
+        count_events(&mut events, BOARD_STATE_EMIT);
+        assert!(stepper.step_many(FRAMES_IN_A_SECOND - 1).await);
+        assert_eq!(count_events(&mut events, BOARD_STATE_EMIT), 0);
+        assert!(stepper.step().await);
+        assert_eq!(count_events(&mut events, BOARD_STATE_EMIT), 1);
+        assert!(stepper.step_many(FRAMES_IN_A_SECOND).await);
+        assert_eq!(count_events(&mut events, BOARD_STATE_EMIT), 1);
+    }
+
+    #[tokio::test]
+    async fn piece_locks_after_lock_delay_on_the_floor() {
+        let (mut stepper, tx, _control_tx, mut events) = stepped_game(GameOptions::default()).await;
+        for _ in 0..SOFT_DROPS_TO_FLOOR {
+            tx.send(FirstLevelCommands::SoftDrop).await.unwrap();
+        }
+        assert!(stepper.step().await);
+        assert!(stepper.step_many(FRAMES_IN_LOCK_TIME - 1).await);
+        assert_eq!(count_events(&mut events, PIECE_FIXED_EMIT), 0);
+        assert!(stepper.step().await);
+        assert_eq!(count_events(&mut events, PIECE_FIXED_EMIT), 1);
+    }
+
+    #[tokio::test]
+    async fn twenty_g_drops_piece_to_floor_on_spawn() {
+        let mut options = GameOptions::default();
+        options.set_gravity(GravityCurve::Fixed(20.0));
+        let (mut stepper, _tx, _control_tx, mut events) = stepped_game(options).await;
+        // Without any input only lock delay is left before the piece gets fixed
+        assert!(stepper.step_many(FRAMES_IN_LOCK_TIME + 1).await);
+        assert_eq!(count_events(&mut events, PIECE_FIXED_EMIT), 1);
+    }
+
+    #[tokio::test]
+    async fn instant_lock_fixes_piece_on_touch() {
+        let mut options = GameOptions::default();
+        options.set_gravity(GravityCurve::Fixed(20.0));
+        options.set_lock_delay(LockDelay::Instant);
+        let (mut stepper, _tx, _control_tx, mut events) = stepped_game(options).await;
+        assert!(stepper.step_many(2).await);
+        assert!(count_events(&mut events, PIECE_FIXED_EMIT) >= 1);
+    }
+
+    #[tokio::test]
+    async fn infinite_lock_never_fixes_resting_piece() {
+        let mut options = GameOptions::default();
+        options.set_gravity(GravityCurve::Fixed(20.0));
+        options.set_lock_delay(LockDelay::Infinite);
+        let (mut stepper, _tx, _control_tx, mut events) = stepped_game(options).await;
+        assert!(stepper.step_many(FRAMES_IN_A_SECOND * 10).await);
+        assert_eq!(count_events(&mut events, PIECE_FIXED_EMIT), 0);
+    }
+
+    #[tokio::test]
+    async fn entry_delay_runs_between_pieces() {
+        let mut options = GameOptions::default();
+        options.set_entry_delay_millis(100);
+        let (mut stepper, tx, _control_tx, mut events) = stepped_game(options).await;
+        tx.send(FirstLevelCommands::HardDrop).await.unwrap();
+        assert!(stepper.step().await);
+        assert_eq!(count_events(&mut events, PHASE_START_EMIT), 1);
+        // 100 ms are 12 frames
+        assert!(stepper.step_many(11).await);
+        assert_eq!(count_events(&mut events, PHASE_END_EMIT), 0);
+        assert!(stepper.step_many(3).await);
+        assert_eq!(count_events(&mut events, PHASE_END_EMIT), 1);
+    }
+
+    #[tokio::test]
+    async fn input_is_ignored_during_entry_delay() {
+        let mut options = GameOptions::default();
+        options.set_entry_delay_millis(1000);
+        let (mut stepper, tx, _control_tx, mut events) = stepped_game(options).await;
+        tx.send(FirstLevelCommands::HardDrop).await.unwrap();
+        assert!(stepper.step().await);
+        tx.send(FirstLevelCommands::HardDrop).await.unwrap();
+        assert!(stepper.step().await);
+        assert_eq!(count_events(&mut events, PIECE_FIXED_EMIT), 1);
+    }
+
+    #[tokio::test]
+    async fn zero_arr_shifts_to_the_wall_after_das() {
+        let mut options = GameOptions::default();
+        options.set_handling(Handling::new(100, 0, 0, 20));
+        let (mut stepper, tx, _control_tx, mut events) = stepped_game(options).await;
+        tx.send(FirstLevelCommands::KeyPressed(HeldKey::Left))
+            .await
+            .unwrap();
+        assert!(stepper.step().await);
+        assert!(!piece_on_left_wall(&last_state(&mut events).unwrap()));
+        // 100 ms are 12 frames
+        assert!(stepper.step_many(13).await);
+        assert!(piece_on_left_wall(&last_state(&mut events).unwrap()));
+    }
+
+    #[tokio::test]
+    async fn soft_drop_factor_speeds_up_gravity() {
+        let (mut stepper, tx, _control_tx, mut events) = stepped_game(GameOptions::default()).await;
+        tx.send(FirstLevelCommands::KeyPressed(HeldKey::SoftDrop))
+            .await
+            .unwrap();
+        // At 20 rows per second the piece reaches the floor and locks in two seconds
+        assert!(stepper.step_many(FRAMES_IN_A_SECOND * 2).await);
+        assert_eq!(count_events(&mut events, PIECE_FIXED_EMIT), 1);
+    }
+
+    /// Holds the first piece with the hold key and keeps it held while the next piece spawns.
+    async fn hold_key_held_on_spawn(options: GameOptions) -> usize {
+        let (mut stepper, tx, _control_tx, mut events) = stepped_game(options).await;
+        tx.send(FirstLevelCommands::KeyPressed(HeldKey::SavePiece))
+            .await
+            .unwrap();
+        tx.send(FirstLevelCommands::HardDrop).await.unwrap();
+        assert!(stepper.step().await);
+        count_events(&mut events, HELD_PIECE_EMIT)
+    }
+
+    #[tokio::test]
+    async fn initial_hold_swaps_spawning_piece() {
+        let mut options = GameOptions::default();
+        options.set_initial_actions(true);
+        assert_eq!(hold_key_held_on_spawn(options).await, 2);
+    }
+
+    #[tokio::test]
+    async fn initial_hold_is_disabled_by_default() {
+        assert_eq!(hold_key_held_on_spawn(GameOptions::default()).await, 1);
+    }
+
+    #[tokio::test]
+    async fn pause_freezes_lock_delay_and_hides_board() {
+        let (mut stepper, tx, control_tx, mut events) = stepped_game(GameOptions::default()).await;
+        assert!(stepper.step().await);
+        let spawned = last_state(&mut events).unwrap();
+        // Soft drop doesn't move the piece while paused
+        control_tx.send(GameControl::Pause).await.unwrap();
+        assert!(stepper.step().await);
+        for _ in 0..3 {
+            tx.send(FirstLevelCommands::KeyPressed(HeldKey::SoftDrop))
+                .await
+                .unwrap();
+            tx.send(FirstLevelCommands::KeyReleased(HeldKey::SoftDrop))
+                .await
+                .unwrap();
+            assert!(stepper.step().await);
+        }
+        control_tx.send(GameControl::Resume).await.unwrap();
+        assert!(stepper.step().await);
+        assert_eq!(last_state(&mut events).unwrap(), spawned);
+        for _ in 0..SOFT_DROPS_TO_FLOOR {
+            tx.send(FirstLevelCommands::SoftDrop).await.unwrap();
+        }
+        assert!(stepper.step().await);
+        control_tx.send(GameControl::Pause).await.unwrap();
+        assert!(stepper.step().await);
+        let state = last_state(&mut events).unwrap();
+        assert!(state.chars().all(|cell| cell == 'E'));
+        assert!(stepper.step_many(FRAMES_IN_LOCK_TIME * 2).await);
+        assert_eq!(count_events(&mut events, PIECE_FIXED_EMIT), 0);
+        control_tx.send(GameControl::Resume).await.unwrap();
+        assert!(stepper.step_many(FRAMES_IN_LOCK_TIME).await);
+        assert_eq!(count_events(&mut events, PIECE_FIXED_EMIT), 1);
+    }
+
+    #[tokio::test]
+    async fn replay_reproduces_moves_made_just_before_the_lock() {
+        let mut options = GameOptions::default();
+        options.set_gravity(GravityCurve::Fixed(0.0));
+        options.set_seed(1);
+        let (mut stepper, tx, control_tx, handle) =
+            framed_game(options.clone(), None, FRAME_DURATION).await;
+        for _ in 0..SOFT_DROPS_TO_FLOOR {
+            tx.send(FirstLevelCommands::SoftDrop).await.unwrap();
+        }
+        assert!(stepper.step().await);
+        // Each move restarts the lock delay on the last frame before the piece locks
+        for _ in 0..3 {
+            assert!(stepper.step_many(FRAMES_IN_LOCK_TIME - 2).await);
+            tx.send(FirstLevelCommands::KeyPressed(HeldKey::Left))
+                .await
+                .unwrap();
+            tx.send(FirstLevelCommands::KeyReleased(HeldKey::Left))
+                .await
+                .unwrap();
+            assert!(stepper.step().await);
+        }
+        tx.send(FirstLevelCommands::HardDrop).await.unwrap();
+        assert!(stepper.step().await);
+        // A saved game keeps its replay
+        control_tx.send(GameControl::Save).await.unwrap();
+        assert!(!stepper.step_many(2).await);
+        let recorded = handle.await.unwrap();
+        assert_eq!(recorded.local_board.locks(), 1);
+
+        // Played a second at a time, the frames fall far from the ones of the recording
+        let (mut stepper, _tx, control_tx, handle) = framed_game(
+            options,
+            Some(recorded.replay.clone()),
+            Duration::from_secs(1),
+        )
+        .await;
+        assert!(stepper.step_many(3).await);
+        control_tx.send(GameControl::Forfeit).await.unwrap();
+        assert!(!stepper.step_many(2).await);
+        let played = handle.await.unwrap();
+        assert_eq!(
+            played.local_board.snapshot(),
+            recorded.local_board.snapshot()
+        );
+        assert_eq!(played.points, recorded.points);
+    }
+
+    #[tokio::test]
+    async fn forfeit_ends_stepped_game() {
+        let (mut stepper, _tx, control_tx, mut events) = stepped_game(GameOptions::default()).await;
+        control_tx.send(GameControl::Forfeit).await.unwrap();
+        assert!(!stepper.step_many(2).await);
+        assert_eq!(count_events(&mut events, GAME_OVER_EMIT), 1);
+    }
+
+    #[tokio::test]
+    async fn save_ends_stepped_game() {
+        let (mut stepper, _tx, control_tx, mut events) = stepped_game(GameOptions::default()).await;
+        control_tx.send(GameControl::Save).await.unwrap();
+        assert!(!stepper.step_many(2).await);
+        assert_eq!(count_events(&mut events, GAME_SAVED_EMIT), 1);
+    }
+
+    #[tokio::test]
+    async fn resumed_game_continues_where_it_was_saved() {
+        initialize();
+        let (sink, _events) = ChannelSink::new();
+        let (_tx, rx) = mpsc::channel(32);
+        let (_control_tx, control_rx) = mpsc::channel(32);
+        let mut game = LocalGame::new(
+            GameOptions::default(),
+            Arc::new(sink),
+            rx,
+            None,
+            control_rx,
+            None,
+            LocalQueue::new(1, RandomizerType::SevenBag),
+        );
+        game.local_board.hard_drop();
+        game.level = 3;
+        game.line_clears = 4;
+        game.elapsed_offset = Duration::from_secs(65);
+        let content = serde_json::to_string(&game.snapshot()).unwrap();
+        let snapshot: GameSnapshot = serde_json::from_str(&content).unwrap();
+        let (mut stepper, _tx, _control_tx, mut events) =
+            stepped_game_with(GameOptions::default(), |game| game.restore(snapshot)).await;
+        assert!(stepper.step().await);
+        let mut lines_info = None;
+        let mut time = None;
+        let mut state = None;
+        while let Ok((event, payload)) = events.try_recv() {
+            match event.as_str() {
+                LINE_CLEARED_INFO_EMIT => lines_info = Some(payload),
+                TIME_EMIT => time = time.or(Some(payload)),
+                BOARD_STATE_EMIT => state = payload.as_str().map(str::to_string),
+                _ => (),
+            }
+        }
+        assert_eq!(lines_info, Some(Value::from("4/15")));
+        assert_eq!(time, Some(Value::from("00:01:05.000")));
+        // The piece fixed before saving is still on the floor
+        let floor = &state.unwrap()[390..];
+        assert!(floor.chars().any(|cell| cell != 'E' && cell != 'G'));
+    }
+
+    #[tokio::test]
+    async fn blitz_ends_at_its_time_limit_without_locking() {
+        let mut options = GameOptions::default();
+        options.set_mode(GameMode::Blitz { duration_secs: 1 });
+        let (mut stepper, _tx, _control_tx, mut events) = stepped_game(options).await;
+        assert!(stepper.step_many(FRAMES_IN_A_SECOND - 5).await);
+        assert_eq!(count_events(&mut events, GAME_WON_EMIT), 0);
+        // The game finishes on the first frame past the limit
+        assert!(!stepper.step_many(10).await);
+        let mut won = 0;
+        let mut time = None;
+        while let Ok((event, payload)) = events.try_recv() {
+            match event.as_str() {
+                GAME_WON_EMIT => won += 1,
+                TIME_EMIT => time = payload.as_str().map(str::to_string),
+                _ => (),
+            }
+        }
+        assert_eq!(won, 1);
+        assert_eq!(time.as_deref(), Some("00:00:01.000"));
+    }
+
+    #[tokio::test]
+    async fn survival_garbage_rises_on_its_schedule() {
+        let mut options = GameOptions::default();
+        options.set_mode(GameMode::Survival);
+        let (mut stepper, tx, _control_tx, mut events) = stepped_game(options).await;
+        tx.send(FirstLevelCommands::HardDrop).await.unwrap();
+        assert!(stepper.step().await);
+        let state = last_state(&mut events).unwrap();
+        assert!(!state.contains('R'));
+        // The first garbage line rises after four seconds, set when the next piece spawns
+        assert!(stepper.step_many(FRAMES_IN_A_SECOND * 4).await);
+        tx.send(FirstLevelCommands::HardDrop).await.unwrap();
+        assert!(stepper.step().await);
+        let state = last_state(&mut events).unwrap();
+        assert_eq!(state.matches('R').count(), 9);
+    }
+
+    #[tokio::test]
+    async fn survival_only_sends_back_the_lines_left_after_countering() {
+        initialize();
+        let mut options = GameOptions::default();
+        options.set_mode(GameMode::Survival);
+        let (sink, _events) = ChannelSink::new();
+        let (_tx, rx) = mpsc::channel(32);
+        let (_control_tx, control_rx) = mpsc::channel(32);
+        let mut game = LocalGame::new(
+            options,
+            Arc::new(sink),
+            rx,
+            None,
+            control_rx,
+            None,
+            LocalQueue::new(1, RandomizerType::SevenBag),
+        );
+        game.next_garbage = Some(Duration::ZERO);
+        game.local_board.insert_trash(3);
+        game.lines_awarded_calculation(ClearLinePattern::Triple, false)
+            .await;
+        let GameTypeInfo::Survival(info) = game.game_info.type_of_info() else {
+            panic!("A survival game keeps survival information");
+        };
+        assert_eq!(info.lines_sent(), 2);
+        assert_eq!(game.local_board.num_of_trash_lines(), 0);
+    }
+
+    #[tokio::test]
+    async fn master_level_advances_with_each_piece_after_its_entry_delay() {
+        let mut options = GameOptions::default();
+        options.set_mode(GameMode::Master);
+        let (mut stepper, tx, _control_tx, mut events) = stepped_game(options).await;
+        tx.send(FirstLevelCommands::HardDrop).await.unwrap();
+        assert!(stepper.step().await);
+        assert_eq!(count_events(&mut events, LINE_CLEARED_INFO_EMIT), 0);
+        // The entry delay of the first section is 417 ms
+        assert!(stepper.step_many(FRAMES_IN_A_SECOND / 2).await);
+        let mut lines_info = None;
+        while let Ok((event, payload)) = events.try_recv() {
+            if event == LINE_CLEARED_INFO_EMIT {
+                lines_info = Some(payload);
+            }
+        }
+        assert_eq!(lines_info, Some(Value::from("1/100")));
+    }
+
+    fn marathon(marathon: Marathon) -> LocalGame {
+        let mut options = GameOptions::default();
+        options.set_marathon(marathon);
+        let (sink, _events) = ChannelSink::new();
+        let (_tx, rx) = mpsc::channel(32);
+        let (_control_tx, control_rx) = mpsc::channel(32);
+        LocalGame::new(
+            options,
+            Arc::new(sink),
+            rx,
+            None,
+            control_rx,
+            None,
+            LocalQueue::new(1, RandomizerType::SevenBag),
+        )
+    }
+
+    #[test]
+    fn marathon_starts_at_its_level_and_carries_the_lines_over() {
+        let mut game = marathon(Marathon::new(5, LevelGoal::Fixed, MarathonEnd::Endless));
+        assert_eq!(game.level, 5);
+        game.level_up_checks(8, 4);
+        assert_eq!((game.level, game.line_clears), (5, 4));
+        game.level_up_checks(8, 4);
+        game.level_up_checks(8, 4);
+        assert_eq!((game.level, game.line_clears), (6, 2));
+        assert_eq!(game.lines_info(), "2/10");
+    }
+
+    #[test]
+    fn variable_goal_counts_the_lines_awarded() {
+        let mut game = marathon(Marathon::default());
+        game.level_up_checks(8, 4);
+        assert_eq!((game.level, game.line_clears), (2, 3));
+        assert_eq!(game.lines_info(), "3/10");
+    }
+
+    #[test]
+    fn marathon_is_won_at_its_end() {
+        let mut game = marathon(Marathon::new(1, LevelGoal::Variable, MarathonEnd::Lines150));
+        game.level = 15;
+        assert!(!game.local_board.game_won(game.get_win_condition()));
+        game.level = 16;
+        assert!(game.local_board.game_won(game.get_win_condition()));
+        let mut endless = marathon(Marathon::default());
+        endless.level = 100;
+        assert!(!endless.local_board.game_won(endless.get_win_condition()));
+    }
+
+    /// Creates a cheese race that hasn't started.
+    fn cheese_race(goal: u32, height: u8, big: bool) -> LocalGame {
+        let mut options = GameOptions::default();
+        options.set_mode(GameMode::Cheese {
+            goal,
+            height,
+            messiness: 50,
+        });
+        options.set_big(big);
+        options.set_seed(4);
+        let (sink, _events) = ChannelSink::new();
+        let (_tx, rx) = mpsc::channel(32);
+        let (_control_tx, control_rx) = mpsc::channel(32);
+        LocalGame::new(
+            options,
+            Arc::new(sink),
+            rx,
+            None,
+            control_rx,
+            None,
+            LocalQueue::new(4, RandomizerType::SevenBag),
+        )
+    }
+
+    #[test]
+    fn cheese_race_starts_with_its_garbage() {
+        let game = cheese_race(18, 8, false);
+        assert_eq!(game.local_board.garbage_lines(), 8);
+        assert_eq!(game.lines_info(), "0/18");
+        // Never more garbage than the lines left to reach the goal
+        let game = cheese_race(5, 8, false);
+        assert_eq!(game.local_board.garbage_lines(), 5);
+    }
+
+    #[test]
+    fn cheese_race_garbage_is_the_same_with_the_same_seed() {
+        let first = cheese_race(18, 8, false);
+        let second = cheese_race(18, 8, false);
+        assert_eq!(
+            first.local_board.board_state(),
+            second.local_board.board_state()
+        );
+    }
+
+    #[test]
+    fn big_cheese_race_garbage_fits_the_big_field() {
+        let game = cheese_race(18, DEFAULT_CHEESE_HEIGHT, true);
+        // The top row of the field of ten is left free
+        assert_eq!(game.local_board.garbage_lines(), 9);
+    }
+
+    #[test]
+    fn big_is_only_played_alone() {
+        let mut options = GameOptions::default();
+        options.set_big(true);
+        assert!(options.big());
+        options.multi_player(2);
+        assert!(!options.big());
+    }
+
+    /// Returns the bottom row of the main board in the state.
+    fn bottom_row(state: &str) -> &str {
+        &state[390..400]
+    }
+
+    #[tokio::test]
+    async fn invisible_cells_are_hidden_after_the_delay() {
+        let mut options = GameOptions::default();
+        options.set_visibility(Visibility::Invisible { delay_millis: 500 });
+        let (mut stepper, tx, _control_tx, mut events) = stepped_game(options).await;
+        tx.send(FirstLevelCommands::HardDrop).await.unwrap();
+        assert!(stepper.step().await);
+        let state = last_state(&mut events).unwrap();
+        assert!(bottom_row(&state).chars().any(|cell| cell != 'E'));
+        assert!(!state.contains('G'));
+        assert!(stepper.step_many(FRAMES_IN_A_SECOND / 2 + 1).await);
+        let state = last_state(&mut events).unwrap();
+        assert_eq!(bottom_row(&state), "EEEEEEEEEE");
+    }
+
+    #[tokio::test]
+    async fn monochrome_cells_share_a_colour() {
+        let mut options = GameOptions::default();
+        options.set_visibility(Visibility::Monochrome);
+        let (mut stepper, tx, _control_tx, mut events) = stepped_game(options).await;
+        tx.send(FirstLevelCommands::HardDrop).await.unwrap();
+        assert!(stepper.step().await);
+        let state = last_state(&mut events).unwrap();
+        assert!(
+            bottom_row(&state)
+                .chars()
+                .all(|cell| cell == 'E' || cell == 'M')
+        );
+        assert!(bottom_row(&state).contains('M'));
+        assert!(state.contains('G'));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn runs_headless_with_channel_sink() {
+        initialize();
+        let (sink, mut events) = ChannelSink::new();
+        let (tx, rx) = mpsc::channel(32);
+        let (control_tx, control_rx) = mpsc::channel(32);
+        let mut game = LocalGame::new(
+            GameOptions::default(),
+            Arc::new(sink),
+            rx,
+            None,
+            control_rx,
+            None,
+            LocalQueue::new(1, RandomizerType::SevenBag),
+        );
+        let handle = tokio::spawn(async move {
+            game.start_game().await;
+        });
+        tokio::time::sleep(Duration::from_secs(4)).await;
+        tx.send(FirstLevelCommands::HardDrop).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        control_tx.send(GameControl::Forfeit).await.unwrap();
+        handle.await.unwrap();
+
+        let mut emitted = vec![];
+        while let Ok(event) = events.try_recv() {
+            emitted.push(event);
+        }
+        assert!(emitted.iter().any(|(name, _)| name == PIECE_FIXED_EMIT));
+        assert!(emitted.contains(&(GAME_OVER_EMIT.to_string(), Value::Bool(true))));
+    }
 }
\ No newline at end of file
@@ -7,7 +7,6 @@ use std::{
 // use futures::{StreamExt, stream};
 use futures_util::StreamExt;
 use rand::{Rng, seq::IteratorRandom};
-use tauri::{AppHandle, Emitter};
 use tokio::sync::{
     Mutex,
     mpsc::{self, Receiver, Sender},
@@ -16,6 +15,7 @@ use tokio_stream::{self as stream};
 
 use crate::{
     commands::game_commands::{FIRST_LEVEL_CHANNEL, GAME_CONTROL_CHANNEL, SECOND_LEVEL_CHANNEL},
+    events::EventSink,
     game::{pieces::Piece, strategy::Strategy},
     globals::SIZE_FOR_KB,
     models::{
@@ -71,8 +71,8 @@ pub struct OnlineGame {
     waiting_for_payback_lines: HashMap<DummyPlayer, u32>,
     /// Tracks the danger levels of all players in the game.
     danger_levels: DangerTracker,
-    /// Tauri application handle for emitting events.
-    app: AppHandle,
+    /// Sink for emitting events.
+    events: Arc<dyn EventSink>,
     /// A set of players who have lost the game.
     players_lost: HashSet<DummyPlayer>,
     /// A map of players to the number of even lines they have.
@@ -87,14 +87,14 @@ impl OnlineGame {
     ///
     /// * `players` - The list of players in the game.
     /// * `playing` - An atomic boolean indicating whether the game is currently being played.
-    /// * `app` - Tauri application handle for emitting events.
+    /// * `events` - Sink for emitting events.
     /// * `delay` - A delay in milliseconds before the game starts.
     /// * `queue` - The queue implementation to use.
     /// * `local_player` - The local player's information.
     pub async fn new(
         players: Vec<Player>,
         playing: Arc<Mutex<bool>>,
-        app: AppHandle,
+        events: Arc<dyn EventSink>,
         delay: u64,
         queue: LocalQueue,
         local_player: DummyPlayer,
@@ -108,7 +108,7 @@ impl OnlineGame {
         Self::set_channels(tx_commands, tx_commands_second.clone(), tx_control).await;
         let mut local_game = LocalGame::new(
             options,
-            app.clone(),
+            events.clone(),
             rx_commands,
             Some(rx_commands_second),
             rx_control,
@@ -155,7 +155,7 @@ impl OnlineGame {
             self_player_strategy: Strategy::Random,
             waiting_for_payback_lines: HashMap::new(),
             danger_levels: DangerTracker::new(dummys),
-            app,
+            events,
             players_lost: HashSet::new(),
             even_lines,
             self_lost: false,
@@ -200,9 +200,7 @@ impl OnlineGame {
     }
     /// Starts the online game.
     pub async fn start(&mut self) {
-        self.app
-            .emit(GAME_STARTED_EMIT, self.self_player.id())
-            .unwrap();
+        self.events.emit(GAME_STARTED_EMIT, self.self_player.id());
         let mut value = self.playing.lock().await;
         *value = true;
         drop(value);
@@ -617,7 +615,7 @@ impl OnlineGame {
     /// * `dummy_player` - The player who's state is being emitted.
     /// * `state` - The state to emit.
     fn other_player_state_emit(&self, dummy_player: DummyPlayer, state: String) {
-        let _ = self.app.emit(
+        self.events.emit(
             STATE_EMIT_OTHER_PLAYERS,
             OtherPlayerState {
                 player: dummy_player,
@@ -631,7 +629,7 @@ impl OnlineGame {
     ///
     /// * `dummy_player` - The player who lost.
     fn other_player_lost(&self, dummy_player: DummyPlayer) {
-        let _ = self.app.emit(OTHER_PLAYER_LOST, dummy_player);
+        self.events.emit(OTHER_PLAYER_LOST, dummy_player);
     }
     /// Checks if the game is over.
     ///
@@ -654,7 +652,7 @@ impl OnlineGame {
     ///
     /// * `dummy_player` - The player who won.
     fn other_player_won(&self, dummy_player: DummyPlayer) {
-        let _ = self.app.emit(
+        self.events.emit(
             OTHER_PLAYER_WON,
             WonSignal {
                 player: dummy_player,
@@ -701,7 +699,7 @@ impl OnlineGame {
                 }
             })
             .await;
-        let _ = self.app.emit(
+        self.events.emit(
             OTHER_PLAYER_WON,
             WonSignal {
                 player: winner,
@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// The frame gravity is measured with. A gravity of 1G makes the piece fall a row every frame.
+const GRAVITY_FRAME: Duration = Duration::from_nanos(16_666_667);
+/// Gravity from which the piece falls to the floor as soon as it spawns.
+const INSTANT_GRAVITY: f64 = 20.0;
+/// Last level of the guideline curve, the ones after it keep its gravity.
+const GUIDELINE_MAX_LEVEL: u16 = 20;
+
+/// `GravityCurve` represents how fast the pieces fall in each level.
+///
+/// Gravity is measured in G, the rows a piece falls every 1/60 of a second.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub enum GravityCurve {
+    /// Guideline curve, a row every `(0.8 - (level - 1) * 0.007)^(level - 1)` seconds, up to
+    /// level 20.
+    #[default]
+    Guideline,
+    /// The gravity of each level, starting from the first one. The levels after the table
+    /// keep the last gravity.
+    Table(Vec<f64>),
+    /// The same gravity for every level.
+    Fixed(f64),
+}
+
+impl GravityCurve {
+    /// Returns the gravity of the given level, in G.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The level of the game.
+    pub fn gravity(&self, level: u16) -> f64 {
+        match self {
+            GravityCurve::Guideline => {
+                let level = level.clamp(1, GUIDELINE_MAX_LEVEL) as f64;
+                let seconds_per_row = (0.8 - (level - 1.0) * 0.007).powf(level - 1.0);
+                GRAVITY_FRAME.as_secs_f64() / seconds_per_row
+            }
+            GravityCurve::Table(table) => {
+                let index = (level.max(1) as usize - 1).min(table.len().saturating_sub(1));
+                table.get(index).copied().unwrap_or_default()
+            }
+            GravityCurve::Fixed(gravity) => *gravity,
+        }
+    }
+
+    /// Returns true if the piece falls to the floor as soon as it spawns in the given level.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The level of the game.
+    pub fn is_instant(&self, level: u16) -> bool {
+        Self::instant(self.gravity(level))
+    }
+
+    /// Returns the time the piece takes to fall a row in the given level, `None` if it doesn't fall.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The level of the game.
+    pub fn row_interval(&self, level: u16) -> Option<Duration> {
+        Self::interval(self.gravity(level))
+    }
+
+    /// Returns true if the given gravity makes the piece fall to the floor as soon as it spawns.
+    ///
+    /// # Arguments
+    ///
+    /// * `gravity` - The gravity, in G.
+    pub fn instant(gravity: f64) -> bool {
+        gravity >= INSTANT_GRAVITY
+    }
+
+    /// Returns the time the piece takes to fall a row with the given gravity, `None` if it
+    /// doesn't fall or falls too slowly for the time to fit in a `Duration`.
+    ///
+    /// # Arguments
+    ///
+    /// * `gravity` - The gravity, in G.
+    pub fn interval(gravity: f64) -> Option<Duration> {
+        if gravity <= 0.0 {
+            return None;
+        }
+        Duration::try_from_secs_f64(GRAVITY_FRAME.as_secs_f64() / gravity).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::GravityCurve;
+
+    #[test]
+    fn guideline_first_level_falls_a_row_per_second() {
+        let interval = GravityCurve::Guideline.row_interval(1).unwrap();
+        assert!(interval.abs_diff(Duration::from_secs(1)) < Duration::from_micros(1));
+        assert!(!GravityCurve::Guideline.is_instant(1));
+    }
+
+    #[test]
+    fn guideline_reaches_instant_gravity() {
+        assert!(GravityCurve::Guideline.is_instant(20));
+    }
+
+    #[test]
+    fn guideline_keeps_its_last_gravity_at_high_levels() {
+        let last = GravityCurve::Guideline.gravity(20);
+        assert!(last.is_finite());
+        assert_eq!(GravityCurve::Guideline.gravity(116), last);
+        assert_eq!(GravityCurve::Guideline.gravity(u16::MAX), last);
+        assert!(GravityCurve::Guideline.row_interval(200).is_some());
+    }
+
+    #[test]
+    fn table_keeps_last_gravity_after_its_end() {
+        let curve = GravityCurve::Table(vec![0.5, 1.0, 2.0]);
+        assert_eq!(curve.gravity(1), 0.5);
+        assert_eq!(curve.gravity(3), 2.0);
+        assert_eq!(curve.gravity(15), 2.0);
+    }
+
+    #[test]
+    fn fixed_gravity_ignores_level() {
+        let curve = GravityCurve::Fixed(1.0);
+        assert_eq!(curve.row_interval(1), curve.row_interval(30));
+        assert!(GravityCurve::Fixed(20.0).is_instant(1));
+    }
+
+    #[test]
+    fn no_gravity_never_falls() {
+        assert_eq!(GravityCurve::Fixed(0.0).row_interval(1), None);
+        assert_eq!(GravityCurve::Table(vec![]).row_interval(1), None);
+    }
+
+    #[test]
+    fn tiny_gravity_never_falls() {
+        assert_eq!(GravityCurve::Fixed(f64::MIN_POSITIVE).row_interval(1), None);
+        assert_eq!(GravityCurve::interval(1e-300), None);
+    }
+}
@@ -0,0 +1,315 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::game_commands::HeldKey;
+
+/// `Handling` represents how the held keys repeat their action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Handling {
+    /// Milliseconds a direction has to be held before it starts repeating (DAS).
+    das_millis: u64,
+    /// Milliseconds between the repeated shifts, with 0 the piece shifts to the wall (ARR).
+    arr_millis: u64,
+    /// Milliseconds the repetition waits after a rotation or a new piece (DCD).
+    dcd_millis: u64,
+    /// How many times faster than gravity the piece falls while soft drop is held.
+    soft_drop_factor: u32,
+}
+
+impl Default for Handling {
+    fn default() -> Self {
+        Handling {
+            das_millis: 167,
+            arr_millis: 33,
+            dcd_millis: 0,
+            soft_drop_factor: 20,
+        }
+    }
+}
+
+impl Handling {
+    /// Creates a new `Handling` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `das_millis` - Milliseconds a direction has to be held before it starts repeating.
+    /// * `arr_millis` - Milliseconds between the repeated shifts.
+    /// * `dcd_millis` - Milliseconds the repetition waits after a rotation or a new piece.
+    /// * `soft_drop_factor` - How many times faster than gravity soft drop is.
+    pub fn new(das_millis: u64, arr_millis: u64, dcd_millis: u64, soft_drop_factor: u32) -> Self {
+        Handling {
+            das_millis,
+            arr_millis,
+            dcd_millis,
+            soft_drop_factor,
+        }
+    }
+
+    /// Returns how many times faster than gravity the piece falls while soft drop is held.
+    pub fn soft_drop_factor(&self) -> u32 {
+        self.soft_drop_factor
+    }
+}
+
+/// `AutoShift` keeps the state of the held keys and repeats their action.
+#[derive(Debug)]
+pub struct AutoShift {
+    /// The handling followed.
+    handling: Handling,
+    /// Whether the left key is held.
+    left_held: bool,
+    /// Whether the right key is held.
+    right_held: bool,
+    /// Whether the soft drop key is held.
+    soft_drop_held: bool,
+    /// The last rotation key pressed that is still held.
+    rotation_held: Option<HeldKey>,
+    /// Whether the hold key is held.
+    hold_held: bool,
+    /// The direction being repeated, with the time of the clock of its next shift.
+    shifting: Option<(HeldKey, Duration)>,
+}
+
+impl AutoShift {
+    /// Creates a new `AutoShift` without any key held.
+    ///
+    /// # Arguments
+    ///
+    /// * `handling` - The handling to follow.
+    pub fn new(handling: Handling) -> Self {
+        AutoShift {
+            handling,
+            left_held: false,
+            right_held: false,
+            soft_drop_held: false,
+            rotation_held: None,
+            hold_held: false,
+            shifting: None,
+        }
+    }
+
+    /// Registers a key being pressed. The last direction pressed is the one repeated.
+    ///
+    /// Returns true if the key is a direction, so the piece has to shift at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key pressed.
+    /// * `now` - The current time of the clock.
+    pub fn press(&mut self, key: HeldKey, now: Duration) -> bool {
+        match key {
+            HeldKey::Left => self.left_held = true,
+            HeldKey::Right => self.right_held = true,
+            HeldKey::SoftDrop => {
+                self.soft_drop_held = true;
+                return false;
+            }
+            HeldKey::ClockWiseRotation
+            | HeldKey::CounterClockWiseRotation
+            | HeldKey::FullRotation => {
+                self.rotation_held = Some(key);
+                return false;
+            }
+            HeldKey::SavePiece => {
+                self.hold_held = true;
+                return false;
+            }
+        }
+        self.shifting = Some((key, now + self.das()));
+        true
+    }
+
+    /// Registers a key being released. If the other direction is still held it starts
+    /// charging again.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key released.
+    /// * `now` - The current time of the clock.
+    pub fn release(&mut self, key: HeldKey, now: Duration) {
+        let other_held = match key {
+            HeldKey::Left => {
+                self.left_held = false;
+                self.right_held.then_some(HeldKey::Right)
+            }
+            HeldKey::Right => {
+                self.right_held = false;
+                self.left_held.then_some(HeldKey::Left)
+            }
+            HeldKey::SoftDrop => {
+                self.soft_drop_held = false;
+                return;
+            }
+            HeldKey::ClockWiseRotation
+            | HeldKey::CounterClockWiseRotation
+            | HeldKey::FullRotation => {
+                if self.rotation_held == Some(key) {
+                    self.rotation_held = None;
+                }
+                return;
+            }
+            HeldKey::SavePiece => {
+                self.hold_held = false;
+                return;
+            }
+        };
+        if self.shifting.is_some_and(|(shifting, _)| shifting == key) {
+            self.shifting = other_held.map(|other| (other, now + self.das()));
+        }
+    }
+
+    /// Returns the direction the piece has to shift at the given time and how many columns.
+    /// With an ARR of 0 the piece shifts to the wall, `u32::MAX` columns.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time of the clock.
+    pub fn shifts(&mut self, now: Duration) -> Option<(HeldKey, u32)> {
+        let arr = Duration::from_millis(self.handling.arr_millis);
+        let (key, next) = self.shifting.as_mut()?;
+        if now < *next {
+            return None;
+        }
+        if arr.is_zero() {
+            *next = now;
+            return Some((*key, u32::MAX));
+        }
+        let mut columns: u32 = 0;
+        while now >= *next {
+            columns = columns.saturating_add(1);
+            *next += arr;
+        }
+        Some((*key, columns))
+    }
+
+    /// Delays the repetition after a rotation or a new piece (DCD). It also drops the shifts
+    /// that were due, so a new piece doesn't shift the columns the previous one couldn't.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time of the clock.
+    pub fn cut(&mut self, now: Duration) {
+        let dcd = Duration::from_millis(self.handling.dcd_millis);
+        if let Some((_, next)) = self.shifting.as_mut() {
+            *next = (*next).max(now + dcd);
+        }
+    }
+
+    /// Returns true if the soft drop key is held.
+    pub fn soft_dropping(&self) -> bool {
+        self.soft_drop_held
+    }
+
+    /// Returns the last rotation key pressed that is still held.
+    pub fn rotation_held(&self) -> Option<HeldKey> {
+        self.rotation_held
+    }
+
+    /// Returns true if the hold key is held.
+    pub fn hold_held(&self) -> bool {
+        self.hold_held
+    }
+
+    /// Returns the time a direction has to be held before it starts repeating.
+    fn das(&self) -> Duration {
+        Duration::from_millis(self.handling.das_millis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::models::game_commands::HeldKey;
+
+    use super::{AutoShift, Handling};
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn repeats_after_das_every_arr() {
+        let mut auto_shift = AutoShift::new(Handling::new(100, 20, 0, 20));
+        assert!(auto_shift.press(HeldKey::Left, millis(0)));
+        assert_eq!(auto_shift.shifts(millis(99)), None);
+        assert_eq!(auto_shift.shifts(millis(100)), Some((HeldKey::Left, 1)));
+        assert_eq!(auto_shift.shifts(millis(110)), None);
+        assert_eq!(auto_shift.shifts(millis(160)), Some((HeldKey::Left, 3)));
+    }
+
+    #[test]
+    fn zero_arr_shifts_to_the_wall() {
+        let mut auto_shift = AutoShift::new(Handling::new(100, 0, 0, 20));
+        auto_shift.press(HeldKey::Right, millis(0));
+        assert_eq!(
+            auto_shift.shifts(millis(100)),
+            Some((HeldKey::Right, u32::MAX))
+        );
+        assert_eq!(
+            auto_shift.shifts(millis(108)),
+            Some((HeldKey::Right, u32::MAX))
+        );
+    }
+
+    #[test]
+    fn last_direction_pressed_wins() {
+        let mut auto_shift = AutoShift::new(Handling::new(100, 20, 0, 20));
+        auto_shift.press(HeldKey::Left, millis(0));
+        auto_shift.press(HeldKey::Right, millis(50));
+        assert_eq!(auto_shift.shifts(millis(100)), None);
+        assert_eq!(auto_shift.shifts(millis(150)), Some((HeldKey::Right, 1)));
+    }
+
+    #[test]
+    fn releasing_goes_back_to_the_other_direction() {
+        let mut auto_shift = AutoShift::new(Handling::new(100, 20, 0, 20));
+        auto_shift.press(HeldKey::Left, millis(0));
+        auto_shift.press(HeldKey::Right, millis(50));
+        auto_shift.release(HeldKey::Right, millis(200));
+        assert_eq!(auto_shift.shifts(millis(299)), None);
+        assert_eq!(auto_shift.shifts(millis(300)), Some((HeldKey::Left, 1)));
+        auto_shift.release(HeldKey::Left, millis(310));
+        assert_eq!(auto_shift.shifts(millis(1000)), None);
+    }
+
+    #[test]
+    fn cut_delays_the_repetition() {
+        let mut auto_shift = AutoShift::new(Handling::new(100, 20, 50, 20));
+        auto_shift.press(HeldKey::Left, millis(0));
+        assert_eq!(auto_shift.shifts(millis(100)), Some((HeldKey::Left, 1)));
+        auto_shift.cut(millis(110));
+        assert_eq!(auto_shift.shifts(millis(159)), None);
+        assert_eq!(auto_shift.shifts(millis(160)), Some((HeldKey::Left, 1)));
+    }
+
+    #[test]
+    fn tracks_rotation_and_hold_keys() {
+        let mut auto_shift = AutoShift::new(Handling::default());
+        assert!(!auto_shift.press(HeldKey::ClockWiseRotation, millis(0)));
+        assert!(!auto_shift.press(HeldKey::CounterClockWiseRotation, millis(10)));
+        assert!(!auto_shift.press(HeldKey::SavePiece, millis(10)));
+        auto_shift.release(HeldKey::ClockWiseRotation, millis(20));
+        assert_eq!(
+            auto_shift.rotation_held(),
+            Some(HeldKey::CounterClockWiseRotation)
+        );
+        assert!(auto_shift.hold_held());
+        auto_shift.release(HeldKey::CounterClockWiseRotation, millis(30));
+        auto_shift.release(HeldKey::SavePiece, millis(30));
+        assert_eq!(auto_shift.rotation_held(), None);
+        assert!(!auto_shift.hold_held());
+        assert_eq!(auto_shift.shifts(millis(1000)), None);
+    }
+
+    #[test]
+    fn soft_drop_is_held_until_released() {
+        let mut auto_shift = AutoShift::new(Handling::default());
+        assert!(!auto_shift.press(HeldKey::SoftDrop, millis(0)));
+        assert!(auto_shift.soft_dropping());
+        assert_eq!(auto_shift.shifts(millis(1000)), None);
+        auto_shift.release(HeldKey::SoftDrop, millis(1000));
+        assert!(!auto_shift.soft_dropping());
+    }
+}
@@ -0,0 +1,244 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// `LockDelay` represents when a piece resting on the floor gets fixed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LockDelay {
+    /// Guideline extended placement. Every movement or rotation on the floor restarts the
+    /// countdown, up to a limit of resets that is restored when the piece reaches a lower row.
+    ExtendedPlacement {
+        /// Milliseconds the piece can rest on the floor.
+        delay_millis: u64,
+        /// Movements and rotations that restart the countdown.
+        resets: u8,
+    },
+    /// The countdown only restarts when the piece reaches a lower row.
+    StepReset {
+        /// Milliseconds the piece can rest on the floor.
+        delay_millis: u64,
+    },
+    /// The piece gets fixed as soon as it touches the floor.
+    Instant,
+    /// The piece is only fixed with a hard drop.
+    Infinite,
+}
+
+impl Default for LockDelay {
+    fn default() -> Self {
+        LockDelay::ExtendedPlacement {
+            delay_millis: 500,
+            resets: 15,
+        }
+    }
+}
+
+/// `LockDown` keeps the lock delay state of the current piece.
+#[derive(Debug)]
+pub struct LockDown {
+    /// The lock delay followed.
+    policy: LockDelay,
+    /// The time the piece gets fixed at, if it's resting on the floor.
+    deadline: Option<Duration>,
+    /// The movements and rotations left that restart the countdown.
+    resets_left: u8,
+    /// The lowest y-coordinate the piece has reached.
+    lowest_y: i16,
+}
+
+impl LockDown {
+    /// Creates a new `LockDown` for the first piece.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The lock delay to follow.
+    pub fn new(policy: LockDelay) -> Self {
+        let mut lock_down = LockDown {
+            policy,
+            deadline: None,
+            resets_left: 0,
+            lowest_y: i16::MIN,
+        };
+        lock_down.new_piece();
+        lock_down
+    }
+
+    /// Changes the lock delay followed from now on.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - The lock delay to follow.
+    pub fn set_policy(&mut self, policy: LockDelay) {
+        self.policy = policy;
+    }
+
+    /// Starts again for a new piece.
+    pub fn new_piece(&mut self) {
+        self.deadline = None;
+        self.lowest_y = i16::MIN;
+        self.restore_resets();
+    }
+
+    /// Updates the state with the current position of the piece.
+    ///
+    /// Returns true if the piece has to be fixed.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time of the clock.
+    /// * `y` - The y-coordinate of the piece.
+    /// * `on_floor` - Whether the piece can't move down.
+    pub fn update(&mut self, now: Duration, y: i16, on_floor: bool) -> bool {
+        if y > self.lowest_y {
+            self.lowest_y = y;
+            self.deadline = None;
+            self.restore_resets();
+        }
+        if !on_floor {
+            self.deadline = None;
+            return false;
+        }
+        match self.policy {
+            LockDelay::ExtendedPlacement { delay_millis, .. } => {
+                self.resets_left == 0 || self.countdown(now, delay_millis)
+            }
+            LockDelay::StepReset { delay_millis } => self.countdown(now, delay_millis),
+            LockDelay::Instant => true,
+            LockDelay::Infinite => false,
+        }
+    }
+
+    /// Registers a movement or rotation of the piece.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time of the clock.
+    pub fn piece_moved(&mut self, now: Duration) {
+        let LockDelay::ExtendedPlacement { delay_millis, .. } = self.policy else {
+            return;
+        };
+        if self.deadline.is_some() {
+            self.resets_left = self.resets_left.saturating_sub(1);
+            self.deadline = Some(now + Duration::from_millis(delay_millis));
+        }
+    }
+
+    /// Starts the countdown if it wasn't running and checks if it has finished.
+    ///
+    /// # Arguments
+    ///
+    /// * `now` - The current time of the clock.
+    /// * `delay_millis` - Milliseconds the piece can rest on the floor.
+    fn countdown(&mut self, now: Duration, delay_millis: u64) -> bool {
+        let deadline = *self
+            .deadline
+            .get_or_insert(now + Duration::from_millis(delay_millis));
+        now >= deadline
+    }
+
+    /// Restores the movements and rotations that restart the countdown.
+    fn restore_resets(&mut self) {
+        if let LockDelay::ExtendedPlacement { resets, .. } = self.policy {
+            self.resets_left = resets;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{LockDelay, LockDown};
+
+    const DELAY_MILLIS: u64 = 500;
+
+    fn millis(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn extended_placement_locks_after_delay() {
+        let mut lock_down = LockDown::new(LockDelay::default());
+        assert!(!lock_down.update(millis(0), 18, true));
+        assert!(!lock_down.update(millis(499), 18, true));
+        assert!(lock_down.update(millis(500), 18, true));
+    }
+
+    #[test]
+    fn extended_placement_movements_restart_countdown() {
+        let mut lock_down = LockDown::new(LockDelay::default());
+        assert!(!lock_down.update(millis(0), 18, true));
+        lock_down.piece_moved(millis(400));
+        assert!(!lock_down.update(millis(800), 18, true));
+        assert!(lock_down.update(millis(900), 18, true));
+    }
+
+    #[test]
+    fn extended_placement_locks_when_resets_run_out() {
+        let mut lock_down = LockDown::new(LockDelay::ExtendedPlacement {
+            delay_millis: DELAY_MILLIS,
+            resets: 3,
+        });
+        assert!(!lock_down.update(millis(0), 18, true));
+        for time in 1..=3 {
+            lock_down.piece_moved(millis(time));
+        }
+        assert!(lock_down.update(millis(4), 18, true));
+    }
+
+    #[test]
+    fn extended_placement_restores_resets_on_lower_row() {
+        let mut lock_down = LockDown::new(LockDelay::ExtendedPlacement {
+            delay_millis: DELAY_MILLIS,
+            resets: 1,
+        });
+        assert!(!lock_down.update(millis(0), 10, true));
+        lock_down.piece_moved(millis(1));
+        assert!(!lock_down.update(millis(2), 11, false));
+        assert!(!lock_down.update(millis(3), 12, true));
+    }
+
+    #[test]
+    fn step_reset_ignores_movements() {
+        let mut lock_down = LockDown::new(LockDelay::StepReset {
+            delay_millis: DELAY_MILLIS,
+        });
+        assert!(!lock_down.update(millis(0), 18, true));
+        lock_down.piece_moved(millis(400));
+        assert!(lock_down.update(millis(500), 18, true));
+    }
+
+    #[test]
+    fn step_reset_restarts_on_lower_row() {
+        let mut lock_down = LockDown::new(LockDelay::StepReset {
+            delay_millis: DELAY_MILLIS,
+        });
+        assert!(!lock_down.update(millis(0), 17, true));
+        assert!(!lock_down.update(millis(400), 18, true));
+        assert!(!lock_down.update(millis(899), 18, true));
+        assert!(lock_down.update(millis(900), 18, true));
+    }
+
+    #[test]
+    fn instant_locks_on_touch() {
+        let mut lock_down = LockDown::new(LockDelay::Instant);
+        assert!(!lock_down.update(millis(0), 17, false));
+        assert!(lock_down.update(millis(0), 18, true));
+    }
+
+    #[test]
+    fn infinite_never_locks() {
+        let mut lock_down = LockDown::new(LockDelay::Infinite);
+        assert!(!lock_down.update(millis(0), 18, true));
+        assert!(!lock_down.update(millis(3_600_000), 18, true));
+    }
+
+    #[test]
+    fn countdown_stops_off_the_floor() {
+        let mut lock_down = LockDown::new(LockDelay::default());
+        assert!(!lock_down.update(millis(0), 18, true));
+        assert!(!lock_down.update(millis(400), 18, false));
+        assert!(!lock_down.update(millis(600), 18, true));
+        assert!(lock_down.update(millis(1100), 18, true));
+    }
+}
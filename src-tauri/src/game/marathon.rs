@@ -0,0 +1,206 @@
+use serde::{Deserialize, Serialize};
+
+use super::game_mode::ModeProgress;
+
+/// Lines to clear for each level with the fixed goal system.
+const FIXED_GOAL_LINES: u16 = 10;
+/// Lines awarded for each level number with the variable goal system.
+const VARIABLE_GOAL_LINES_PER_LEVEL: u16 = 5;
+/// Last level to complete to win a 150 lines game.
+const LINES_150_LAST_LEVEL: u16 = 15;
+
+/// `LevelGoal` represents how the lines needed to level up are counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum LevelGoal {
+    /// Each level needs five times its number of lines, counting the lines awarded by the
+    /// clears, so difficult clears, chains and perfect clears level up faster.
+    #[default]
+    Variable,
+    /// Each level needs ten lines, counting the lines actually cleared.
+    Fixed,
+}
+
+impl LevelGoal {
+    /// Returns the lines needed to level up from the given level.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The level of the game.
+    pub fn lines(&self, level: u16) -> u16 {
+        match self {
+            LevelGoal::Variable => level.max(1) * VARIABLE_GOAL_LINES_PER_LEVEL,
+            LevelGoal::Fixed => FIXED_GOAL_LINES,
+        }
+    }
+
+    /// Returns the lines a clear counts towards the goal of the level.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines_awarded` - The lines awarded by the clear.
+    /// * `lines_cleared` - The lines actually cleared.
+    pub fn counted(&self, lines_awarded: u16, lines_cleared: u16) -> u16 {
+        match self {
+            LevelGoal::Variable => lines_awarded,
+            LevelGoal::Fixed => lines_cleared,
+        }
+    }
+}
+
+/// `MarathonEnd` represents when a classic game is won.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MarathonEnd {
+    /// The game goes on until the player tops out.
+    #[default]
+    Endless,
+    /// The game is won once 150 lines have been cleared or level 15 has been completed.
+    Lines150,
+    /// The game is won once 200 lines have been cleared.
+    Lines200,
+}
+
+impl MarathonEnd {
+    /// Returns the last level to complete to win the game, if the game is won with a level.
+    pub fn last_level(&self) -> Option<u16> {
+        match self {
+            MarathonEnd::Lines150 => Some(LINES_150_LAST_LEVEL),
+            MarathonEnd::Endless | MarathonEnd::Lines200 => None,
+        }
+    }
+}
+
+/// `Marathon` represents the options of a classic game: the level it starts at, how the
+/// levels are gone up and when it ends.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Marathon {
+    /// The level the game starts at.
+    starting_level: u16,
+    /// How the lines needed to level up are counted.
+    goal: LevelGoal,
+    /// When the game is won.
+    end: MarathonEnd,
+}
+
+impl Default for Marathon {
+    fn default() -> Self {
+        Marathon {
+            starting_level: 1,
+            goal: LevelGoal::default(),
+            end: MarathonEnd::default(),
+        }
+    }
+}
+
+impl Marathon {
+    /// Creates a new `Marathon` instance.
+    ///
+    /// # Arguments
+    ///
+    /// * `starting_level` - The level the game starts at, at least the first one and at most
+    ///   the last level of the end.
+    /// * `goal` - How the lines needed to level up are counted.
+    /// * `end` - When the game is won.
+    pub fn new(starting_level: u16, goal: LevelGoal, end: MarathonEnd) -> Self {
+        let mut marathon = Marathon {
+            starting_level,
+            goal,
+            end,
+        };
+        marathon.starting_level = marathon.starting_level();
+        marathon
+    }
+    /// Returns the level the game starts at, kept below the last level of the end so the game
+    /// isn't won on the first lock.
+    pub fn starting_level(&self) -> u16 {
+        let starting_level = self.starting_level.max(1);
+        match self.end.last_level() {
+            Some(last_level) => starting_level.min(last_level),
+            None => starting_level,
+        }
+    }
+    /// Returns how the lines needed to level up are counted.
+    pub fn goal(&self) -> LevelGoal {
+        self.goal
+    }
+    /// Returns when the game is won.
+    pub fn end(&self) -> MarathonEnd {
+        self.end
+    }
+
+    /// Checks if the game has reached its end.
+    ///
+    /// # Arguments
+    ///
+    /// * `progress` - How far the game has gone.
+    pub fn finished(&self, progress: &ModeProgress) -> bool {
+        match self.end {
+            MarathonEnd::Endless => false,
+            MarathonEnd::Lines150 => {
+                progress.lines_cleared >= 150 || progress.level > LINES_150_LAST_LEVEL
+            }
+            MarathonEnd::Lines200 => progress.lines_cleared >= 200,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::game::game_mode::ModeProgress;
+
+    use super::{LevelGoal, Marathon, MarathonEnd};
+
+    fn progress(lines_cleared: u32, level: u16) -> ModeProgress {
+        ModeProgress {
+            lines_cleared,
+            level,
+            ..ModeProgress::default()
+        }
+    }
+
+    #[test]
+    fn variable_goal_grows_with_the_level() {
+        assert_eq!(LevelGoal::Variable.lines(1), 5);
+        assert_eq!(LevelGoal::Variable.lines(4), 20);
+        assert_eq!(LevelGoal::Variable.counted(8, 4), 8);
+    }
+
+    #[test]
+    fn fixed_goal_counts_the_lines_cleared() {
+        assert_eq!(LevelGoal::Fixed.lines(1), 10);
+        assert_eq!(LevelGoal::Fixed.lines(12), 10);
+        assert_eq!(LevelGoal::Fixed.counted(8, 4), 4);
+    }
+
+    #[test]
+    fn endless_never_finishes() {
+        let marathon = Marathon::default();
+        assert_eq!(marathon.starting_level(), 1);
+        assert!(!marathon.finished(&progress(10000, 100)));
+    }
+
+    #[test]
+    fn lines_150_finishes_with_the_lines_or_the_level() {
+        let marathon = Marathon::new(1, LevelGoal::Variable, MarathonEnd::Lines150);
+        assert!(!marathon.finished(&progress(149, 15)));
+        assert!(marathon.finished(&progress(150, 12)));
+        assert!(marathon.finished(&progress(120, 16)));
+    }
+
+    #[test]
+    fn lines_150_starts_at_its_last_level_at_most() {
+        let marathon = Marathon::new(20, LevelGoal::Fixed, MarathonEnd::Lines150);
+        assert_eq!(marathon.starting_level(), 15);
+        assert!(!marathon.finished(&progress(4, marathon.starting_level())));
+        let endless = Marathon::new(20, LevelGoal::Fixed, MarathonEnd::Endless);
+        assert_eq!(endless.starting_level(), 20);
+    }
+
+    #[test]
+    fn lines_200_finishes_with_the_lines() {
+        let marathon = Marathon::new(5, LevelGoal::Fixed, MarathonEnd::Lines200);
+        assert_eq!(marathon.starting_level(), 5);
+        assert!(!marathon.finished(&progress(199, 30)));
+        assert!(marathon.finished(&progress(200, 25)));
+    }
+}
@@ -0,0 +1,442 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use super::lock_delay::LockDelay;
+
+/// Level at which a master game is won.
+pub const MASTER_MAX_LEVEL: u16 = 999;
+/// Levels in each section of a master game.
+const SECTION_LEVELS: u16 = 100;
+/// Number of sections of a master game.
+pub const MASTER_SECTIONS: usize = 10;
+
+/// Gravity of the levels of a master game, as the level it starts at and the gravity in
+/// 1/256 G.
+const GRAVITY_TABLE: [(u16, u32); 30] = [
+    (0, 4),
+    (30, 6),
+    (35, 8),
+    (40, 10),
+    (50, 12),
+    (60, 16),
+    (70, 32),
+    (80, 48),
+    (90, 64),
+    (100, 80),
+    (120, 96),
+    (140, 112),
+    (160, 128),
+    (170, 144),
+    (200, 4),
+    (220, 32),
+    (230, 64),
+    (233, 96),
+    (236, 128),
+    (239, 160),
+    (243, 192),
+    (247, 224),
+    (251, 256),
+    (300, 512),
+    (330, 768),
+    (360, 1024),
+    (400, 1280),
+    (420, 1024),
+    (450, 768),
+    (500, 5120),
+];
+
+/// Timings of each section of a master game, as the milliseconds of entry delay, line clear
+/// delay and lock delay.
+const SECTION_TIMINGS: [(u64, u64, u64); MASTER_SECTIONS] = [
+    (417, 667, 500),
+    (417, 667, 500),
+    (417, 667, 500),
+    (417, 667, 500),
+    (417, 667, 500),
+    (417, 417, 500),
+    (417, 267, 500),
+    (267, 200, 500),
+    (200, 100, 500),
+    (200, 100, 283),
+];
+
+/// Points needed for each grade below the grand master, from grade 9 to S9.
+const GRADE_POINTS: [u32; 18] = [
+    0, 400, 800, 1400, 2000, 3500, 5500, 8000, 12000, 16000, 22000, 30000, 40000, 52000, 66000,
+    82000, 100000, 120000,
+];
+/// Points needed at the end of the game to become grand master.
+const GRAND_MASTER_POINTS: u32 = 126000;
+/// Levels the player must reach before the given time to become grand master.
+const GRAND_MASTER_TIMES: [(u16, Duration); 3] = [
+    (300, Duration::from_secs(255)),
+    (500, Duration::from_secs(450)),
+    (MASTER_MAX_LEVEL, Duration::from_secs(810)),
+];
+
+/// `MasterGrade` represents the grades a master game is awarded, from the lowest to the
+/// highest: grades 9 to 1, then S1 to S9 and the grand master.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Serialize, Deserialize)]
+pub enum MasterGrade {
+    #[default]
+    #[serde(rename = "9")]
+    Nine,
+    #[serde(rename = "8")]
+    Eight,
+    #[serde(rename = "7")]
+    Seven,
+    #[serde(rename = "6")]
+    Six,
+    #[serde(rename = "5")]
+    Five,
+    #[serde(rename = "4")]
+    Four,
+    #[serde(rename = "3")]
+    Three,
+    #[serde(rename = "2")]
+    Two,
+    #[serde(rename = "1")]
+    One,
+    S1,
+    S2,
+    S3,
+    S4,
+    S5,
+    S6,
+    S7,
+    S8,
+    S9,
+    /// The grand master, only awarded finishing the game fast enough with S9.
+    GM,
+}
+
+impl MasterGrade {
+    /// Every grade, from the lowest to the highest.
+    const ALL: [MasterGrade; 19] = [
+        MasterGrade::Nine,
+        MasterGrade::Eight,
+        MasterGrade::Seven,
+        MasterGrade::Six,
+        MasterGrade::Five,
+        MasterGrade::Four,
+        MasterGrade::Three,
+        MasterGrade::Two,
+        MasterGrade::One,
+        MasterGrade::S1,
+        MasterGrade::S2,
+        MasterGrade::S3,
+        MasterGrade::S4,
+        MasterGrade::S5,
+        MasterGrade::S6,
+        MasterGrade::S7,
+        MasterGrade::S8,
+        MasterGrade::S9,
+        MasterGrade::GM,
+    ];
+
+    /// Returns the name of the grade, as shown to the player and stored.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            MasterGrade::Nine => "9",
+            MasterGrade::Eight => "8",
+            MasterGrade::Seven => "7",
+            MasterGrade::Six => "6",
+            MasterGrade::Five => "5",
+            MasterGrade::Four => "4",
+            MasterGrade::Three => "3",
+            MasterGrade::Two => "2",
+            MasterGrade::One => "1",
+            MasterGrade::S1 => "S1",
+            MasterGrade::S2 => "S2",
+            MasterGrade::S3 => "S3",
+            MasterGrade::S4 => "S4",
+            MasterGrade::S5 => "S5",
+            MasterGrade::S6 => "S6",
+            MasterGrade::S7 => "S7",
+            MasterGrade::S8 => "S8",
+            MasterGrade::S9 => "S9",
+            MasterGrade::GM => "GM",
+        }
+    }
+
+    /// Returns the grade with the given name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the grade.
+    pub fn from_name(name: &str) -> Option<MasterGrade> {
+        Self::ALL.into_iter().find(|grade| grade.name() == name)
+    }
+
+    /// Returns the grade earned with the given points, below the grand master.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points scored.
+    fn from_points(points: u32) -> MasterGrade {
+        let index = GRADE_POINTS
+            .iter()
+            .rposition(|needed| points >= *needed)
+            .unwrap_or_default();
+        Self::ALL[index]
+    }
+}
+
+/// `MasterTimings` represents the delays of a section of a master game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MasterTimings {
+    /// Milliseconds before the next piece spawns (ARE).
+    entry_delay_millis: u64,
+    /// Milliseconds the completed lines stay on the board before being cleared.
+    line_clear_delay_millis: u64,
+    /// Milliseconds a piece can rest on the floor, restarted only when it reaches a lower row.
+    lock_delay_millis: u64,
+}
+
+impl MasterTimings {
+    /// Returns the timings of the section the given level belongs to.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The level of the game.
+    pub fn of_level(level: u16) -> MasterTimings {
+        let (entry_delay_millis, line_clear_delay_millis, lock_delay_millis) =
+            SECTION_TIMINGS[section(level)];
+        MasterTimings {
+            entry_delay_millis,
+            line_clear_delay_millis,
+            lock_delay_millis,
+        }
+    }
+    /// Returns the milliseconds before the next piece spawns.
+    pub fn entry_delay_millis(&self) -> u64 {
+        self.entry_delay_millis
+    }
+    /// Returns the milliseconds the completed lines stay on the board.
+    pub fn line_clear_delay_millis(&self) -> u64 {
+        self.line_clear_delay_millis
+    }
+    /// Returns the lock delay of the section.
+    pub fn lock_delay(&self) -> LockDelay {
+        LockDelay::StepReset {
+            delay_millis: self.lock_delay_millis,
+        }
+    }
+}
+
+/// Returns the gravity of the given level of a master game, in G.
+///
+/// # Arguments
+///
+/// * `level` - The level of the game.
+pub fn gravity(level: u16) -> f64 {
+    let (_, gravity) = GRAVITY_TABLE
+        .iter()
+        .rev()
+        .find(|(from, _)| level >= *from)
+        .copied()
+        .unwrap_or(GRAVITY_TABLE[0]);
+    gravity as f64 / 256.0
+}
+
+/// Returns the section the given level belongs to.
+fn section(level: u16) -> usize {
+    ((level / SECTION_LEVELS) as usize).min(MASTER_SECTIONS - 1)
+}
+
+/// `MasterProgress` keeps the level, the scoring chain and the section times of a master game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct MasterProgress {
+    /// The level of the game, from 0 to 999.
+    level: u16,
+    /// The multiplier of the points of the next clear, extended by consecutive clears.
+    combo: u32,
+    /// The time played when the current section started.
+    section_start_millis: u64,
+    /// The milliseconds each section took, 0 for the sections not completed.
+    section_times_millis: [u64; MASTER_SECTIONS],
+    /// The highest grade earned.
+    grade: MasterGrade,
+}
+
+impl MasterProgress {
+    /// Returns the level of the game.
+    pub fn level(&self) -> u16 {
+        self.level
+    }
+
+    /// Returns the level the pieces stop at until a line is cleared, which ends the section.
+    pub fn section_stop(&self) -> u16 {
+        ((self.level / SECTION_LEVELS + 1) * SECTION_LEVELS).min(MASTER_MAX_LEVEL)
+    }
+
+    /// Returns the milliseconds each section took, 0 for the sections not completed.
+    pub fn section_times_millis(&self) -> [u64; MASTER_SECTIONS] {
+        self.section_times_millis
+    }
+
+    /// Returns the highest grade earned.
+    pub fn grade(&self) -> MasterGrade {
+        self.grade
+    }
+
+    /// Returns true once the last level has been reached.
+    pub fn finished(&self) -> bool {
+        self.level >= MASTER_MAX_LEVEL
+    }
+
+    /// Advances a level for the piece that has spawned, unless the level stops the section.
+    ///
+    /// # Arguments
+    ///
+    /// * `elapsed` - The time played.
+    pub fn piece_spawned(&mut self, elapsed: Duration) {
+        if self.level + 1 < self.section_stop() {
+            self.advance(1, elapsed);
+        }
+    }
+
+    /// Registers a fixed piece, advancing a level for each line cleared.
+    ///
+    /// Returns the points scored, `(ceil((level + lines) / 4)) * lines * combo`, four times as
+    /// many if the board was left empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `lines` - The lines cleared by the piece.
+    /// * `perfect_clear` - Whether the clear left the board empty.
+    /// * `elapsed` - The time played.
+    pub fn piece_fixed(&mut self, lines: u16, perfect_clear: bool, elapsed: Duration) -> u32 {
+        if lines == 0 {
+            self.combo = 1;
+            return 0;
+        }
+        let lines = lines as u32;
+        self.combo = self.combo.max(1) + 2 * lines - 2;
+        let bravo = if perfect_clear { 4 } else { 1 };
+        let points = (self.level as u32 + lines).div_ceil(4) * lines * self.combo * bravo;
+        self.advance(lines as u16, elapsed);
+        points
+    }
+
+    /// Updates the grade with the points scored, keeping the highest one earned.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points scored in the game.
+    pub fn update_grade(&mut self, points: u32) {
+        let grade = if self.grand_master(points) {
+            MasterGrade::GM
+        } else {
+            MasterGrade::from_points(points)
+        };
+        self.grade = self.grade.max(grade);
+    }
+
+    /// Checks if the player has passed every check of the grand master.
+    fn grand_master(&self, points: u32) -> bool {
+        self.finished()
+            && points >= GRAND_MASTER_POINTS
+            && GRAND_MASTER_TIMES.iter().all(|(level, limit)| {
+                let sections = level.div_ceil(SECTION_LEVELS) as usize;
+                let reached: u64 = self.section_times_millis[..sections].iter().sum();
+                Duration::from_millis(reached) <= *limit
+            })
+    }
+
+    /// Advances the given levels, recording the time of the sections completed.
+    fn advance(&mut self, levels: u16, elapsed: Duration) {
+        let previous_section = section(self.level);
+        self.level = (self.level + levels).min(MASTER_MAX_LEVEL);
+        let elapsed_millis = elapsed.as_millis() as u64;
+        if section(self.level) != previous_section || self.finished() {
+            self.section_times_millis[previous_section] =
+                elapsed_millis.saturating_sub(self.section_start_millis);
+            self.section_start_millis = elapsed_millis;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{MasterGrade, MasterProgress, MasterTimings, gravity};
+    use crate::game::{gravity_curve::GravityCurve, lock_delay::LockDelay};
+
+    #[test]
+    fn gravity_reaches_twenty_g() {
+        assert_eq!(gravity(0), 4.0 / 256.0);
+        assert_eq!(gravity(250), 224.0 / 256.0);
+        assert_eq!(gravity(251), 1.0);
+        assert!(!GravityCurve::instant(gravity(499)));
+        assert!(GravityCurve::instant(gravity(500)));
+        assert!(GravityCurve::instant(gravity(999)));
+    }
+
+    #[test]
+    fn timings_shrink_in_the_last_sections() {
+        let first = MasterTimings::of_level(0);
+        let last = MasterTimings::of_level(999);
+        assert!(last.entry_delay_millis() < first.entry_delay_millis());
+        assert!(last.line_clear_delay_millis() < first.line_clear_delay_millis());
+        assert_eq!(
+            last.lock_delay(),
+            LockDelay::StepReset { delay_millis: 283 }
+        );
+    }
+
+    #[test]
+    fn pieces_stop_at_the_end_of_the_section() {
+        let mut progress = MasterProgress::default();
+        for _ in 0..150 {
+            progress.piece_spawned(Duration::ZERO);
+        }
+        assert_eq!(progress.level(), 99);
+        assert_eq!(progress.section_stop(), 100);
+        progress.piece_fixed(2, false, Duration::from_secs(70));
+        assert_eq!(progress.level(), 101);
+        assert_eq!(progress.section_times_millis()[0], 70_000);
+        assert_eq!(progress.section_stop(), 200);
+    }
+
+    #[test]
+    fn consecutive_clears_score_more() {
+        let mut progress = MasterProgress::default();
+        // ceil((0 + 4) / 4) * 4 lines * combo 7
+        assert_eq!(progress.piece_fixed(4, false, Duration::ZERO), 28);
+        progress.piece_fixed(0, false, Duration::ZERO);
+        // ceil((4 + 1) / 4) * 1 line * combo 1, four times for a perfect clear
+        assert_eq!(progress.piece_fixed(1, true, Duration::ZERO), 8);
+    }
+
+    #[test]
+    fn grade_follows_the_points() {
+        let mut progress = MasterProgress::default();
+        progress.update_grade(0);
+        assert_eq!(progress.grade(), MasterGrade::Nine);
+        progress.update_grade(12000);
+        assert_eq!(progress.grade(), MasterGrade::One);
+        progress.update_grade(500);
+        assert_eq!(progress.grade(), MasterGrade::One);
+        progress.update_grade(130000);
+        assert_eq!(progress.grade(), MasterGrade::S9);
+        assert_eq!(MasterGrade::from_name("S9"), Some(MasterGrade::S9));
+    }
+
+    #[test]
+    fn grand_master_needs_to_be_fast() {
+        let mut fast = MasterProgress::default();
+        let mut slow = MasterProgress::default();
+        for section in 1..=10 {
+            fast.piece_fixed(100, false, Duration::from_secs(60 * section));
+            slow.piece_fixed(100, false, Duration::from_secs(90 * section));
+        }
+        assert!(fast.finished());
+        fast.update_grade(126000);
+        slow.update_grade(126000);
+        assert_eq!(fast.grade(), MasterGrade::GM);
+        assert_eq!(slow.grade(), MasterGrade::S9);
+    }
+}
@@ -1,5 +1,13 @@
 pub mod board;
+pub mod clock;
+pub mod game_mode;
 pub mod game_types;
+pub mod gravity_curve;
+pub mod handling;
+pub mod lock_delay;
+pub mod marathon;
+pub mod master;
 pub mod pieces;
 pub mod queue;
 pub mod strategy;
+pub mod visibility;
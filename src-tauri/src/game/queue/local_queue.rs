@@ -1,39 +1,51 @@
 use super::super::pieces::Piece;
 use super::Queue;
-use std::collections::HashSet;
+use super::randomizer::{Randomizer, RandomizerType};
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
 
 /// The number of pieces shown in the queue.
 const PIECES_SHOWN: usize = 5; // Used in the module, but marked as not used for an unknown reason
 
-/// The number of pieces generated in each cycle.
-const PIECES_GENERATED_BY_CYCLE: usize = 7; // Used in the module, but marked as not used for an unknown reason
-
-/// The number of cycles of pieces generated when asked for the queue.
-const CICLES_OF_PIECES_GENERATED_WHEN_ASKED: usize = 10;
+/// The number of pieces generated when asked for the queue.
+const PIECES_GENERATED_WHEN_ASKED: usize = 70;
 
 /// `LocalQueue` represents a local implementation of the piece queue.
-#[derive(Default, Debug)]
+#[derive(Debug)]
 pub struct LocalQueue {
     /// The vector of pieces in the queue.
     pieces: Vec<Piece>,
-    /// The maximum number of pieces generated.
-    max_piece: usize,
+    /// The generator of the pieces.
+    randomizer: Box<dyn Randomizer>,
+    /// The random number generator used by the randomizer. Its algorithm is fixed, unlike the
+    /// one of `StdRng`, so a seed keeps giving the same pieces to replays and saved games.
+    rng: ChaCha12Rng,
+}
+
+impl Default for LocalQueue {
+    fn default() -> Self {
+        Self::new(rand::random(), RandomizerType::default())
+    }
 }
 
 impl LocalQueue {
+    /// Creates a new `LocalQueue` whose pieces are determined by the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed of the random number generator.
+    /// * `randomizer` - The type of generator used for the pieces.
+    pub fn new(seed: u64, randomizer: RandomizerType) -> Self {
+        LocalQueue {
+            pieces: Vec::new(),
+            randomizer: randomizer.randomizer(),
+            rng: ChaCha12Rng::seed_from_u64(seed),
+        }
+    }
     /// Generates new pieces for the queue.
     fn generate_new_pieces(&mut self) {
-        let mut generated_pieces = HashSet::new();
-        while generated_pieces.len() < PIECES_GENERATED_BY_CYCLE {
-            let piece_num = rand::random_range(0..=6);
-            let piece = piece_num.into();
-            if generated_pieces.contains(&piece) {
-                continue;
-            }
-            generated_pieces.insert(piece);
-        }
-        self.pieces.append(&mut Vec::from_iter(generated_pieces));
-        self.max_piece += PIECES_GENERATED_BY_CYCLE;
+        let mut pieces = self.randomizer.next_pieces(&mut self.rng);
+        self.pieces.append(&mut pieces);
     }
     /// Returns the pieces in the queue.
     pub fn get_pieces(&self) -> Vec<Piece> {
@@ -43,10 +55,7 @@ impl LocalQueue {
 impl Queue for LocalQueue {
     /// Gets a piece from the queue at the given position.
     fn get_piece(&mut self, position: usize) -> Option<Piece> {
-        if self.max_piece < position {
-            self.max_piece = position;
-        }
-        if self.max_piece - position < PIECES_SHOWN {
+        while self.pieces.len() <= position + PIECES_SHOWN {
             self.generate_new_pieces();
         }
         self.pieces.get(position).copied()
@@ -59,7 +68,8 @@ impl Queue for LocalQueue {
 
     /// Gets all pieces in the queue.
     fn get_pieces(&mut self) -> Vec<Piece> {
-        for _ in 0..CICLES_OF_PIECES_GENERATED_WHEN_ASKED {
+        let len = self.pieces.len() + PIECES_GENERATED_WHEN_ASKED;
+        while self.pieces.len() < len {
             self.generate_new_pieces();
         }
         self.pieces.clone()
@@ -68,11 +78,20 @@ impl Queue for LocalQueue {
 
 #[cfg(test)]
 mod tests {
-    use std::collections::HashSet;
+    use std::collections::{HashMap, HashSet};
 
-    use crate::{game::queue::Queue, init_trace::initialize};
+    use crate::{
+        game::{
+            pieces::Piece,
+            queue::{Queue, randomizer::RandomizerType},
+        },
+        init_trace::initialize,
+    };
 
-    use super::{LocalQueue, PIECES_GENERATED_BY_CYCLE};
+    use super::LocalQueue;
+
+    /// The number of pieces in a 7-bag cycle.
+    const PIECES_GENERATED_BY_CYCLE: usize = 7;
 
     #[test]
     fn get_unique_pieces() {
@@ -86,6 +105,54 @@ mod tests {
         }
     }
 
+    #[test]
+    fn same_seed_same_pieces() {
+        initialize();
+        let mut queue = LocalQueue::new(42, RandomizerType::SevenBag);
+        let mut other_queue = LocalQueue::new(42, RandomizerType::SevenBag);
+        for i in 0..100 {
+            assert_eq!(queue.get_piece(i), other_queue.get_piece(i));
+        }
+    }
+
+    #[test]
+    fn fourteen_bag_has_two_of_each_piece() {
+        initialize();
+        let mut queue = LocalQueue::new(42, RandomizerType::FourteenBag);
+        let mut count: HashMap<Piece, u8> = HashMap::new();
+        for i in 0..14 {
+            *count.entry(queue.get_piece(i).unwrap()).or_insert(0) += 1;
+        }
+        assert_eq!(count.len(), 7);
+        assert!(count.values().all(|times| *times == 2));
+    }
+
+    #[test]
+    fn history_never_starts_with_s_z_or_o() {
+        initialize();
+        for seed in 0..50 {
+            let mut queue = LocalQueue::new(seed, RandomizerType::History);
+            let piece = queue.get_piece(0).unwrap();
+            assert!(![Piece::S, Piece::Z, Piece::O].contains(&piece));
+        }
+    }
+
+    #[test]
+    fn nes_generates_every_piece() {
+        initialize();
+        let mut queue = LocalQueue::new(42, RandomizerType::Nes);
+        let pieces: HashSet<Piece> = (0..200).map(|i| queue.get_piece(i).unwrap()).collect();
+        assert_eq!(pieces.len(), 7);
+    }
+
+    #[test]
+    fn pure_random_generates_every_piece() {
+        initialize();
+        let mut queue = LocalQueue::new(42, RandomizerType::PureRandom);
+        let pieces: HashSet<Piece> = (0..200).map(|i| queue.get_piece(i).unwrap()).collect();
+        assert_eq!(pieces.len(), 7);
+    }
+
     #[test]
     fn generate_undetermined_number_of_pieces() {
         initialize();
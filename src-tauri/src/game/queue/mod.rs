@@ -3,6 +3,7 @@ use std::fmt::Debug;
 use super::pieces::Piece;
 
 pub mod local_queue;
+pub mod randomizer;
 pub mod remote_queue;
 
 pub trait Queue: Send + Sync + Debug {
@@ -0,0 +1,20 @@
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha12Rng;
+
+use crate::game::pieces::Piece;
+
+use super::{PIECE_TYPES, Randomizer};
+
+/// `FourteenBag` generates bags with two of each piece, shuffled with Fisher-Yates.
+#[derive(Debug)]
+pub struct FourteenBag;
+
+impl Randomizer for FourteenBag {
+    fn next_pieces(&mut self, rng: &mut ChaCha12Rng) -> Vec<Piece> {
+        let mut bag: Vec<Piece> = (0..PIECE_TYPES * 2)
+            .map(|piece| Piece::from(piece % PIECE_TYPES))
+            .collect();
+        bag.shuffle(rng);
+        bag
+    }
+}
@@ -0,0 +1,58 @@
+use std::collections::VecDeque;
+
+use rand::{Rng, seq::IndexedRandom};
+use rand_chacha::ChaCha12Rng;
+
+use crate::game::pieces::Piece;
+
+use super::{PIECE_TYPES, Randomizer};
+
+/// The number of pieces remembered.
+const HISTORY_SIZE: usize = 4;
+/// The number of rolls made before accepting a piece found in the history.
+const ROLLS: u8 = 4;
+/// The pieces the first piece is chosen from, so the game never starts with an S, Z or O.
+const FIRST_PIECES: [Piece; 4] = [Piece::I, Piece::J, Piece::L, Piece::T];
+
+/// `History` generates pieces like TGM: it rolls again when the piece is one of the last four,
+/// up to a fixed number of rolls.
+#[derive(Debug)]
+pub struct History {
+    /// The last pieces generated, oldest first.
+    history: VecDeque<Piece>,
+    /// A boolean indicating whether the first piece has been generated.
+    started: bool,
+}
+
+impl History {
+    /// Creates a new `History` randomizer, with the history filled with Z pieces.
+    pub fn new() -> Self {
+        History {
+            history: VecDeque::from([Piece::Z; HISTORY_SIZE]),
+            started: false,
+        }
+    }
+}
+
+impl Randomizer for History {
+    fn next_pieces(&mut self, rng: &mut ChaCha12Rng) -> Vec<Piece> {
+        let piece = if self.started {
+            let mut piece = rng.random_range(0..PIECE_TYPES).into();
+            for _ in 1..ROLLS {
+                if !self.history.contains(&piece) {
+                    break;
+                }
+                piece = rng.random_range(0..PIECE_TYPES).into();
+            }
+            piece
+        } else {
+            self.started = true;
+            *FIRST_PIECES
+                .choose(rng)
+                .expect("There are always first pieces to choose from")
+        };
+        self.history.pop_front();
+        self.history.push_back(piece);
+        vec![piece]
+    }
+}
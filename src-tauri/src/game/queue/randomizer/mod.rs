@@ -0,0 +1,61 @@
+mod fourteen_bag;
+mod history;
+mod nes;
+mod pure_random;
+mod seven_bag;
+
+use std::fmt::Debug;
+
+use fourteen_bag::FourteenBag;
+use history::History;
+use nes::Nes;
+use pure_random::PureRandom;
+use rand_chacha::ChaCha12Rng;
+use serde::{Deserialize, Serialize};
+use seven_bag::SevenBag;
+
+use crate::game::pieces::Piece;
+
+/// The number of different playable pieces.
+const PIECE_TYPES: u8 = 7;
+
+/// `Randomizer` trait defines how the pieces of a queue are generated.
+pub trait Randomizer: Send + Sync + Debug {
+    /// Generates the next pieces of the queue.
+    ///
+    /// Bag randomizers return a whole bag, the rest return a single piece.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - The random number generator of the queue.
+    fn next_pieces(&mut self, rng: &mut ChaCha12Rng) -> Vec<Piece>;
+}
+
+/// `RandomizerType` represents the different piece generators a queue can use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum RandomizerType {
+    /// One of each piece, shuffled.
+    #[default]
+    SevenBag,
+    /// Two of each piece, shuffled.
+    FourteenBag,
+    /// TGM-style generator, rerolls pieces found in the history of the last four.
+    History,
+    /// NES-style generator, rerolls once if the piece repeats.
+    Nes,
+    /// Every piece has the same chance to appear.
+    PureRandom,
+}
+
+impl RandomizerType {
+    /// Creates a new randomizer of the given type.
+    pub fn randomizer(&self) -> Box<dyn Randomizer> {
+        match self {
+            RandomizerType::SevenBag => Box::new(SevenBag),
+            RandomizerType::FourteenBag => Box::new(FourteenBag),
+            RandomizerType::History => Box::new(History::new()),
+            RandomizerType::Nes => Box::new(Nes::new()),
+            RandomizerType::PureRandom => Box::new(PureRandom),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use rand::Rng;
+use rand_chacha::ChaCha12Rng;
+
+use crate::game::pieces::Piece;
+
+use super::{PIECE_TYPES, Randomizer};
+
+/// `Nes` generates pieces like the NES version: it rolls one of eight values, and rolls again
+/// among the seven pieces if it got the extra value or the same piece as before.
+#[derive(Debug)]
+pub struct Nes {
+    /// The last piece generated.
+    prev_piece: Option<Piece>,
+}
+
+impl Nes {
+    /// Creates a new `Nes` randomizer.
+    pub fn new() -> Self {
+        Nes { prev_piece: None }
+    }
+}
+
+impl Randomizer for Nes {
+    fn next_pieces(&mut self, rng: &mut ChaCha12Rng) -> Vec<Piece> {
+        let roll = rng.random_range(0..=PIECE_TYPES);
+        let mut piece = Piece::from(roll);
+        if roll == PIECE_TYPES || self.prev_piece == Some(piece) {
+            piece = rng.random_range(0..PIECE_TYPES).into();
+        }
+        self.prev_piece = Some(piece);
+        vec![piece]
+    }
+}
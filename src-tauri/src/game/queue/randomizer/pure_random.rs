@@ -0,0 +1,16 @@
+use rand::Rng;
+use rand_chacha::ChaCha12Rng;
+
+use crate::game::pieces::Piece;
+
+use super::{PIECE_TYPES, Randomizer};
+
+/// `PureRandom` generates every piece independently, with the same chance for each one.
+#[derive(Debug)]
+pub struct PureRandom;
+
+impl Randomizer for PureRandom {
+    fn next_pieces(&mut self, rng: &mut ChaCha12Rng) -> Vec<Piece> {
+        vec![rng.random_range(0..PIECE_TYPES).into()]
+    }
+}
@@ -0,0 +1,18 @@
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha12Rng;
+
+use crate::game::pieces::Piece;
+
+use super::{PIECE_TYPES, Randomizer};
+
+/// `SevenBag` generates bags with one of each piece, shuffled with Fisher-Yates.
+#[derive(Debug)]
+pub struct SevenBag;
+
+impl Randomizer for SevenBag {
+    fn next_pieces(&mut self, rng: &mut ChaCha12Rng) -> Vec<Piece> {
+        let mut bag: Vec<Piece> = (0..PIECE_TYPES).map(Piece::from).collect();
+        bag.shuffle(rng);
+        bag
+    }
+}
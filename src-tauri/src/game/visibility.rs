@@ -0,0 +1,141 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// `Visibility` represents how the locked cells of the board are shown to the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Visibility {
+    /// Every locked cell is shown.
+    #[default]
+    Visible,
+    /// Locked cells are hidden once the delay has passed since their piece was locked.
+    Invisible { delay_millis: u64 },
+    /// Locked cells fade out halfway through the duration and are hidden once it has passed.
+    Fading { duration_millis: u64 },
+    /// Every locked cell is shown with the same colour.
+    Monochrome,
+}
+
+/// `CellShown` represents how a locked cell is shown to the player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellShown {
+    /// The cell is shown with the colour of its piece.
+    Normal,
+    /// The cell is shown faded out.
+    Faded,
+    /// The cell is shown as empty.
+    Hidden,
+    /// The cell is shown with the colour shared by every locked cell.
+    Monochrome,
+}
+
+impl Visibility {
+    /// Returns how a locked cell is shown.
+    ///
+    /// # Arguments
+    ///
+    /// * `since_locked` - The time since the piece of the cell was locked, or `None` when it is
+    ///   not known, like for trash cells, which are shown as if they had been locked long ago.
+    pub fn shown(&self, since_locked: Option<Duration>) -> CellShown {
+        match (self, since_locked) {
+            (Visibility::Visible, _) => CellShown::Normal,
+            (Visibility::Monochrome, _) => CellShown::Monochrome,
+            (Visibility::Invisible { delay_millis }, Some(since))
+                if since < Duration::from_millis(*delay_millis) =>
+            {
+                CellShown::Normal
+            }
+            (Visibility::Fading { duration_millis }, Some(since))
+                if since < Duration::from_millis(*duration_millis / 2) =>
+            {
+                CellShown::Normal
+            }
+            (Visibility::Fading { duration_millis }, Some(since))
+                if since < Duration::from_millis(*duration_millis) =>
+            {
+                CellShown::Faded
+            }
+            (Visibility::Invisible { .. } | Visibility::Fading { .. }, _) => CellShown::Hidden,
+        }
+    }
+
+    /// Returns the time since the lock at which a locked cell is next shown differently, if
+    /// it ever is.
+    ///
+    /// # Arguments
+    ///
+    /// * `since_locked` - The time since the piece of the cell was locked.
+    pub fn next_change(&self, since_locked: Duration) -> Option<Duration> {
+        let changes = match self {
+            Visibility::Visible | Visibility::Monochrome => vec![],
+            Visibility::Invisible { delay_millis } => vec![Duration::from_millis(*delay_millis)],
+            Visibility::Fading { duration_millis } => vec![
+                Duration::from_millis(*duration_millis / 2),
+                Duration::from_millis(*duration_millis),
+            ],
+        };
+        changes.into_iter().find(|change| *change > since_locked)
+    }
+
+    /// Checks if the ghost piece is hidden, so the stack can't be read through it.
+    pub fn hides_ghost(&self) -> bool {
+        matches!(
+            self,
+            Visibility::Invisible { .. } | Visibility::Fading { .. }
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{CellShown, Visibility};
+
+    #[test]
+    fn invisible_hides_after_the_delay() {
+        let visibility = Visibility::Invisible { delay_millis: 500 };
+        let shown = |millis| visibility.shown(Some(Duration::from_millis(millis)));
+        assert_eq!(shown(0), CellShown::Normal);
+        assert_eq!(shown(499), CellShown::Normal);
+        assert_eq!(shown(500), CellShown::Hidden);
+        assert_eq!(visibility.shown(None), CellShown::Hidden);
+        assert_eq!(
+            visibility.next_change(Duration::ZERO),
+            Some(Duration::from_millis(500))
+        );
+        assert_eq!(visibility.next_change(Duration::from_millis(500)), None);
+        assert!(visibility.hides_ghost());
+    }
+
+    #[test]
+    fn fading_fades_then_hides() {
+        let visibility = Visibility::Fading {
+            duration_millis: 2000,
+        };
+        let shown = |millis| visibility.shown(Some(Duration::from_millis(millis)));
+        assert_eq!(shown(999), CellShown::Normal);
+        assert_eq!(shown(1000), CellShown::Faded);
+        assert_eq!(shown(2000), CellShown::Hidden);
+        assert_eq!(
+            visibility.next_change(Duration::from_millis(10)),
+            Some(Duration::from_millis(1000))
+        );
+        assert_eq!(
+            visibility.next_change(Duration::from_millis(1000)),
+            Some(Duration::from_millis(2000))
+        );
+        assert_eq!(visibility.next_change(Duration::from_millis(2000)), None);
+    }
+
+    #[test]
+    fn monochrome_and_visible_never_change() {
+        assert_eq!(Visibility::Monochrome.shown(None), CellShown::Monochrome);
+        assert_eq!(
+            Visibility::Visible.shown(Some(Duration::ZERO)),
+            CellShown::Normal
+        );
+        assert_eq!(Visibility::Monochrome.next_change(Duration::ZERO), None);
+        assert!(!Visibility::Monochrome.hides_ghost());
+    }
+}
@@ -1,7 +1,8 @@
 use init_trace::initialize;
-use persistence::migrations::run_migrations;
+use persistence::{migrations::run_migrations, replays::init_replays_dir};
 use tauri::Manager;
 mod commands;
+pub mod events;
 pub mod game;
 pub mod globals;
 pub mod helpers;
@@ -19,6 +20,7 @@ pub fn run() {
             main_window.minimize().unwrap();
             let handle = app.handle().clone();
             tokio::spawn(async move {
+                init_replays_dir(handle.clone()).await;
                 run_migrations(handle).await;
             });
             Ok(())
@@ -34,8 +36,17 @@ pub fn run() {
             commands::game_commands::save_piece,
             commands::game_commands::hard_drop,
             commands::game_commands::soft_drop,
+            commands::game_commands::key_pressed,
+            commands::game_commands::key_released,
             commands::game_commands::forfeit_game,
+            commands::game_commands::pause_game,
+            commands::game_commands::resume_game,
             commands::game_commands::retry_game,
+            commands::game_commands::play_replay,
+            commands::game_commands::list_replays,
+            commands::game_commands::save_game,
+            commands::game_commands::list_saved_games,
+            commands::game_commands::resume_saved_game,
             commands::game_commands::targeting_strategy_even,
             commands::game_commands::targeting_strategy_eliminations,
             commands::game_commands::targeting_strategy_random,
@@ -44,6 +55,9 @@ pub fn run() {
             commands::game_info_retreive::retreive_classic_game_info,
             commands::game_info_retreive::retreive_lines_game_info,
             commands::game_info_retreive::retreive_blitz_game_info,
+            commands::game_info_retreive::retreive_cheese_game_info,
+            commands::game_info_retreive::retreive_survival_game_info,
+            commands::game_info_retreive::retreive_master_game_info,
             commands::exit_app::exit_app,
             commands::room_commands::server::create_room,
             commands::room_commands::server::close_room,
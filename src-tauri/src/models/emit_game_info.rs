@@ -43,6 +43,24 @@ impl EmitGameInfo {
                     };
                     Self::top_five_blitz(previous_results)
                 }
+                super::game_info::GameTypeInfo::Cheese(_cheese_game_info) => {
+                    let GameTypeInfo::Cheese(_info) = last_game_info.type_of_info() else {
+                        panic!("GameInfo type must be equal to the one of previous results");
+                    };
+                    Self::top_five_cheese(previous_results)
+                }
+                super::game_info::GameTypeInfo::Survival(_survival_game_info) => {
+                    let GameTypeInfo::Survival(_info) = last_game_info.type_of_info() else {
+                        panic!("GameInfo type must be equal to the one of previous results");
+                    };
+                    Self::top_five_survival(previous_results)
+                }
+                super::game_info::GameTypeInfo::Master(_master_game_info) => {
+                    let GameTypeInfo::Master(_info) = last_game_info.type_of_info() else {
+                        panic!("GameInfo type must be equal to the one of previous results");
+                    };
+                    Self::top_five_master(previous_results)
+                }
             },
             None => vec![],
         };
@@ -71,6 +89,13 @@ impl EmitGameInfo {
                 0,
                 0,
                 0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
+                0,
                 GameTypeInfo::Classic(ClassicGameInfo::default()),
             ),
             top_five_results: vec![],
@@ -124,6 +149,77 @@ impl EmitGameInfo {
             .take(5)
             .collect::<Vec<GameInfo>>()
     }
+    /// Sorts the previous results and returns the top five cheese race results, the fastest
+    /// first and the ones that used fewer pieces for each garbage line on ties.
+    fn top_five_cheese(mut previous_results: Vec<GameInfo>) -> Vec<GameInfo> {
+        previous_results.sort_by(|a, b| match (a.type_of_info(), b.type_of_info()) {
+            (
+                super::game_info::GameTypeInfo::Cheese(cheese_game_info_a),
+                super::game_info::GameTypeInfo::Cheese(cheese_game_info_b),
+            ) => cheese_game_info_a
+                .time_endured()
+                .cmp(&cheese_game_info_b.time_endured())
+                .then(
+                    cheese_game_info_a
+                        .pieces_per_garbage()
+                        .total_cmp(&cheese_game_info_b.pieces_per_garbage()),
+                ),
+            _ => panic!("Invalid state"),
+        });
+        previous_results
+            .into_iter()
+            .take(5)
+            .collect::<Vec<GameInfo>>()
+    }
+    /// Sorts the previous results and returns the top five survival results, the longest
+    /// first and the ones that sent more lines on ties.
+    fn top_five_survival(mut previous_results: Vec<GameInfo>) -> Vec<GameInfo> {
+        previous_results.sort_by(|a, b| match (a.type_of_info(), b.type_of_info()) {
+            (
+                super::game_info::GameTypeInfo::Survival(survival_game_info_a),
+                super::game_info::GameTypeInfo::Survival(survival_game_info_b),
+            ) => survival_game_info_b
+                .time_endured()
+                .cmp(&survival_game_info_a.time_endured())
+                .then(
+                    survival_game_info_b
+                        .lines_sent()
+                        .cmp(&survival_game_info_a.lines_sent()),
+                ),
+            _ => panic!("Invalid state"),
+        });
+        previous_results
+            .into_iter()
+            .take(5)
+            .collect::<Vec<GameInfo>>()
+    }
+    /// Sorts the previous results and returns the top five master game results, the highest
+    /// grade first, then the highest level and the fastest on ties.
+    fn top_five_master(mut previous_results: Vec<GameInfo>) -> Vec<GameInfo> {
+        previous_results.sort_by(|a, b| match (a.type_of_info(), b.type_of_info()) {
+            (
+                super::game_info::GameTypeInfo::Master(master_game_info_a),
+                super::game_info::GameTypeInfo::Master(master_game_info_b),
+            ) => master_game_info_b
+                .grade()
+                .cmp(&master_game_info_a.grade())
+                .then(
+                    master_game_info_b
+                        .level_reached()
+                        .cmp(&master_game_info_a.level_reached()),
+                )
+                .then(
+                    master_game_info_a
+                        .time_endured()
+                        .cmp(&master_game_info_b.time_endured()),
+                ),
+            _ => panic!("Invalid state"),
+        });
+        previous_results
+            .into_iter()
+            .take(5)
+            .collect::<Vec<GameInfo>>()
+    }
     /// Checks if a game info is in the top five results.
     ///
     /// # Arguments
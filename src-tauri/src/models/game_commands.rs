@@ -1,7 +1,9 @@
+use serde::{Deserialize, Serialize};
+
 use crate::game::{pieces::Piece, strategy::Strategy};
 
 /// `FirstLevelCommands` represents the commands that can be directly triggered by player input.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum FirstLevelCommands {
     /// Moves the current piece to the right.
     RightMove,
@@ -19,9 +21,32 @@ pub enum FirstLevelCommands {
     SavePiece,
     /// Rotates the current piece 180 degrees.
     FullRotation,
+    /// A key whose held state is tracked has been pressed.
+    KeyPressed(HeldKey),
+    /// A key whose held state is tracked has been released.
+    KeyReleased(HeldKey),
+}
+/// `HeldKey` represents the keys whose held state is tracked, either because they repeat
+/// their action or because they act on the pieces that spawn while held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HeldKey {
+    /// Moves the current piece to the left.
+    Left,
+    /// Moves the current piece to the right.
+    Right,
+    /// Makes the current piece fall faster.
+    SoftDrop,
+    /// Rotates the current piece clockwise.
+    ClockWiseRotation,
+    /// Rotates the current piece counter-clockwise.
+    CounterClockWiseRotation,
+    /// Rotates the current piece 180 degrees.
+    FullRotation,
+    /// Saves the current piece for later use.
+    SavePiece,
 }
 /// `SecondLevelCommands` represents the commands that are triggered by game logic or network events.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SecondLevelCommands {
     /// Asks for the queue of upcoming pieces.
     AskForQueue,
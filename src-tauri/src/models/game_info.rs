@@ -1,7 +1,11 @@
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::game_options::GameOptions;
-use crate::game::board::local_board::ClearLinePattern;
+use crate::game::{
+    board::local_board::ClearLinePattern,
+    game_mode::GameMode,
+    master::{MASTER_SECTIONS, MasterGrade, MasterProgress},
+};
 
 /// `GameInfo` stores general information about a game session, including statistics applicable to all game types.
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, sqlx::FromRow, PartialEq, Eq)]
@@ -34,23 +38,28 @@ pub struct GameInfo {
     minitspins: u32,
     /// The number of mini T-spin single line clears.
     minitspin_singles: u32,
+    /// The number of spins performed with pieces other than T.
+    allspins: u32,
+    /// The number of spin single line clears performed with pieces other than T.
+    allspin_singles: u32,
+    /// The number of spin double line clears performed with pieces other than T.
+    allspin_doubles: u32,
+    /// The number of spin triple line clears performed with pieces other than T.
+    allspin_triples: u32,
+    /// The number of perfect clears performed.
+    perfect_clears: u32,
+    /// The longest back-to-back chain reached.
+    max_back_to_back: u32,
+    /// The longest combo reached.
+    max_combo: u32,
     /// Specific information for the particular game type.
     specific_info: GameTypeInfo,
 }
 
 impl GameInfo {
     /// Creates a new `GameInfo` instance based on the provided `GameOptions`.
-    pub fn new(options: GameOptions) -> Self {
-        let type_info = match (
-            options.is_normal(),
-            options.is_lines_40(),
-            options.is_blitz(),
-        ) {
-            (true, false, false) => GameTypeInfo::Classic(ClassicGameInfo::default()),
-            (false, true, false) => GameTypeInfo::Lines(LinesGameInfo::default()),
-            (false, false, true) => GameTypeInfo::Blitz(BlitzGameInfo::default()),
-            _ => panic!("Invalid state"),
-        };
+    pub fn new(options: &GameOptions) -> Self {
+        let type_info = options.mode().type_info();
         GameInfo {
             piece_moves: 0,
             spins: 0,
@@ -66,6 +75,13 @@ impl GameInfo {
             tspin_triples: 0,
             minitspins: 0,
             minitspin_singles: 0,
+            allspins: 0,
+            allspin_singles: 0,
+            allspin_doubles: 0,
+            allspin_triples: 0,
+            perfect_clears: 0,
+            max_back_to_back: 0,
+            max_combo: 0,
             specific_info: type_info,
         }
     }
@@ -86,6 +102,13 @@ impl GameInfo {
         tspin_triples: u32,
         minitspins: u32,
         minitspin_singles: u32,
+        allspins: u32,
+        allspin_singles: u32,
+        allspin_doubles: u32,
+        allspin_triples: u32,
+        perfect_clears: u32,
+        max_back_to_back: u32,
+        max_combo: u32,
         specific_info: GameTypeInfo,
     ) -> Self {
         Self {
@@ -103,6 +126,13 @@ impl GameInfo {
             tspin_triples,
             minitspins,
             minitspin_singles,
+            allspins,
+            allspin_singles,
+            allspin_doubles,
+            allspin_triples,
+            perfect_clears,
+            max_back_to_back,
+            max_combo,
             specific_info,
         }
     }
@@ -177,6 +207,41 @@ impl GameInfo {
         self.minitspin_singles
     }
 
+    /// Returns the number of allspins.
+    pub fn allspins(&self) -> u32 {
+        self.allspins
+    }
+
+    /// Returns the number of allspin singles.
+    pub fn allspin_singles(&self) -> u32 {
+        self.allspin_singles
+    }
+
+    /// Returns the number of allspin doubles.
+    pub fn allspin_doubles(&self) -> u32 {
+        self.allspin_doubles
+    }
+
+    /// Returns the number of allspin triples.
+    pub fn allspin_triples(&self) -> u32 {
+        self.allspin_triples
+    }
+
+    /// Returns the number of perfect clears.
+    pub fn perfect_clears(&self) -> u32 {
+        self.perfect_clears
+    }
+
+    /// Returns the longest back-to-back chain.
+    pub fn max_back_to_back(&self) -> u32 {
+        self.max_back_to_back
+    }
+
+    /// Returns the longest combo.
+    pub fn max_combo(&self) -> u32 {
+        self.max_combo
+    }
+
     /// Updates the line clear counts based on the given `ClearLinePattern`.
     pub fn line_cleared(&mut self, pattern: ClearLinePattern) {
         match pattern {
@@ -215,6 +280,19 @@ impl GameInfo {
                 self.lines_cleared += 1;
                 self.minitspin_singles += 1;
             }
+            ClearLinePattern::Spin(_) => self.allspins += 1,
+            ClearLinePattern::SpinSingle(_) => {
+                self.lines_cleared += 1;
+                self.allspin_singles += 1;
+            }
+            ClearLinePattern::SpinDouble(_) => {
+                self.lines_cleared += 2;
+                self.allspin_doubles += 1;
+            }
+            ClearLinePattern::SpinTriple(_) => {
+                self.lines_cleared += 3;
+                self.allspin_triples += 1;
+            }
         }
     }
     /// Increments the number of pieces used.
@@ -229,20 +307,66 @@ impl GameInfo {
     pub fn spinned(&mut self) {
         self.spins += 1;
     }
-    /// Registers the final information for the game, such as time, points, and level.
-    pub fn register_final_info(&mut self, time: u64, points: u32, level: u16) {
+    /// Increments the number of perfect clears.
+    pub fn perfect_cleared(&mut self) {
+        self.perfect_clears += 1;
+    }
+    /// Registers the current back-to-back and combo chains, keeping the longest ones.
+    pub fn register_chains(&mut self, back_to_back: Option<u16>, combo: Option<u16>) {
+        if let Some(back_to_back) = back_to_back {
+            self.max_back_to_back = self.max_back_to_back.max(back_to_back as u32);
+        }
+        if let Some(combo) = combo {
+            self.max_combo = self.max_combo.max(combo as u32);
+        }
+    }
+    /// Registers the garbage lines cleared so far, kept only by the games that count them.
+    pub fn register_garbage_cleared(&mut self, garbage_cleared: u32) {
+        if let GameTypeInfo::Cheese(cheese_game_info) = &mut self.specific_info {
+            cheese_game_info.garbage_cleared = garbage_cleared;
+        }
+    }
+    /// Registers lines sent by a clear, kept only by the games that count them.
+    pub fn lines_sent(&mut self, lines: u32) {
+        if let GameTypeInfo::Survival(survival_game_info) = &mut self.specific_info {
+            survival_game_info.lines_sent += lines;
+        }
+    }
+    /// Registers the level, grade and section times of a master game.
+    pub fn register_master(&mut self, master: &MasterProgress) {
+        if let GameTypeInfo::Master(master_game_info) = &mut self.specific_info {
+            master_game_info.level_reached = master.level();
+            master_game_info.grade = master.grade();
+            master_game_info.section_times_millis = master.section_times_millis();
+        }
+    }
+    /// Registers the final information for the game, such as time in milliseconds, points, level,
+    /// and whether it was won.
+    pub fn register_final_info(&mut self, time: u64, points: u32, level: u16, won: bool) {
         match &mut self.specific_info {
             GameTypeInfo::Classic(classic_game_info) => {
                 classic_game_info.level_reached = level;
                 classic_game_info.points = points;
-                classic_game_info.time_endured = time;
+                classic_game_info.time_endured_millis = time;
+                classic_game_info.won = won;
             }
             GameTypeInfo::Lines(lines_game_info) => {
-                lines_game_info.time_endured = time;
+                lines_game_info.time_endured_millis = time;
             }
             GameTypeInfo::Blitz(blitz_game_info) => {
                 blitz_game_info.points = points;
             }
+            GameTypeInfo::Cheese(cheese_game_info) => {
+                cheese_game_info.time_endured_millis = time;
+                cheese_game_info.pieces_used = self.pieces_used;
+            }
+            GameTypeInfo::Survival(survival_game_info) => {
+                survival_game_info.time_endured_millis = time;
+            }
+            GameTypeInfo::Master(master_game_info) => {
+                master_game_info.time_endured_millis = time;
+                master_game_info.points = points;
+            }
         }
     }
     /// Returns the specific game type information.
@@ -260,31 +384,64 @@ pub enum GameTypeInfo {
     Lines(LinesGameInfo),
     /// Represents information for a blitz game.
     Blitz(BlitzGameInfo),
+    /// Represents information for a cheese race.
+    Cheese(CheeseGameInfo),
+    /// Represents information for a survival game.
+    Survival(SurvivalGameInfo),
+    /// Represents information for a master game.
+    Master(MasterGameInfo),
+}
+
+impl GameTypeInfo {
+    /// Returns the mode the information belongs to.
+    pub fn mode(&self) -> GameMode {
+        match self {
+            GameTypeInfo::Classic(_) => GameMode::Classic,
+            GameTypeInfo::Lines(info) => GameMode::Lines {
+                goal: info.lines_goal,
+            },
+            GameTypeInfo::Blitz(info) => GameMode::Blitz {
+                duration_secs: info.duration_secs,
+            },
+            GameTypeInfo::Cheese(info) => GameMode::Cheese {
+                goal: info.garbage_goal,
+                height: info.height,
+                messiness: info.messiness,
+            },
+            GameTypeInfo::Survival(_) => GameMode::Survival,
+            GameTypeInfo::Master(_) => GameMode::Master,
+        }
+    }
 }
 
 /// `ClassicGameInfo` stores information specific to classic Tetris games.
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, sqlx::FromRow, PartialEq, Eq)]
 pub struct ClassicGameInfo {
-    /// The time endured in the game.
-    time_endured: u64,
+    /// The time endured in the game, in milliseconds. Sent to the UI in seconds.
+    #[serde(rename = "time_endured", with = "millis_as_secs")]
+    time_endured_millis: u64,
     /// The points earned in the game.
     points: u32,
     /// The level reached in the game.
     level_reached: u16,
+    /// Whether the game reached its end instead of the player topping out.
+    #[serde(default)]
+    won: bool,
 }
 
 impl ClassicGameInfo {
     /// Creates a new `ClassicGameInfo` instance.
-    pub fn new(time_endured: u64, points: u32, level_reached: u16) -> Self {
+    pub fn new(time_endured_millis: u64, points: u32, level_reached: u16, won: bool) -> Self {
         ClassicGameInfo {
-            time_endured,
+            time_endured_millis,
             points,
             level_reached,
+            won,
         }
     }
-    /// Returns the time endured in the game.
+    /// Returns the time endured in the game, in milliseconds.
     pub fn time_endured(&self) -> u32 {
-        self.time_endured as u32
+        self.time_endured_millis as u32
     }
 
     /// Returns the points earned in the game.
@@ -296,40 +453,233 @@ impl ClassicGameInfo {
     pub fn level_reached(&self) -> u16 {
         self.level_reached
     }
+
+    /// Returns whether the game reached its end instead of the player topping out.
+    pub fn won(&self) -> bool {
+        self.won
+    }
 }
 
-/// `LinesGameInfo` stores information specific to 40-lines Tetris games.
+/// `LinesGameInfo` stores information specific to lines (sprint) Tetris games.
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, sqlx::FromRow, PartialEq, Eq)]
 pub struct LinesGameInfo {
-    /// The time endured in the game.
-    time_endured: u64,
+    /// The time endured in the game, in milliseconds. Sent to the UI in seconds.
+    #[serde(rename = "time_endured", with = "millis_as_secs")]
+    time_endured_millis: u64,
+    /// The lines to clear in the game.
+    lines_goal: u32,
 }
 
 impl LinesGameInfo {
     /// Creates a new `LinesGameInfo` instance.
-    pub fn new(time_endured: u64) -> Self {
-        LinesGameInfo { time_endured }
+    pub fn new(time_endured_millis: u64, lines_goal: u32) -> Self {
+        LinesGameInfo {
+            time_endured_millis,
+            lines_goal,
+        }
     }
-    /// Returns the time endured in the game.
+    /// Returns the time endured in the game, in milliseconds.
     pub fn time_endured(&self) -> u32 {
-        self.time_endured as u32
+        self.time_endured_millis as u32
+    }
+    /// Returns the lines to clear in the game.
+    pub fn lines_goal(&self) -> u32 {
+        self.lines_goal
     }
 }
 
-/// `BlitzGameInfo` stores information specific to Blitz Tetris games.
+/// `BlitzGameInfo` stores information specific to Blitz (ultra) Tetris games.
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, sqlx::FromRow, PartialEq, Eq)]
 pub struct BlitzGameInfo {
     /// The points earned in the game.
     points: u32,
+    /// The seconds the game lasted.
+    duration_secs: u64,
 }
 
 impl BlitzGameInfo {
     /// Creates a new `BlitzGameInfo` instance.
-    pub fn new(points: u32) -> Self {
-        BlitzGameInfo { points }
+    pub fn new(points: u32, duration_secs: u64) -> Self {
+        BlitzGameInfo {
+            points,
+            duration_secs,
+        }
     }
     /// Returns the points earned in the game.
     pub fn points(&self) -> u32 {
         self.points
     }
+    /// Returns the seconds the game lasted.
+    pub fn duration_secs(&self) -> u64 {
+        self.duration_secs
+    }
+}
+
+/// `CheeseGameInfo` stores information specific to cheese races.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, sqlx::FromRow, PartialEq, Eq)]
+pub struct CheeseGameInfo {
+    /// The time endured in the game, in milliseconds. Sent to the UI in seconds.
+    #[serde(rename = "time_endured", with = "millis_as_secs")]
+    time_endured_millis: u64,
+    /// The garbage lines to clear in the game.
+    garbage_goal: u32,
+    /// The garbage lines kept on the board.
+    height: u8,
+    /// The percentage of garbage lines whose hole moves.
+    messiness: u8,
+    /// The garbage lines cleared in the game.
+    garbage_cleared: u32,
+    /// The pieces used to clear the garbage lines.
+    pieces_used: u32,
+}
+
+impl CheeseGameInfo {
+    /// Creates a new `CheeseGameInfo` instance.
+    pub fn new(
+        time_endured_millis: u64,
+        garbage_goal: u32,
+        height: u8,
+        messiness: u8,
+        garbage_cleared: u32,
+        pieces_used: u32,
+    ) -> Self {
+        CheeseGameInfo {
+            time_endured_millis,
+            garbage_goal,
+            height,
+            messiness,
+            garbage_cleared,
+            pieces_used,
+        }
+    }
+    /// Returns the time endured in the game, in milliseconds.
+    pub fn time_endured(&self) -> u32 {
+        self.time_endured_millis as u32
+    }
+    /// Returns the garbage lines to clear in the game.
+    pub fn garbage_goal(&self) -> u32 {
+        self.garbage_goal
+    }
+    /// Returns the garbage lines kept on the board.
+    pub fn height(&self) -> u8 {
+        self.height
+    }
+    /// Returns the percentage of garbage lines whose hole moves.
+    pub fn messiness(&self) -> u8 {
+        self.messiness
+    }
+    /// Returns the garbage lines cleared in the game.
+    pub fn garbage_cleared(&self) -> u32 {
+        self.garbage_cleared
+    }
+    /// Returns the pieces used to clear the garbage lines.
+    pub fn pieces_used(&self) -> u32 {
+        self.pieces_used
+    }
+    /// Returns the pieces used for each garbage line cleared, or 0 if none was cleared.
+    pub fn pieces_per_garbage(&self) -> f64 {
+        if self.garbage_cleared == 0 {
+            return 0.0;
+        }
+        self.pieces_used as f64 / self.garbage_cleared as f64
+    }
+}
+
+/// `SurvivalGameInfo` stores information specific to survival games.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, sqlx::FromRow, PartialEq, Eq)]
+pub struct SurvivalGameInfo {
+    /// The time survived in the game, in milliseconds. Sent to the UI in seconds.
+    #[serde(rename = "time_endured", with = "millis_as_secs")]
+    time_endured_millis: u64,
+    /// The lines sent back by the clears, countering the rising garbage.
+    lines_sent: u32,
+}
+
+impl SurvivalGameInfo {
+    /// Creates a new `SurvivalGameInfo` instance.
+    pub fn new(time_endured_millis: u64, lines_sent: u32) -> Self {
+        SurvivalGameInfo {
+            time_endured_millis,
+            lines_sent,
+        }
+    }
+    /// Returns the time survived in the game, in milliseconds.
+    pub fn time_endured(&self) -> u32 {
+        self.time_endured_millis as u32
+    }
+    /// Returns the lines sent back by the clears.
+    pub fn lines_sent(&self) -> u32 {
+        self.lines_sent
+    }
+}
+
+/// `MasterGameInfo` stores information specific to master games.
+#[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MasterGameInfo {
+    /// The time endured in the game, in milliseconds. Sent to the UI in seconds.
+    #[serde(rename = "time_endured", with = "millis_as_secs")]
+    time_endured_millis: u64,
+    /// The level reached in the game.
+    level_reached: u16,
+    /// The grade awarded in the game.
+    grade: MasterGrade,
+    /// The points earned in the game.
+    points: u32,
+    /// The milliseconds each section took, 0 for the sections not completed.
+    section_times_millis: [u64; MASTER_SECTIONS],
+}
+
+impl MasterGameInfo {
+    /// Creates a new `MasterGameInfo` instance.
+    pub fn new(
+        time_endured_millis: u64,
+        level_reached: u16,
+        grade: MasterGrade,
+        points: u32,
+        section_times_millis: [u64; MASTER_SECTIONS],
+    ) -> Self {
+        MasterGameInfo {
+            time_endured_millis,
+            level_reached,
+            grade,
+            points,
+            section_times_millis,
+        }
+    }
+    /// Returns the time endured in the game, in milliseconds.
+    pub fn time_endured(&self) -> u32 {
+        self.time_endured_millis as u32
+    }
+    /// Returns the level reached in the game.
+    pub fn level_reached(&self) -> u16 {
+        self.level_reached
+    }
+    /// Returns the grade awarded in the game.
+    pub fn grade(&self) -> MasterGrade {
+        self.grade
+    }
+    /// Returns the points earned in the game.
+    pub fn points(&self) -> u32 {
+        self.points
+    }
+    /// Returns the milliseconds each section took, 0 for the sections not completed.
+    pub fn section_times_millis(&self) -> [u64; MASTER_SECTIONS] {
+        self.section_times_millis
+    }
+}
+
+/// Serializes times kept in milliseconds as seconds with their fractional part.
+mod millis_as_secs {
+    use super::{Deserialize, Deserializer, Serializer};
+
+    /// Serializes the milliseconds as seconds.
+    pub fn serialize<S: Serializer>(millis: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(*millis as f64 / 1000.0)
+    }
+
+    /// Deserializes seconds into milliseconds.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        let secs = f64::deserialize(deserializer)?;
+        Ok((secs * 1000.0).round() as u64)
+    }
 }
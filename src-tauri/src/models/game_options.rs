@@ -1,45 +1,96 @@
 use serde::{Deserialize, Serialize};
 
+use crate::game::{
+    board::local_board::{BIG_SCALE, BOARD_HEIGHT, rotation_system::RotationSystem},
+    game_mode::GameMode,
+    gravity_curve::GravityCurve,
+    handling::Handling,
+    lock_delay::LockDelay,
+    marathon::Marathon,
+    queue::randomizer::RandomizerType,
+    visibility::Visibility,
+};
+
 /// `GameOptions` represents the options for a game session.
-#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GameOptions {
     /// The number of players in the game.
     number_of_players: u8,
-    /// A boolean indicating whether the game is in 40-lines mode.
-    lines_40: bool,
-    /// A boolean indicating whether the game is in blitz mode.
-    blitz: bool,
-    /// A boolean indicating whether the game is in normal mode.
-    normal: bool,
+    /// The mode of the game.
+    mode: GameMode,
+    /// The starting level, goal system and end of a classic game.
+    #[serde(default)]
+    marathon: Marathon,
+    /// The rotation system used to kick the pieces.
+    #[serde(default)]
+    rotation_system: RotationSystem,
+    /// The seed of the random number generators, chosen when the game starts if not given.
+    #[serde(default)]
+    seed: Option<u64>,
+    /// The generator used for the pieces of the queue.
+    #[serde(default)]
+    randomizer: RandomizerType,
+    /// How fast the pieces fall in each level.
+    #[serde(default)]
+    gravity: GravityCurve,
+    /// When a piece resting on the floor gets fixed.
+    #[serde(default)]
+    lock_delay: LockDelay,
+    /// Milliseconds between a piece being fixed and the next one spawning (ARE).
+    #[serde(default)]
+    entry_delay_millis: u64,
+    /// Milliseconds the completed lines stay on the board before being cleared.
+    #[serde(default)]
+    line_clear_delay_millis: u64,
+    /// How the held keys repeat their action.
+    #[serde(default)]
+    handling: Handling,
+    /// Whether the rotation and hold keys held while a piece spawns act on it (IRS and IHS).
+    #[serde(default)]
+    initial_actions: bool,
+    /// How the locked cells of the board are shown to the player.
+    #[serde(default)]
+    visibility: Visibility,
+    /// Whether the pieces are played at double scale, on a field of half the width and height.
+    #[serde(default)]
+    big: bool,
 }
 impl Default for GameOptions {
     fn default() -> Self {
         Self {
             number_of_players: 1,
-            lines_40: false,
-            blitz: false,
-            normal: true,
+            mode: GameMode::default(),
+            marathon: Marathon::default(),
+            rotation_system: RotationSystem::default(),
+            seed: None,
+            randomizer: RandomizerType::default(),
+            gravity: GravityCurve::default(),
+            lock_delay: LockDelay::default(),
+            entry_delay_millis: 0,
+            line_clear_delay_millis: 0,
+            handling: Handling::default(),
+            initial_actions: false,
+            visibility: Visibility::default(),
+            big: false,
         }
     }
 }
 impl GameOptions {
-    /// Sets the game to normal mode.
-    pub fn normal(&mut self) {
-        self.lines_40 = false;
-        self.blitz = false;
-        self.normal = true;
-    }
-    /// Sets the game to blitz mode.
-    pub fn blitz(&mut self) {
-        self.lines_40 = false;
-        self.blitz = true;
-        self.normal = false;
-    }
-    /// Sets the game to 40-lines mode.
-    pub fn lines_40(&mut self) {
-        self.lines_40 = true;
-        self.blitz = false;
-        self.normal = false;
+    /// Sets the mode of the game.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode to play.
+    pub fn set_mode(&mut self, mode: GameMode) {
+        self.mode = mode;
+    }
+    /// Sets the starting level, goal system and end of a classic game.
+    ///
+    /// # Arguments
+    ///
+    /// * `marathon` - The options of the classic game.
+    pub fn set_marathon(&mut self, marathon: Marathon) {
+        self.marathon = marathon;
     }
     /// Sets the game to single player mode.
     pub fn single_player(&mut self) {
@@ -56,20 +107,165 @@ impl GameOptions {
         }
         self.number_of_players = players;
     }
-    /// Returns a boolean indicating whether the game is in normal mode.
-    pub fn is_normal(&self) -> bool {
-        self.normal
+    /// Sets the rotation system used to kick the pieces.
+    ///
+    /// # Arguments
+    ///
+    /// * `rotation_system` - The rotation system to use.
+    pub fn set_rotation_system(&mut self, rotation_system: RotationSystem) {
+        self.rotation_system = rotation_system;
     }
-    /// Returns a boolean indicating whether the game is in blitz mode.
-    pub fn is_blitz(&self) -> bool {
-        self.blitz
+    /// Sets the seed of the random number generators.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - The seed to use.
+    pub fn set_seed(&mut self, seed: u64) {
+        self.seed = Some(seed);
     }
-    /// Returns a boolean indicating whether the game is in 40-lines mode.
-    pub fn is_lines_40(&self) -> bool {
-        self.lines_40
+    /// Returns the seed, choosing a random one first if it wasn't set.
+    pub fn resolve_seed(&mut self) -> u64 {
+        *self.seed.get_or_insert_with(rand::random)
+    }
+    /// Sets the generator used for the pieces of the queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `randomizer` - The type of generator to use.
+    pub fn set_randomizer(&mut self, randomizer: RandomizerType) {
+        self.randomizer = randomizer;
+    }
+    /// Sets how fast the pieces fall in each level.
+    ///
+    /// # Arguments
+    ///
+    /// * `gravity` - The gravity curve to use.
+    pub fn set_gravity(&mut self, gravity: GravityCurve) {
+        self.gravity = gravity;
+    }
+    /// Sets when a piece resting on the floor gets fixed.
+    ///
+    /// # Arguments
+    ///
+    /// * `lock_delay` - The lock delay to use.
+    pub fn set_lock_delay(&mut self, lock_delay: LockDelay) {
+        self.lock_delay = lock_delay;
+    }
+    /// Sets the milliseconds between a piece being fixed and the next one spawning.
+    ///
+    /// # Arguments
+    ///
+    /// * `entry_delay_millis` - The entry delay in milliseconds.
+    pub fn set_entry_delay_millis(&mut self, entry_delay_millis: u64) {
+        self.entry_delay_millis = entry_delay_millis;
+    }
+    /// Sets the milliseconds the completed lines stay on the board before being cleared.
+    ///
+    /// # Arguments
+    ///
+    /// * `line_clear_delay_millis` - The line clear delay in milliseconds.
+    pub fn set_line_clear_delay_millis(&mut self, line_clear_delay_millis: u64) {
+        self.line_clear_delay_millis = line_clear_delay_millis;
+    }
+    /// Sets how the held keys repeat their action.
+    ///
+    /// # Arguments
+    ///
+    /// * `handling` - The handling to use.
+    pub fn set_handling(&mut self, handling: Handling) {
+        self.handling = handling;
+    }
+    /// Sets whether the rotation and hold keys held while a piece spawns act on it.
+    ///
+    /// # Arguments
+    ///
+    /// * `initial_actions` - Whether to enable initial rotation and hold.
+    pub fn set_initial_actions(&mut self, initial_actions: bool) {
+        self.initial_actions = initial_actions;
+    }
+    /// Sets how the locked cells of the board are shown to the player.
+    ///
+    /// # Arguments
+    ///
+    /// * `visibility` - The visibility of the locked cells.
+    pub fn set_visibility(&mut self, visibility: Visibility) {
+        self.visibility = visibility;
+    }
+    /// Sets whether the pieces are played at double scale, on a field of half the width and
+    /// height.
+    ///
+    /// # Arguments
+    ///
+    /// * `big` - Whether to play at double scale.
+    pub fn set_big(&mut self, big: bool) {
+        self.big = big;
+    }
+    /// Returns the mode of the game, with its targets brought into the range it can be played
+    /// with on the field, as the options may come unchecked from the frontend.
+    pub fn mode(&self) -> GameMode {
+        self.mode.clamped(self.field_height())
+    }
+    /// Returns the height of the field the pieces are played on, in cells of the pieces.
+    fn field_height(&self) -> u8 {
+        let scale = if self.big() { BIG_SCALE } else { 1 };
+        (BOARD_HEIGHT / scale) as u8
+    }
+    /// Returns the starting level, goal system and end of a classic game, the default ones
+    /// in the other modes.
+    pub fn marathon(&self) -> Marathon {
+        match self.mode {
+            GameMode::Classic => self.marathon,
+            _ => Marathon::default(),
+        }
     }
     /// Returns the number of players in the game.
     pub fn number_of_players(&self) -> u8 {
         self.number_of_players
     }
+    /// Returns the rotation system used to kick the pieces.
+    pub fn rotation_system(&self) -> RotationSystem {
+        self.rotation_system
+    }
+    /// Returns the seed of the random number generators, if it has been set.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+    /// Returns the generator used for the pieces of the queue.
+    pub fn randomizer(&self) -> RandomizerType {
+        self.randomizer
+    }
+    /// Returns how fast the pieces fall in each level.
+    pub fn gravity(&self) -> &GravityCurve {
+        &self.gravity
+    }
+    /// Returns when a piece resting on the floor gets fixed.
+    pub fn lock_delay(&self) -> LockDelay {
+        self.lock_delay
+    }
+    /// Returns the milliseconds between a piece being fixed and the next one spawning.
+    pub fn entry_delay_millis(&self) -> u64 {
+        self.entry_delay_millis
+    }
+    /// Returns the milliseconds the completed lines stay on the board before being cleared.
+    pub fn line_clear_delay_millis(&self) -> u64 {
+        self.line_clear_delay_millis
+    }
+    /// Returns how the held keys repeat their action.
+    pub fn handling(&self) -> Handling {
+        self.handling
+    }
+    /// Returns whether the rotation and hold keys held while a piece spawns act on it.
+    pub fn initial_actions(&self) -> bool {
+        self.initial_actions
+    }
+    /// Returns how the locked cells of the board are shown to the player.
+    pub fn visibility(&self) -> Visibility {
+        self.visibility
+    }
+    /// Returns whether the pieces are played at double scale, on a field of half the width and
+    /// height. Only games played alone are, as the trash exchanged with other players is made
+    /// for the whole board.
+    pub fn big(&self) -> bool {
+        self.big && self.number_of_players == 1
+    }
 }
@@ -23,3 +23,7 @@ pub mod game_responses;
 pub mod other_player_state;
 
 pub mod won_signal;
+
+pub mod replay;
+
+pub mod saved_game;
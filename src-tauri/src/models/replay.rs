@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    game_commands::{FirstLevelCommands, SecondLevelCommands},
+    game_options::GameOptions,
+};
+
+/// The version of the replay format written by this build.
+pub const REPLAY_VERSION: u32 = 3;
+
+/// `Replay` stores everything needed to reproduce a game: its options and every input received.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Replay {
+    /// The version of the replay format.
+    version: u32,
+    /// The options the game was played with, including the seed of the queue.
+    options: GameOptions,
+    /// The inputs received during the game, in order.
+    inputs: Vec<ReplayInput>,
+}
+
+impl Replay {
+    /// Creates a new empty `Replay` for a game with the given options.
+    pub fn new(options: GameOptions) -> Self {
+        Replay {
+            version: REPLAY_VERSION,
+            options,
+            inputs: Vec::new(),
+        }
+    }
+    /// Records an input.
+    ///
+    /// # Arguments
+    ///
+    /// * `time` - The milliseconds elapsed since the game started.
+    /// * `command` - The command received.
+    pub fn record(&mut self, time: u64, command: ReplayCommand) {
+        self.inputs.push(ReplayInput { time, command });
+    }
+    /// Returns the version of the replay format.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+    /// Returns the options the game was played with.
+    pub fn options(&self) -> &GameOptions {
+        &self.options
+    }
+    /// Returns the seed of the queue, if the game had one.
+    pub fn seed(&self) -> Option<u64> {
+        self.options.seed()
+    }
+    /// Returns the recorded inputs.
+    pub fn inputs(&self) -> &[ReplayInput] {
+        &self.inputs
+    }
+    /// Consumes the replay, returning the recorded inputs.
+    pub fn into_inputs(self) -> Vec<ReplayInput> {
+        self.inputs
+    }
+}
+
+/// `ReplayInput` represents a command received at a given moment of the game.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReplayInput {
+    /// The milliseconds elapsed since the game started.
+    time: u64,
+    /// The command received.
+    command: ReplayCommand,
+}
+
+impl ReplayInput {
+    /// Returns the milliseconds elapsed since the game started.
+    pub fn time(&self) -> u64 {
+        self.time
+    }
+    /// Returns the command received.
+    pub fn command(&self) -> &ReplayCommand {
+        &self.command
+    }
+}
+
+/// `ReplayCommand` represents any of the commands a game can receive.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum ReplayCommand {
+    /// A command triggered by player input.
+    FirstLevel(FirstLevelCommands),
+    /// A command triggered by game logic or network events.
+    SecondLevel(SecondLevelCommands),
+}
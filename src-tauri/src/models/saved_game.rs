@@ -0,0 +1,45 @@
+use serde::Serialize;
+
+/// `SavedGameSummary` represents a saved game as listed to the player, without its state.
+#[derive(Serialize, Debug, Clone, sqlx::FromRow)]
+pub struct SavedGameSummary {
+    /// The unique identifier of the saved game.
+    id: u32,
+    /// The moment the game was saved, in seconds since the UNIX epoch.
+    saved_at: i64,
+    /// The type of game (e.g., "classic", "lines", "blitz").
+    game_type: String,
+    /// The points of the player when the game was saved.
+    points: u32,
+    /// The level of the game when the game was saved.
+    level: u16,
+    /// The time played when the game was saved, in milliseconds.
+    time_endured_millis: u64,
+}
+
+impl SavedGameSummary {
+    /// Returns the ID of the saved game.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+    /// Returns the moment the game was saved, in seconds since the UNIX epoch.
+    pub fn saved_at(&self) -> i64 {
+        self.saved_at
+    }
+    /// Returns the type of the game.
+    pub fn game_type(&self) -> &str {
+        self.game_type.as_ref()
+    }
+    /// Returns the points of the player when the game was saved.
+    pub fn points(&self) -> u32 {
+        self.points
+    }
+    /// Returns the level of the game when the game was saved.
+    pub fn level(&self) -> u16 {
+        self.level
+    }
+    /// Returns the time played when the game was saved, in milliseconds.
+    pub fn time_endured_millis(&self) -> u64 {
+        self.time_endured_millis
+    }
+}
@@ -1,23 +1,57 @@
+use std::path::PathBuf;
+
 use tokio::sync::OnceCell;
 
+use crate::game::game_mode::{
+    DEFAULT_BLITZ_DURATION_SECS, DEFAULT_CHEESE_GOAL, DEFAULT_CHEESE_HEIGHT,
+    DEFAULT_CHEESE_MESSINESS, DEFAULT_LINES_GOAL, GameMode,
+};
+
 pub mod migrations;
 
+pub mod replays;
+
 pub mod store_game_info;
 
 pub mod retreive_game_info;
 
+pub mod saved_games;
+
 static DB_URL: OnceCell<String> = OnceCell::const_new();
 
+static REPLAYS_DIR: OnceCell<PathBuf> = OnceCell::const_new();
+
 /// Name of the games table in the database.
 const GAME_TABLE_NAME: &str = "games";
 /// Name of the classic game info table in the database.
-const CLASSIC_TABLE_NAME: &str = "classic";
+const CLASSIC_TABLE_NAME: &str = GameMode::Classic.table_name();
 /// Name of the blitz game info table in the database.
-const BLITZ_TABLE_NAME: &str = "blitz";
+const BLITZ_TABLE_NAME: &str = GameMode::Blitz {
+    duration_secs: DEFAULT_BLITZ_DURATION_SECS,
+}
+.table_name();
 /// Name of the lines game info table in the database.
-const LINES_TABLE_NAME: &str = "lines";
+const LINES_TABLE_NAME: &str = GameMode::Lines {
+    goal: DEFAULT_LINES_GOAL,
+}
+.table_name();
+/// Name of the cheese race info table in the database.
+const CHEESE_TABLE_NAME: &str = GameMode::Cheese {
+    goal: DEFAULT_CHEESE_GOAL,
+    height: DEFAULT_CHEESE_HEIGHT,
+    messiness: DEFAULT_CHEESE_MESSINESS,
+}
+.table_name();
+/// Name of the survival game info table in the database.
+const SURVIVAL_TABLE_NAME: &str = GameMode::Survival.table_name();
+/// Name of the master game info table in the database.
+const MASTER_TABLE_NAME: &str = GameMode::Master.table_name();
+/// Name of the master section times table in the database.
+const MASTER_SECTIONS_TABLE_NAME: &str = "master_sections";
 /// Name of the game info table in the database.
 const GAME_INFO_TABLE_NAME: &str = "game_info";
+/// Name of the saved games table in the database.
+const SAVED_GAMES_TABLE_NAME: &str = "saved_games";
 
 /// Column name for piece moves in the game info table.
 const GAME_INFO_PIECE_MOVES: &str = "piece_moves";
@@ -47,13 +81,29 @@ const GAME_INFO_TSPINS_TRIPLES: &str = "tspin_triples";
 const GAME_INFO_MINI_TSPINS: &str = "minitspins";
 /// Column name for minitspin singles in the game info table.
 const GAME_INFO_MINI_TSPINS_SINGLES: &str = "minitspin_singles";
+/// Column name for allspins in the game info table.
+const GAME_INFO_ALLSPINS: &str = "allspins";
+/// Column name for allspin singles in the game info table.
+const GAME_INFO_ALLSPINS_SINGLES: &str = "allspin_singles";
+/// Column name for allspin doubles in the game info table.
+const GAME_INFO_ALLSPINS_DOUBLES: &str = "allspin_doubles";
+/// Column name for allspin triples in the game info table.
+const GAME_INFO_ALLSPINS_TRIPLES: &str = "allspin_triples";
+/// Column name for perfect clears in the game info table.
+const GAME_INFO_PERFECT_CLEARS: &str = "perfect_clears";
+/// Column name for max back-to-back in the game info table.
+const GAME_INFO_MAX_BACK_TO_BACK: &str = "max_back_to_back";
+/// Column name for max combo in the game info table.
+const GAME_INFO_MAX_COMBO: &str = "max_combo";
 
-/// Column name for time endured in the classic game info table.
-const CLASSIC_TIME_ENDURED: &str = "time_endured";
+/// Column name for time endured in milliseconds in the classic game info table.
+const CLASSIC_TIME_ENDURED_MILLIS: &str = "time_endured_millis";
 /// Column name for points in the classic game info table.
 const CLASSIC_POINTS: &str = "points";
 /// Column name for level reached in the classic game info table.
 const CLASSIC_LEVEL_REACHED: &str = "level_reached";
+/// Column name for whether the game was won in the classic game info table.
+const CLASSIC_WON: &str = "won";
 /// Column name for game info ID in the classic game info table.
 const CLASSIC_GAME_INFO_ID: &str = "game_info_id";
 
@@ -61,8 +111,52 @@ const CLASSIC_GAME_INFO_ID: &str = "game_info_id";
 const BLITZ_GAME_INFO_ID: &str = "game_info_id";
 /// Column name for points in the blitz game info table.
 const BLITZ_POINTS: &str = "points";
+/// Column name for the duration in seconds in the blitz game info table.
+const BLITZ_DURATION_SECS: &str = "duration_secs";
 
 /// Column name for game info ID in the lines game info table.
 const LINES_GAME_INFO_ID: &str = "game_info_id";
-/// Column name for time endured in the lines game info table.
-const LINES_TIME_ENDURED: &str = "time_endured";
+/// Column name for time endured in milliseconds in the lines game info table.
+const LINES_TIME_ENDURED_MILLIS: &str = "time_endured_millis";
+/// Column name for the lines to clear in the lines game info table.
+const LINES_GOAL: &str = "lines_goal";
+
+/// Column name for game info ID in the cheese race info table.
+const CHEESE_GAME_INFO_ID: &str = "game_info_id";
+/// Column name for time endured in milliseconds in the cheese race info table.
+const CHEESE_TIME_ENDURED_MILLIS: &str = "time_endured_millis";
+/// Column name for the garbage lines to clear in the cheese race info table.
+const CHEESE_GARBAGE_GOAL: &str = "garbage_goal";
+/// Column name for the garbage lines kept on the board in the cheese race info table.
+const CHEESE_HEIGHT: &str = "height";
+/// Column name for the messiness of the garbage in the cheese race info table.
+const CHEESE_MESSINESS: &str = "messiness";
+/// Column name for the garbage lines cleared in the cheese race info table.
+const CHEESE_GARBAGE_CLEARED: &str = "garbage_cleared";
+/// Column name for the pieces used in the cheese race info table.
+const CHEESE_PIECES_USED: &str = "pieces_used";
+
+/// Column name for game info ID in the survival game info table.
+const SURVIVAL_GAME_INFO_ID: &str = "game_info_id";
+/// Column name for time survived in milliseconds in the survival game info table.
+const SURVIVAL_TIME_ENDURED_MILLIS: &str = "time_endured_millis";
+/// Column name for the lines sent in the survival game info table.
+const SURVIVAL_LINES_SENT: &str = "lines_sent";
+
+/// Column name for game info ID in the master game info table.
+const MASTER_GAME_INFO_ID: &str = "game_info_id";
+/// Column name for time endured in milliseconds in the master game info table.
+const MASTER_TIME_ENDURED_MILLIS: &str = "time_endured_millis";
+/// Column name for the level reached in the master game info table.
+const MASTER_LEVEL_REACHED: &str = "level_reached";
+/// Column name for the grade in the master game info table.
+const MASTER_GRADE: &str = "grade";
+/// Column name for points in the master game info table.
+const MASTER_POINTS: &str = "points";
+
+/// Column name for the master game ID in the master section times table.
+const MASTER_SECTIONS_MASTER_ID: &str = "master_id";
+/// Column name for the section in the master section times table.
+const MASTER_SECTIONS_SECTION: &str = "section";
+/// Column name for the time in milliseconds in the master section times table.
+const MASTER_SECTIONS_TIME_MILLIS: &str = "time_millis";
@@ -0,0 +1,73 @@
+use tauri::{AppHandle, Manager};
+use tokio::fs;
+
+use crate::models::replay::{REPLAY_VERSION, Replay};
+
+/// Name of the directory where the replays are stored, inside the application's data directory.
+const REPLAYS_DIR_NAME: &str = "replays";
+/// Extension of the replay files.
+const REPLAY_EXTENSION: &str = "json";
+
+/// Creates the replays directory if it doesn't exist and remembers its path.
+///
+/// # Arguments
+///
+/// * `app` - A Tauri `AppHandle` for accessing the application's data directory.
+pub async fn init_replays_dir(app: AppHandle) {
+    let dir = app.path().app_data_dir().unwrap().join(REPLAYS_DIR_NAME);
+    fs::create_dir_all(&dir).await.unwrap();
+    super::REPLAYS_DIR.set(dir).unwrap();
+}
+
+/// Stores a replay in its own file.
+///
+/// # Arguments
+///
+/// * `replay` - The replay to be stored.
+/// * `name` - The name of the replay, used as the file name.
+pub async fn store_replay(replay: Replay, name: String) {
+    // Games running without the application, like headless ones, have nowhere to store replays
+    let Some(dir) = super::REPLAYS_DIR.get() else {
+        return;
+    };
+    let path = dir.join(name).with_extension(REPLAY_EXTENSION);
+    let content = serde_json::to_string(&replay).unwrap();
+    fs::write(path, content).await.unwrap();
+}
+
+/// Retrieves a stored replay.
+///
+/// Returns `None` if the replay doesn't exist, can't be read or was written with another version of the format.
+///
+/// # Arguments
+///
+/// * `name` - The name of the replay.
+pub async fn retreive_replay(name: &str) -> Option<Replay> {
+    let Some(dir) = super::REPLAYS_DIR.get() else {
+        panic!("REPLAYS_DIR Not set")
+    };
+    let content = fs::read_to_string(dir.join(name).with_extension(REPLAY_EXTENSION))
+        .await
+        .ok()?;
+    let replay: Replay = serde_json::from_str(&content).ok()?;
+    (replay.version() == REPLAY_VERSION).then_some(replay)
+}
+
+/// Retrieves the names of all the stored replays, sorted from oldest to newest.
+pub async fn retreive_replay_names() -> Vec<String> {
+    let Some(dir) = super::REPLAYS_DIR.get() else {
+        panic!("REPLAYS_DIR Not set")
+    };
+    let mut names = vec![];
+    let mut entries = fs::read_dir(dir).await.unwrap();
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if path.extension().is_some_and(|ext| ext == REPLAY_EXTENSION)
+            && let Some(name) = path.file_stem().and_then(|stem| stem.to_str())
+        {
+            names.push(name.to_string());
+        }
+    }
+    names.sort();
+    names
+}
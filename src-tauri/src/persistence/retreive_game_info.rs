@@ -1,11 +1,16 @@
-use crate::models::game_info::{BlitzGameInfo, ClassicGameInfo, GameTypeInfo, LinesGameInfo};
+use crate::game::game_mode::GameMode;
+use crate::game::master::{MASTER_SECTIONS, MasterGrade};
+use crate::models::game_info::{
+    BlitzGameInfo, CheeseGameInfo, ClassicGameInfo, GameTypeInfo, LinesGameInfo, MasterGameInfo,
+    SurvivalGameInfo,
+};
 use crate::models::{
     emit_game_info::EmitGameInfo, game_generic_info::GameGenericInfo, game_info::GameInfo,
 };
 use sqlx::Row;
 use sqlx::{Pool, Sqlite, SqlitePool};
 
-/// Retrieves the last game's information and all results for that game type.
+/// Retrieves the last game's information and all results for that game type and target.
 pub async fn retreive_last_games_info() -> EmitGameInfo {
     let Some(url) = super::DB_URL.get() else {
         panic!("DB_URL Not set")
@@ -23,9 +28,11 @@ pub async fn retreive_last_games_info() -> EmitGameInfo {
     .fetch_one(&pool)
     .await
     .unwrap();
+    let last_result =
+        get_last_result(generic_info.game_id(), generic_info.game_type(), &pool).await;
     EmitGameInfo::new(
-        get_last_result(generic_info.game_id(), generic_info.game_type(), &pool).await,
-        get_all_results(generic_info.game_type(), &pool).await,
+        last_result,
+        get_all_results(last_result.type_of_info().mode(), &pool).await,
     )
 }
 /// Retrieves the last classic game's information.
@@ -40,7 +47,19 @@ pub async fn retreive_last_lines_info() -> EmitGameInfo {
 pub async fn retreive_last_blitz_info() -> EmitGameInfo {
     get_all_info(super::BLITZ_TABLE_NAME).await
 }
-/// Retrieves all information for a specific game type.
+/// Retrieves the last cheese race's information.
+pub async fn retreive_last_cheese_info() -> EmitGameInfo {
+    get_all_info(super::CHEESE_TABLE_NAME).await
+}
+/// Retrieves the last survival game's information.
+pub async fn retreive_last_survival_info() -> EmitGameInfo {
+    get_all_info(super::SURVIVAL_TABLE_NAME).await
+}
+/// Retrieves the last master game's information.
+pub async fn retreive_last_master_info() -> EmitGameInfo {
+    get_all_info(super::MASTER_TABLE_NAME).await
+}
+/// Retrieves the last game's information of a specific game type, and all results with its target.
 async fn get_all_info(type_of_game: &str) -> EmitGameInfo {
     let Some(url) = super::DB_URL.get() else {
         panic!("DB_URL Not set")
@@ -62,13 +81,16 @@ async fn get_all_info(type_of_game: &str) -> EmitGameInfo {
     let Ok(generic_info) = generic_info_result else {
         return EmitGameInfo::empty();
     };
+    let last_result = get_last_result(generic_info.game_id(), type_of_game, &pool).await;
     EmitGameInfo::new(
-        get_last_result(generic_info.game_id(), type_of_game, &pool).await,
-        get_all_results(type_of_game, &pool).await,
+        last_result,
+        get_all_results(last_result.type_of_info().mode(), &pool).await,
     )
 }
-/// Retrieves all results for a specific game type.
-async fn get_all_results(type_of_game: &str, pool: &Pool<Sqlite>) -> Vec<GameInfo> {
+/// Retrieves all results for a specific game mode, only the ones with the same target are
+/// compared with each other.
+async fn get_all_results(mode: GameMode, pool: &Pool<Sqlite>) -> Vec<GameInfo> {
+    let type_of_game = mode.table_name();
     let all_of_type = sqlx::query(
         r#"
         SELECT id_game
@@ -82,18 +104,23 @@ async fn get_all_results(type_of_game: &str, pool: &Pool<Sqlite>) -> Vec<GameInf
     .unwrap();
     let mut vec_of_results = vec![];
     for el in all_of_type {
-        vec_of_results.push(get_last_result(el.get("id_game"), type_of_game, pool).await);
+        let result = get_last_result(el.get("id_game"), type_of_game, pool).await;
+        if result.type_of_info().mode() == mode {
+            vec_of_results.push(result);
+        }
     }
     vec_of_results
 }
 /// Retrieves the last result for a specific game.
 async fn get_last_result(id: u32, type_of_game: &str, pool: &Pool<Sqlite>) -> GameInfo {
-    let specific_info = if type_of_game == super::CLASSIC_TABLE_NAME {
-        get_classic(id, pool).await
-    } else if type_of_game == super::LINES_TABLE_NAME {
-        get_lines(id, pool).await
-    } else {
-        get_blitz(id, pool).await
+    let specific_info = match GameMode::from_table_name(type_of_game) {
+        Some(GameMode::Classic) => get_classic(id, pool).await,
+        Some(GameMode::Lines { .. }) => get_lines(id, pool).await,
+        Some(GameMode::Blitz { .. }) => get_blitz(id, pool).await,
+        Some(GameMode::Cheese { .. }) => get_cheese(id, pool).await,
+        Some(GameMode::Survival) => get_survival(id, pool).await,
+        Some(GameMode::Master) => get_master(id, pool).await,
+        None => panic!("Unknown game type {type_of_game}"),
     };
     let common_info = sqlx::query(&format!(
         r#"SELECT * FROM {} WHERE id = ?1"#,
@@ -118,6 +145,13 @@ async fn get_last_result(id: u32, type_of_game: &str, pool: &Pool<Sqlite>) -> Ga
         common_info.get(super::GAME_INFO_TSPINS_TRIPLES),
         common_info.get(super::GAME_INFO_MINI_TSPINS),
         common_info.get(super::GAME_INFO_MINI_TSPINS_SINGLES),
+        common_info.get(super::GAME_INFO_ALLSPINS),
+        common_info.get(super::GAME_INFO_ALLSPINS_SINGLES),
+        common_info.get(super::GAME_INFO_ALLSPINS_DOUBLES),
+        common_info.get(super::GAME_INFO_ALLSPINS_TRIPLES),
+        common_info.get(super::GAME_INFO_PERFECT_CLEARS),
+        common_info.get(super::GAME_INFO_MAX_BACK_TO_BACK),
+        common_info.get(super::GAME_INFO_MAX_COMBO),
         specific_info.0,
     )
 }
@@ -133,9 +167,10 @@ async fn get_classic(id: u32, pool: &Pool<Sqlite>) -> (GameTypeInfo, i64) {
     .unwrap();
     (
         GameTypeInfo::Classic(ClassicGameInfo::new(
-            info.get(super::CLASSIC_TIME_ENDURED),
+            info.get(super::CLASSIC_TIME_ENDURED_MILLIS),
             info.get(super::CLASSIC_POINTS),
             info.get(super::CLASSIC_LEVEL_REACHED),
+            info.get(super::CLASSIC_WON),
         )),
         info.get(super::CLASSIC_GAME_INFO_ID),
     )
@@ -151,7 +186,10 @@ async fn get_lines(id: u32, pool: &Pool<Sqlite>) -> (GameTypeInfo, i64) {
     .await
     .unwrap();
     (
-        GameTypeInfo::Lines(LinesGameInfo::new(info.get(super::LINES_TIME_ENDURED))),
+        GameTypeInfo::Lines(LinesGameInfo::new(
+            info.get(super::LINES_TIME_ENDURED_MILLIS),
+            info.get(super::LINES_GOAL),
+        )),
         info.get(super::LINES_GAME_INFO_ID),
     )
 }
@@ -166,7 +204,87 @@ async fn get_blitz(id: u32, pool: &Pool<Sqlite>) -> (GameTypeInfo, i64) {
     .await
     .unwrap();
     (
-        GameTypeInfo::Blitz(BlitzGameInfo::new(info.get(super::BLITZ_POINTS))),
+        GameTypeInfo::Blitz(BlitzGameInfo::new(
+            info.get(super::BLITZ_POINTS),
+            info.get(super::BLITZ_DURATION_SECS),
+        )),
         info.get(super::BLITZ_GAME_INFO_ID),
     )
 }
+/// Retrieves the cheese race information.
+async fn get_cheese(id: u32, pool: &Pool<Sqlite>) -> (GameTypeInfo, i64) {
+    let info = sqlx::query(&format!(
+        r#"SELECT * FROM {} WHERE id = ?1"#,
+        super::CHEESE_TABLE_NAME
+    ))
+    .bind(id)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    (
+        GameTypeInfo::Cheese(CheeseGameInfo::new(
+            info.get(super::CHEESE_TIME_ENDURED_MILLIS),
+            info.get(super::CHEESE_GARBAGE_GOAL),
+            info.get(super::CHEESE_HEIGHT),
+            info.get(super::CHEESE_MESSINESS),
+            info.get(super::CHEESE_GARBAGE_CLEARED),
+            info.get(super::CHEESE_PIECES_USED),
+        )),
+        info.get(super::CHEESE_GAME_INFO_ID),
+    )
+}
+/// Retrieves the survival game information.
+async fn get_survival(id: u32, pool: &Pool<Sqlite>) -> (GameTypeInfo, i64) {
+    let info = sqlx::query(&format!(
+        r#"SELECT * FROM {} WHERE id = ?1"#,
+        super::SURVIVAL_TABLE_NAME
+    ))
+    .bind(id)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    (
+        GameTypeInfo::Survival(SurvivalGameInfo::new(
+            info.get(super::SURVIVAL_TIME_ENDURED_MILLIS),
+            info.get(super::SURVIVAL_LINES_SENT),
+        )),
+        info.get(super::SURVIVAL_GAME_INFO_ID),
+    )
+}
+/// Retrieves the master game information, with the time of each section completed.
+async fn get_master(id: u32, pool: &Pool<Sqlite>) -> (GameTypeInfo, i64) {
+    let info = sqlx::query(&format!(
+        r#"SELECT * FROM {} WHERE id = ?1"#,
+        super::MASTER_TABLE_NAME
+    ))
+    .bind(id)
+    .fetch_one(pool)
+    .await
+    .unwrap();
+    let sections = sqlx::query(&format!(
+        r#"SELECT * FROM {} WHERE {} = ?1"#,
+        super::MASTER_SECTIONS_TABLE_NAME,
+        super::MASTER_SECTIONS_MASTER_ID
+    ))
+    .bind(id)
+    .fetch_all(pool)
+    .await
+    .unwrap();
+    let mut section_times_millis = [0; MASTER_SECTIONS];
+    for section in sections {
+        let index: u32 = section.get(super::MASTER_SECTIONS_SECTION);
+        let time_millis: i64 = section.get(super::MASTER_SECTIONS_TIME_MILLIS);
+        section_times_millis[index as usize] = time_millis as u64;
+    }
+    let grade: String = info.get(super::MASTER_GRADE);
+    (
+        GameTypeInfo::Master(MasterGameInfo::new(
+            info.get(super::MASTER_TIME_ENDURED_MILLIS),
+            info.get(super::MASTER_LEVEL_REACHED),
+            MasterGrade::from_name(&grade).unwrap(),
+            info.get(super::MASTER_POINTS),
+            section_times_millis,
+        )),
+        info.get(super::MASTER_GAME_INFO_ID),
+    )
+}
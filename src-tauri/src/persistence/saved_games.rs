@@ -0,0 +1,108 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use sqlx::{Row, SqlitePool};
+
+use crate::{
+    game::game_types::local_game::{GAME_SNAPSHOT_VERSION, GameSnapshot},
+    models::saved_game::SavedGameSummary,
+};
+
+use super::SAVED_GAMES_TABLE_NAME;
+
+/// Stores an in-progress game so it can be resumed later.
+///
+/// # Arguments
+///
+/// * `snapshot` - The state of the game to be stored.
+pub async fn store_saved_game(snapshot: GameSnapshot) {
+    // Games running without the application, like headless ones, have nowhere to store the game
+    let Some(url) = super::DB_URL.get() else {
+        return;
+    };
+    let pool = SqlitePool::connect(url).await.unwrap();
+    let game_type = snapshot.options().mode().table_name();
+    let saved_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards 🗿🤙")
+        .as_secs() as i64;
+    sqlx::query(&format!(
+        r#"
+        INSERT INTO {} (saved_at, game_type, points, level, time_endured_millis, snapshot)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+        "#,
+        SAVED_GAMES_TABLE_NAME
+    ))
+    .bind(saved_at)
+    .bind(game_type)
+    .bind(snapshot.points())
+    .bind(snapshot.level())
+    .bind(snapshot.elapsed_millis() as i64)
+    .bind(serde_json::to_string(&snapshot).unwrap())
+    .execute(&pool)
+    .await
+    .unwrap();
+}
+
+/// Retrieves the summaries of all the saved games, from newest to oldest.
+pub async fn retreive_saved_games() -> Vec<SavedGameSummary> {
+    let Some(url) = super::DB_URL.get() else {
+        panic!("DB_URL Not set")
+    };
+    let pool = SqlitePool::connect(url).await.unwrap();
+    sqlx::query_as::<_, SavedGameSummary>(&format!(
+        r#"
+        SELECT id, saved_at, game_type, points, level, time_endured_millis
+        FROM {}
+        ORDER BY id DESC
+        "#,
+        SAVED_GAMES_TABLE_NAME
+    ))
+    .fetch_all(&pool)
+    .await
+    .unwrap()
+}
+
+/// Retrieves a saved game and removes it, so it can only be resumed once.
+///
+/// Returns `None` if the game doesn't exist, was saved with another version of the format or
+/// has no seed to rebuild its queue with. Only the games returned are removed.
+///
+/// # Arguments
+///
+/// * `id` - The ID of the saved game.
+pub async fn take_saved_game(id: u32) -> Option<GameSnapshot> {
+    let Some(url) = super::DB_URL.get() else {
+        panic!("DB_URL Not set")
+    };
+    let pool = SqlitePool::connect(url).await.unwrap();
+    let row = sqlx::query(&format!(
+        r#"
+        SELECT snapshot
+        FROM {}
+        WHERE id = ?1
+        "#,
+        SAVED_GAMES_TABLE_NAME
+    ))
+    .bind(id)
+    .fetch_optional(&pool)
+    .await
+    .unwrap()?;
+    let content: String = row.get("snapshot");
+    let snapshot = serde_json::from_str::<GameSnapshot>(&content)
+        .ok()
+        .filter(|snapshot| {
+            snapshot.version() == GAME_SNAPSHOT_VERSION && snapshot.options().seed().is_some()
+        })?;
+    sqlx::query(&format!(
+        r#"
+        DELETE FROM {}
+        WHERE id = ?1
+        "#,
+        SAVED_GAMES_TABLE_NAME
+    ))
+    .bind(id)
+    .execute(&pool)
+    .await
+    .unwrap();
+    Some(snapshot)
+}
@@ -1,8 +1,11 @@
 use sqlx::{Pool, Sqlite, SqlitePool};
 
-use crate::models::game_info::{self, BlitzGameInfo, ClassicGameInfo, GameInfo, LinesGameInfo};
+use crate::models::game_info::{
+    self, BlitzGameInfo, CheeseGameInfo, ClassicGameInfo, GameInfo, LinesGameInfo, MasterGameInfo,
+    SurvivalGameInfo,
+};
 
-use super::{BLITZ_TABLE_NAME, CLASSIC_TABLE_NAME, GAME_INFO_TABLE_NAME, LINES_TABLE_NAME};
+use super::GAME_INFO_TABLE_NAME;
 
 /// Stores the game information in the database.
 ///
@@ -12,8 +15,9 @@ use super::{BLITZ_TABLE_NAME, CLASSIC_TABLE_NAME, GAME_INFO_TABLE_NAME, LINES_TA
 ///
 /// * `info` - A `GameInfo` struct containing the information to be stored.
 pub async fn store_game_info(info: GameInfo) {
+    // Games running without the application, like headless ones, have nowhere to store the info
     let Some(url) = super::DB_URL.get() else {
-        panic!("DB_URL Not set")
+        return;
     };
     let pool = SqlitePool::connect(url).await.unwrap();
 
@@ -23,8 +27,10 @@ pub async fn store_game_info(info: GameInfo) {
             piece_moves, spins, lines_cleared, pieces_used,
             singles, doubles, triples, tetrises, tspins,
             tspin_singles, tspin_doubles, tspin_triples,
-            minitspins, minitspin_singles
-        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+            minitspins, minitspin_singles, allspins,
+            allspin_singles, allspin_doubles, allspin_triples,
+            perfect_clears, max_back_to_back, max_combo
+        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
         "#,
         GAME_INFO_TABLE_NAME
     ))
@@ -42,22 +48,35 @@ pub async fn store_game_info(info: GameInfo) {
     .bind(info.tspin_triples())
     .bind(info.minitspins())
     .bind(info.minitspin_singles())
+    .bind(info.allspins())
+    .bind(info.allspin_singles())
+    .bind(info.allspin_doubles())
+    .bind(info.allspin_triples())
+    .bind(info.perfect_clears())
+    .bind(info.max_back_to_back())
+    .bind(info.max_combo())
     .execute(&pool)
     .await
     .unwrap();
-    let type_of_game = match info.type_of_info() {
-        game_info::GameTypeInfo::Classic(type_info) => (
-            store_classic(&pool, type_info, result.last_insert_rowid()).await,
-            CLASSIC_TABLE_NAME,
-        ),
-        game_info::GameTypeInfo::Lines(type_info) => (
-            store_lines(&pool, type_info, result.last_insert_rowid()).await,
-            LINES_TABLE_NAME,
-        ),
-        game_info::GameTypeInfo::Blitz(type_info) => (
-            store_blitz(&pool, type_info, result.last_insert_rowid()).await,
-            BLITZ_TABLE_NAME,
-        ),
+    let id_game = match info.type_of_info() {
+        game_info::GameTypeInfo::Classic(type_info) => {
+            store_classic(&pool, type_info, result.last_insert_rowid()).await
+        }
+        game_info::GameTypeInfo::Lines(type_info) => {
+            store_lines(&pool, type_info, result.last_insert_rowid()).await
+        }
+        game_info::GameTypeInfo::Blitz(type_info) => {
+            store_blitz(&pool, type_info, result.last_insert_rowid()).await
+        }
+        game_info::GameTypeInfo::Cheese(type_info) => {
+            store_cheese(&pool, type_info, result.last_insert_rowid()).await
+        }
+        game_info::GameTypeInfo::Survival(type_info) => {
+            store_survival(&pool, type_info, result.last_insert_rowid()).await
+        }
+        game_info::GameTypeInfo::Master(type_info) => {
+            store_master(&pool, type_info, result.last_insert_rowid()).await
+        }
     };
     sqlx::query(
         r#"
@@ -65,8 +84,8 @@ pub async fn store_game_info(info: GameInfo) {
     VALUES (?1, ?2)
     "#,
     )
-    .bind(type_of_game.1)
-    .bind(type_of_game.0)
+    .bind(info.type_of_info().mode().table_name())
+    .bind(id_game)
     .execute(&pool)
     .await
     .unwrap();
@@ -82,13 +101,17 @@ pub async fn store_game_info(info: GameInfo) {
 async fn store_classic(pool: &Pool<Sqlite>, type_info: ClassicGameInfo, id: i64) -> i64 {
     sqlx::query(
         r#"
-            INSERT INTO classic (time_endured, points, level_reached, game_info_id)
-            VALUES (?1, ?2, ?3, ?4)
+            INSERT INTO classic (
+                time_endured, time_endured_millis, points, level_reached, won, game_info_id
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
             "#,
     )
+    .bind(type_info.time_endured() / 1000)
     .bind(type_info.time_endured())
     .bind(type_info.points())
     .bind(type_info.level_reached())
+    .bind(type_info.won())
     .bind(id)
     .execute(pool)
     .await
@@ -105,11 +128,13 @@ async fn store_classic(pool: &Pool<Sqlite>, type_info: ClassicGameInfo, id: i64)
 async fn store_lines(pool: &Pool<Sqlite>, type_info: LinesGameInfo, id: i64) -> i64 {
     sqlx::query(
         r#"
-            INSERT INTO lines (time_endured, game_info_id)
-            VALUES (?1, ?2)
+            INSERT INTO lines (time_endured, time_endured_millis, lines_goal, game_info_id)
+            VALUES (?1, ?2, ?3, ?4)
             "#,
     )
+    .bind(type_info.time_endured() / 1000)
     .bind(type_info.time_endured())
+    .bind(type_info.lines_goal())
     .bind(id)
     .execute(pool)
     .await
@@ -126,14 +151,108 @@ async fn store_lines(pool: &Pool<Sqlite>, type_info: LinesGameInfo, id: i64) ->
 async fn store_blitz(pool: &Pool<Sqlite>, type_info: BlitzGameInfo, id: i64) -> i64 {
     sqlx::query(
         r#"
-            INSERT INTO blitz (points, game_info_id)
-            VALUES (?1, ?2)
+            INSERT INTO blitz (points, duration_secs, game_info_id)
+            VALUES (?1, ?2, ?3)
             "#,
     )
     .bind(type_info.points())
+    .bind(type_info.duration_secs() as i64)
+    .bind(id)
+    .execute(pool)
+    .await
+    .unwrap()
+    .last_insert_rowid()
+}
+/// Stores cheese race information in the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the SQLite connection pool.
+/// * `type_info` - A `CheeseGameInfo` struct containing the information to be stored.
+/// * `id` - The ID of the associated `GameInfo` record.
+async fn store_cheese(pool: &Pool<Sqlite>, type_info: CheeseGameInfo, id: i64) -> i64 {
+    sqlx::query(
+        r#"
+            INSERT INTO cheese (
+                time_endured_millis, garbage_goal, height, messiness,
+                garbage_cleared, pieces_used, game_info_id
+            )
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            "#,
+    )
+    .bind(type_info.time_endured())
+    .bind(type_info.garbage_goal())
+    .bind(type_info.height())
+    .bind(type_info.messiness())
+    .bind(type_info.garbage_cleared())
+    .bind(type_info.pieces_used())
     .bind(id)
     .execute(pool)
     .await
     .unwrap()
     .last_insert_rowid()
 }
+/// Stores survival game information in the database.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the SQLite connection pool.
+/// * `type_info` - A `SurvivalGameInfo` struct containing the information to be stored.
+/// * `id` - The ID of the associated `GameInfo` record.
+async fn store_survival(pool: &Pool<Sqlite>, type_info: SurvivalGameInfo, id: i64) -> i64 {
+    sqlx::query(
+        r#"
+            INSERT INTO survival (time_endured_millis, lines_sent, game_info_id)
+            VALUES (?1, ?2, ?3)
+            "#,
+    )
+    .bind(type_info.time_endured())
+    .bind(type_info.lines_sent())
+    .bind(id)
+    .execute(pool)
+    .await
+    .unwrap()
+    .last_insert_rowid()
+}
+/// Stores master game information in the database, with the time of each section completed.
+///
+/// # Arguments
+///
+/// * `pool` - A reference to the SQLite connection pool.
+/// * `type_info` - A `MasterGameInfo` struct containing the information to be stored.
+/// * `id` - The ID of the associated `GameInfo` record.
+async fn store_master(pool: &Pool<Sqlite>, type_info: MasterGameInfo, id: i64) -> i64 {
+    let master_id = sqlx::query(
+        r#"
+            INSERT INTO master (time_endured_millis, level_reached, grade, points, game_info_id)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            "#,
+    )
+    .bind(type_info.time_endured())
+    .bind(type_info.level_reached())
+    .bind(type_info.grade().name())
+    .bind(type_info.points())
+    .bind(id)
+    .execute(pool)
+    .await
+    .unwrap()
+    .last_insert_rowid();
+    for (section, time_millis) in type_info.section_times_millis().into_iter().enumerate() {
+        if time_millis == 0 {
+            continue;
+        }
+        sqlx::query(
+            r#"
+            INSERT INTO master_sections (section, time_millis, master_id)
+            VALUES (?1, ?2, ?3)
+            "#,
+        )
+        .bind(section as u32)
+        .bind(time_millis as i64)
+        .bind(master_id)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+    master_id
+}
@@ -4,7 +4,6 @@ use std::{
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
-use tauri::{AppHandle, Emitter};
 use tokio::{
     io::AsyncWriteExt,
     net::TcpStream,
@@ -15,6 +14,7 @@ use tokio::{
 };
 
 use crate::{
+    events::EventSink,
     game::game_types::client_online_game::ClientOnlineGame,
     globals::PING_LIMIT_IN_SECONDS,
     helpers::room_net_helpers::{read_enum_from_server, send_enum_from_client},
@@ -33,8 +33,8 @@ const GAME_STARTED_EMIT: &str = "gameStartedEmit";
 pub struct ClientRoom {
     /// The TCP stream for communication with the server.
     stream: Arc<Mutex<TcpStream>>,
-    /// Tauri application handle for emitting events.
-    app: AppHandle,
+    /// Sink for emitting events.
+    events: Arc<dyn EventSink>,
     /// Receiver for stop signals.
     stop_channel: broadcast::Receiver<bool>,
     /// The player's information.
@@ -51,18 +51,18 @@ impl ClientRoom {
     /// # Arguments
     ///
     /// * `stream` - The TCP stream for communication with the server.
-    /// * `app` - Tauri application handle for emitting events.
+    /// * `events` - Sink for emitting events.
     /// * `stop_channel` - Receiver for stop signals.
     /// * `player` - The player's information.
     pub fn new(
         stream: Arc<Mutex<TcpStream>>,
-        app: AppHandle,
+        events: Arc<dyn EventSink>,
         stop_channel: broadcast::Receiver<bool>,
         player: DummyPlayer,
     ) -> Self {
         Self {
             stream,
-            app,
+            events,
             stop_channel,
             player,
             listening: true,
@@ -93,7 +93,7 @@ impl ClientRoom {
                             let mut error = command.unwrap_err();
                             loop {
                                 if self.handle_error(error) {
-                                    let _ = self.app.emit(LOST_CONNECTION_EMIT, false);
+                                    self.events.emit(LOST_CONNECTION_EMIT, false);
                                     self.listening = false;
                                     break;
                                 } else {
@@ -123,7 +123,7 @@ impl ClientRoom {
                     .expect("Time went backwards 🗿🤙")
                     .as_secs();
                 if cur_time - time > PING_LIMIT_IN_SECONDS {
-                    let _ = self.app.emit(LOST_CONNECTION_EMIT, false);
+                    self.events.emit(LOST_CONNECTION_EMIT, false);
                     break;
                 }
             } else {
@@ -147,26 +147,26 @@ impl ClientRoom {
             ServerRoomNetCommands::JoinRoomRequestAccepted(_) => (),
             ServerRoomNetCommands::JoinRoomRequestRejected(_) => (),
             ServerRoomNetCommands::PlayersUpdate(dummy_players) => {
-                let _ = self.app.emit(PLAYERS_EMIT, dummy_players);
+                self.events.emit(PLAYERS_EMIT, dummy_players);
             }
             ServerRoomNetCommands::RoomClosed(_) => {
-                self.app.emit(ROOM_CLOSED_EMIT, false).unwrap();
+                self.events.emit(ROOM_CLOSED_EMIT, false);
                 self.listening = false;
             }
             ServerRoomNetCommands::PingRequest(_) => {
                 self.listening = !self.ping().await;
             }
             ServerRoomNetCommands::DisconnectedSignal(_) => {
-                let _ = self.app.emit(LOST_CONNECTION_EMIT, false);
+                self.events.emit(LOST_CONNECTION_EMIT, false);
                 self.listening = false;
             }
             ServerRoomNetCommands::GameStarts((delay, pieces, options, id)) => {
-                let _ = self.app.emit(GAME_STARTED_EMIT, id);
+                self.events.emit(GAME_STARTED_EMIT, id);
                 let mut game = ClientOnlineGame::new(
                     self.stream.clone(),
                     pieces,
                     options,
-                    self.app.clone(),
+                    self.events.clone(),
                     delay,
                     self.playing.clone(),
                     self.player.clone(),
@@ -203,7 +203,7 @@ impl ClientRoom {
                 | std::io::ErrorKind::TimedOut
                 | std::io::ErrorKind::Interrupted
                 | std::io::ErrorKind::UnexpectedEof => {
-                    let _ = self.app.emit(LOST_CONNECTION_EMIT, false);
+                    self.events.emit(LOST_CONNECTION_EMIT, false);
                     return true;
                 }
                 _ => (),
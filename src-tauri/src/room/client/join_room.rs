@@ -1,9 +1,9 @@
 use std::sync::Arc;
 
-use tauri::{AppHandle, Emitter};
 use tokio::{io::AsyncWriteExt, net::TcpStream, sync::Mutex};
 
 use crate::{
+    events::EventSink,
     globals::LISTENING_PORT_TCP_SERVER,
     helpers::room_net_helpers::read_enum_from_server,
     models::{
@@ -23,7 +23,7 @@ const CONNECTION_REJECTED: &str = "connection_rejected";
 ///
 /// * `room` - The `RoomInfo` of the room to join.
 /// * `player` - The `DummyPlayer` representing the player joining the room.
-/// * `app` - A reference to the Tauri `AppHandle` for emitting events.
+/// * `events` - Sink for emitting events.
 ///
 /// # Returns
 ///
@@ -32,12 +32,12 @@ const CONNECTION_REJECTED: &str = "connection_rejected";
 pub async fn join_room(
     room: RoomInfo,
     player: DummyPlayer,
-    app: &AppHandle,
+    events: &Arc<dyn EventSink>,
 ) -> Option<(DummyRoom, Arc<Mutex<TcpStream>>)> {
     let Ok(mut tcp_socket) =
         TcpStream::connect(format!("{}:{}", room.ip(), LISTENING_PORT_TCP_SERVER)).await
     else {
-        let _ = app.emit(CONNECTION_ERROR, false);
+        events.emit(CONNECTION_ERROR, false);
         return None;
     };
     let Ok(request) = serde_json::to_vec(&ClientRoomNetCommands::JoinRoomRequest(player)) else {
@@ -47,20 +47,20 @@ pub async fn join_room(
     let result = tcp_socket.write_all(&request).await;
 
     let Ok(_) = result else {
-        let _ = app.emit(CONNECTION_ERROR, false);
+        events.emit(CONNECTION_ERROR, false);
         return None;
     };
     let _ = tcp_socket.flush().await;
 
     let stream = Arc::new(Mutex::new(tcp_socket));
     let Ok(command) = read_enum_from_server(&stream).await else {
-        let _ = app.emit(CONNECTION_ERROR, false);
+        events.emit(CONNECTION_ERROR, false);
         return None;
     };
     if let ServerRoomNetCommands::JoinRoomRequestAccepted(dummy_room) = command {
         Some((dummy_room, stream))
     } else if let ServerRoomNetCommands::JoinRoomRequestRejected(reject_reason) = command {
-        let _ = app.emit(CONNECTION_REJECTED, reject_reason);
+        events.emit(CONNECTION_REJECTED, reject_reason);
         None
     } else {
         None
@@ -1,9 +1,8 @@
-use std::{net::SocketAddr, time::Duration};
+use std::{net::SocketAddr, sync::Arc, time::Duration};
 use tokio::net::UdpSocket;
 
-use tauri::{AppHandle, Emitter};
-
 use crate::{
+    events::EventSink,
     globals::{DUMMY_SEND_BROADCAST, LISTEN_BROADCAST_RESPONSE, SENDING_BROADCAST, SIZE_FOR_KB},
     models::{
         room_commands::client::ClientRoomNetCommands, room_commands::server::ServerRoomNetCommands,
@@ -24,9 +23,12 @@ const ROOM_UPDATES_EVENT: &str = "room-updates";
 ///
 /// # Arguments
 ///
-/// * `app` - A Tauri `AppHandle` for emitting events.
+/// * `events` - Sink for emitting events.
 /// * `channel` - A channel for receiving a signal to stop listening.
-pub async fn listen_to_rooms(app: AppHandle, mut channel: tokio::sync::mpsc::Receiver<bool>) {
+pub async fn listen_to_rooms(
+    events: Arc<dyn EventSink>,
+    mut channel: tokio::sync::mpsc::Receiver<bool>,
+) {
     let send_socket = UdpSocket::bind(DUMMY_SEND_BROADCAST)
         .await
         .expect("Check error");
@@ -86,6 +88,6 @@ pub async fn listen_to_rooms(app: AppHandle, mut channel: tokio::sync::mpsc::Rec
                 }
             }
         }
-        app.emit(ROOM_UPDATES_EVENT, rooms).unwrap();
+        events.emit(ROOM_UPDATES_EVENT, rooms);
     }
 }
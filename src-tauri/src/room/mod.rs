@@ -6,12 +6,12 @@ use serde::{Deserialize, Serialize};
 use server::listen_to_broadcast_requests::listen_to_request;
 use server::listen_to_room_requests::listen_to_room_requests;
 use server::room_player_listener::RoomPlayerListener;
-use tauri::{AppHandle, Emitter};
 use tokio::net::TcpStream;
 use tokio::sync::mpsc::{Receiver, Sender};
 use tokio::sync::{Mutex, broadcast};
 use tokio::time::Instant;
 
+use crate::events::EventSink;
 use crate::game::game_types::online_game::OnlineGame;
 use crate::game::pieces::Piece;
 use crate::game::queue::Queue;
@@ -41,8 +41,8 @@ pub struct Room {
     limit_of_players: u8,
     /// The number of games played in the room.
     games_played: u16,
-    /// Tauri application handle for emitting events.
-    app: AppHandle,
+    /// Sink for emitting events.
+    events: Arc<dyn EventSink>,
     /// Receiver for closing the room.
     close_room: Receiver<bool>,
     /// Receiver for commands sent to the room.
@@ -65,7 +65,7 @@ impl Room {
     /// # Arguments
     ///
     /// * `name` - The name of the room.
-    /// * `app` - Tauri application handle for emitting events.
+    /// * `events` - Sink for emitting events.
     /// * `close_room` - Receiver for closing the room.
     /// * `stop_listening_channel` - Receiver for stopping the broadcast listener.
     /// * `player_name` - The name of the local player.
@@ -73,7 +73,7 @@ impl Room {
     /// * `receiver_commands` - Receiver for receiving commands in the room.
     pub async fn new(
         name: String,
-        app: AppHandle,
+        events: Arc<dyn EventSink>,
         close_room: Receiver<bool>,
         stop_listening_channel: broadcast::Receiver<bool>,
         player_name: String,
@@ -91,7 +91,7 @@ impl Room {
             name,
             limit_of_players: players_limit,
             games_played: 0,
-            app: app.clone(),
+            events: events.clone(),
             close_room,
             receive_commands: receiver_commands,
             send_commands: sender_commands.clone(),
@@ -102,7 +102,7 @@ impl Room {
         };
         listen_to_request(
             (&info).into(),
-            app,
+            events,
             stop_listening_channel.resubscribe(),
             players_info.clone(),
         );
@@ -192,7 +192,7 @@ impl Room {
             self.players.iter().map(|player| player.into()).collect();
         let local_player = &self.local_player;
         players.push(local_player.into());
-        self.app.emit(PLAYERS_EMIT, players).unwrap();
+        self.events.emit(PLAYERS_EMIT, players);
     }
     /// Sends a player update to all players in the room.
     fn players_update(&self) {
@@ -272,20 +272,25 @@ impl Room {
                 highest_ping = player.ping();
             }
         });
-        let mut queue = LocalQueue::default();
+        // Unless the host chose a seed, each round deals a new sequence, so the seed is only set
+        // on the options of the round
+        let seed = self.options.seed().unwrap_or_else(rand::random);
+        let mut queue = LocalQueue::new(seed, self.options.randomizer());
         for i in 0..PIECES_TO_GENERATE {
             let _ = queue.get_piece(i);
         }
         let pieces = queue.get_pieces();
         self.options.multi_player((self.players.len() + 1) as u8);
+        let mut options = self.options.clone();
+        options.set_seed(seed);
         self.send_updates
-            .send(Updates::GameStarts((highest_ping, self.options, pieces)))
+            .send(Updates::GameStarts((highest_ping, options, pieces)))
             .unwrap();
         tokio::time::sleep(Duration::from_millis(highest_ping)).await;
         let mut online_game = OnlineGame::new(
             self.players.clone(),
             self.cur_game_playing.clone(),
-            self.app.clone(),
+            self.events.clone(),
             highest_ping,
             queue,
             (&self.local_player).into(),
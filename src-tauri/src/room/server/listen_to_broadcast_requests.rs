@@ -1,12 +1,12 @@
 use std::{sync::Arc, time::Duration};
 
-use tauri::{AppHandle, Emitter};
 use tokio::{
     net::UdpSocket,
     sync::{Mutex, broadcast},
 };
 
 use crate::{
+    events::EventSink,
     globals::{LISTENING_DIRECTION_BROADCAST, LISTENING_RESPONSE_PORT_BROADCAST, SIZE_FOR_KB},
     models::{
         room_commands::client::ClientRoomNetCommands, room_commands::server::ServerRoomNetCommands,
@@ -24,18 +24,18 @@ const ERROR_OPENING_CONNECTION_EMIT: &str = "error_opening_connection";
 /// # Arguments
 ///
 /// * `info` - The `RoomInfo` to respond with.
-/// * `app` - A Tauri `AppHandle` for emitting events.
+/// * `events` - Sink for emitting events.
 /// * `stop_channel` - A channel for receiving a signal to stop listening.
 /// * `players_num` - An `Arc<Mutex<u8>>` containing the number of players in the room.
 pub fn listen_to_request(
     mut info: RoomInfo,
-    app: AppHandle,
+    events: Arc<dyn EventSink>,
     mut stop_channel: broadcast::Receiver<bool>,
     players_num: Arc<Mutex<u8>>,
 ) {
     tokio::spawn(async move {
         let Ok(socket) = UdpSocket::bind(LISTENING_DIRECTION_BROADCAST).await else {
-            finish_listening(&app);
+            finish_listening(&events);
             return;
         };
         socket
@@ -49,7 +49,7 @@ pub fn listen_to_request(
                 },
                 socket_info = socket.recv_from(&mut data) => {
                     let Ok((valid_bytes, mut addr)) = socket_info else {
-                        finish_listening(&app);
+                        finish_listening(&events);
                         return;
                     };
                     let deserialized: Result<ClientRoomNetCommands, serde_json::Error> =
@@ -83,7 +83,7 @@ pub fn listen_to_request(
 ///
 /// # Arguments
 ///
-/// * `app` - A Tauri `AppHandle` for emitting events.
-fn finish_listening(app: &AppHandle) {
-    let _ = app.emit(ERROR_OPENING_CONNECTION_EMIT, false);
+/// * `events` - Sink for emitting events.
+fn finish_listening(events: &Arc<dyn EventSink>) {
+    events.emit(ERROR_OPENING_CONNECTION_EMIT, false);
 }
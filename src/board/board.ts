@@ -6,13 +6,16 @@ import {
   getTPieceDarkColor, getZPieceColor, getZPieceDarkColor,
   trashColor,
   trashBorderColor,
-  getGhostColor
+  getGhostColor,
+  getMonochromeColor,
+  getMonochromeDarkColor
 } from "./colors";
 import { invoke } from "@tauri-apps/api/core";
 import type { GameOptions } from "../types/GameOptions";
 import { lineClearedEffect, lostEffect, pieceFixedEffect } from "./effects";
 import type { ClearLinePattern } from "../types/ClearLinePattern";
 import { removeInputListeners } from "../controls/keyboard";
+import { getHandling } from "../controls/interval";
 import { UnlistenFn } from "@tauri-apps/api/event";
 import { Piece } from "../types/Piece";
 import { router } from "../router";
@@ -20,6 +23,10 @@ import { todo } from "node:test";
 import { OtherPlayerState, Player, WonSignal } from "../types/Room";
 
 const canvasHeight = 760;
+/**
+ * The opacity of the faded locked cells, sent by the backend in lowercase.
+ */
+const fadedAlpha = 0.35;
 const canvasWidth = 380;
 
 const columnNumber = 10;
@@ -39,6 +46,7 @@ const gameOverEmit = "game_over";
 const gameWonEmit = "game_won";
 const lineClearedInfoEmit = "line_cleared_info";
 const timeEmit = "time_emit";
+const gradeEmit = "grade";
 
 const stateEmitForOtherPlayers = "stateEmitForOtherPlayers";
 const otherPlayerLostEmit = "otherPlayerLostEmit";
@@ -78,6 +86,8 @@ export default function startDraw(canvas: HTMLCanvasElement, secondCanvas: HTMLC
   drawLines(ctx);
   startBoardChangeEventListener();
   if (options.number_of_players == 1) {
+    options.handling = getHandling();
+    options.initial_actions = true;
     invoke("start_game", {
       options: options
     });
@@ -89,17 +99,24 @@ export default function startDraw(canvas: HTMLCanvasElement, secondCanvas: HTMLC
   gameLost();
   lineCleared();
   pieceFixedEvent();
-  if (options.normal || options.lines_40) {
+  if (typeof options.mode === "string" || !("Blitz" in options.mode)) {
     lineClearedInfo();
   } else {
     pointsInfo();
   }
-  if (!options.normal || options.number_of_players > 1) {
+  if (
+    options.mode !== "Classic" ||
+    options.number_of_players > 1 ||
+    (options.marathon !== undefined && options.marathon.end !== "Endless")
+  ) {
     gameWon();
   }
-  if (!options.normal) {
+  if (options.mode !== "Classic") {
     timer();
   }
+  if (options.mode === "Master") {
+    gradeInfo();
+  }
 }
 
 /**
@@ -137,30 +154,43 @@ function drawBoardInternal(board: string, ctx: CanvasRenderingContext2D, drawLDi
       continue;
     const y = Math.floor(i / columnNumber);
     const x = i % columnNumber;
-    if (piece == Piece.Trash) {
-      trashPiece(ctx, x, y);
-      continue;
-    }
-    if (piece == Piece.Ghost) {
-      ghostPiece(ctx, x, y);
-      continue;
-    }
-    const color = getColor(piece);
-    const darkColor = getDarkColor(piece);
-    ctx.strokeStyle = color;
-    ctx.lineWidth = 8;
-    const widthSecondRing = 5;
-    ctx.strokeRect(widthSecondRing + (pieceWidth * x), widthSecondRing + (pieceWidth * y), pieceWidth - (widthSecondRing * 2), pieceHeight - (widthSecondRing * 2));
-    ctx.strokeStyle = darkColor;
-    ctx.lineWidth = 2;
-    ctx.strokeRect(1 + (pieceWidth * x), 1 + (pieceHeight * y), pieceWidth - 1, pieceHeight - 1);
-    ctx.strokeStyle = darkColor;
-    ctx.lineWidth = 2;
-    ctx.strokeRect(10 + (pieceWidth * x), 10 + (pieceHeight * y), pieceWidth / 2, pieceHeight / 2);
+    ctx.globalAlpha = piece == piece.toUpperCase() ? 1 : fadedAlpha;
+    drawCell(ctx, piece.toUpperCase() as Piece, x, y);
+  }
+  ctx.globalAlpha = 1;
+}
 
-    ctx.fillStyle = color;
-    ctx.fillRect(11 + (pieceWidth * x), 11 + (pieceHeight * y), pieceWidth / 3 + 4, pieceHeight / 3 + 4);
+/**
+ * Draws a cell of the board on the canvas.
+ * @param ctx The canvas rendering context.
+ * @param piece The piece of the cell.
+ * @param x The x coordinate of the cell.
+ * @param y The y coordinate of the cell.
+ */
+function drawCell(ctx: CanvasRenderingContext2D, piece: Piece, x: number, y: number) {
+  if (piece == Piece.Trash) {
+    trashPiece(ctx, x, y);
+    return;
+  }
+  if (piece == Piece.Ghost) {
+    ghostPiece(ctx, x, y);
+    return;
   }
+  const color = getColor(piece);
+  const darkColor = getDarkColor(piece);
+  ctx.strokeStyle = color;
+  ctx.lineWidth = 8;
+  const widthSecondRing = 5;
+  ctx.strokeRect(widthSecondRing + (pieceWidth * x), widthSecondRing + (pieceWidth * y), pieceWidth - (widthSecondRing * 2), pieceHeight - (widthSecondRing * 2));
+  ctx.strokeStyle = darkColor;
+  ctx.lineWidth = 2;
+  ctx.strokeRect(1 + (pieceWidth * x), 1 + (pieceHeight * y), pieceWidth - 1, pieceHeight - 1);
+  ctx.strokeStyle = darkColor;
+  ctx.lineWidth = 2;
+  ctx.strokeRect(10 + (pieceWidth * x), 10 + (pieceHeight * y), pieceWidth / 2, pieceHeight / 2);
+
+  ctx.fillStyle = color;
+  ctx.fillRect(11 + (pieceWidth * x), 11 + (pieceHeight * y), pieceWidth / 3 + 4, pieceHeight / 3 + 4);
 }
 
 
@@ -216,6 +246,8 @@ function getColor(piece: string): string {
     return getSPieceColor();
   else if (piece == "Z")
     return getZPieceColor();
+  else if (piece == "M")
+    return getMonochromeColor();
   throw new Error("Invalid Value");
 }
 
@@ -241,6 +273,8 @@ function getDarkColor(piece: string): string {
     return getSPieceDarkColor();
   else if (piece == "Z")
     return getZPieceDarkColor();
+  else if (piece == "M")
+    return getMonochromeDarkColor();
   throw new Error("Invalid Value");
 }
 
@@ -391,6 +425,16 @@ async function pointsInfo() {
   }));
 }
 
+/**
+ * Listens for the grade event of a master game.
+ */
+async function gradeInfo() {
+  unlisteners.push(await listen(gradeEmit, (e) => {
+    const $grade = document.getElementById("write-grade") as HTMLElement;
+    $grade.innerText = e.payload as string;
+  }));
+}
+
 /**
  * Listens for the timer event.
  */
@@ -63,6 +63,22 @@ export function trashColor(): string {
     return "#303030";
 }
 
+/**
+ * Gets the fill color for the locked cells drawn with a single colour.
+ * @returns The monochrome color as a string.
+ */
+export function getMonochromeColor(): string {
+    return "#b0b0b0";
+}
+
+/**
+ * Gets the dark color for the locked cells drawn with a single colour.
+ * @returns The monochrome dark color as a string.
+ */
+export function getMonochromeDarkColor(): string {
+    return "#707070";
+}
+
 /**
  * Gets the color for ghost pieces.
  * @returns The ghost color as a string.
@@ -1,4 +1,8 @@
 import { getRepeatIntervalStored, getStartRepeatIntervalStored } from "../helpers/intervalsStoring";
+import type { Handling } from "../types/Handling";
+
+const defaultDasCutDelay: number = 0;
+const defaultSoftDropFactor: number = 20;
 
 /**
  * Gets the start repeat interval from storage.
@@ -15,3 +19,16 @@ export function getStartRepeatInterval(): number {
 export function getRepeatInterval(): number {
     return getRepeatIntervalStored();
 }
+
+/**
+ * Gets the handling the game repeats the held keys with.
+ * @returns The handling built from the stored intervals.
+ */
+export function getHandling(): Handling {
+    return {
+        das_millis: getStartRepeatInterval(),
+        arr_millis: getRepeatInterval(),
+        dcd_millis: defaultDasCutDelay,
+        soft_drop_factor: defaultSoftDropFactor,
+    };
+}
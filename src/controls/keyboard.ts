@@ -1,27 +1,22 @@
 import { invoke } from "@tauri-apps/api/core";
-import { getRepeatInterval, getStartRepeatInterval } from "./interval";
 import { hardDropEffect } from "../board/effects";
-import { getClockwiseCode, getCounterClockwiseCode, getForfeitCode, getFullRotationCode, getHardDropCode, getLeftMoveCode, getRetryCode, getRightMoveCode, getSavePieceCode, getSoftDropCode, getTargetingEliminationsCode, getTargetingEvenCode, getTargetingPaybackCode, getTargetingRandomCode } from "./keycodes";
+import { getClockwiseCode, getCounterClockwiseCode, getForfeitCode, getFullRotationCode, getHardDropCode, getLeftMoveCode, getPauseCode, getRetryCode, getRightMoveCode, getSavePieceCode, getSoftDropCode, getTargetingEliminationsCode, getTargetingEvenCode, getTargetingPaybackCode, getTargetingRandomCode } from "./keycodes";
 import { currentGameOptions } from "../board/board";
 
-let customRepeatInterval = getRepeatInterval();
-let customStartRepeatInterval = getStartRepeatInterval();
-let keyIntervals: Record<string, NodeJS.Timeout> = {};
-const keySet = new Set<string>(); // Repeat interval
+const keySet = new Set<string>(); // Held state tracked by the backend
 const pressedSet = new Set<string>(); // Not repeat
+let paused = false;
 
 /**
  * Manages the keyboard input listeners for the game.
  *
  * This function sets up the keydown and keyup event listeners to handle user input.
- * It also initializes the keyIntervals, keySet, and pressedSet variables.
+ * It also initializes the keySet and pressedSet variables.
  */
 export default function manageInputListeners() {
-  keyIntervals = {};
   keySet.clear();
   pressedSet.clear();
-  customRepeatInterval = getRepeatInterval();
-  customStartRepeatInterval = getStartRepeatInterval();
+  paused = false;
   // Handle keydown event
   document.addEventListener("keydown", keyDown);
 
@@ -36,13 +31,13 @@ export default function manageInputListeners() {
 export function removeInputListeners() {
   document.removeEventListener("keyup", keyUp);
   document.removeEventListener("keydown", keyDown);
-  removeIntervals();
+  releaseHeldKeys();
 }
 /**
  * Handles the keyup event.
  *
  * This function is called when a key is released. It removes the key from the pressedSet and keySet,
- * and clears any intervals associated with the key.
+ * and tells the backend to stop repeating the held keys.
  * @param event The keyboard event.
  */
 function keyUp(event: KeyboardEvent) {
@@ -51,20 +46,22 @@ function keyUp(event: KeyboardEvent) {
     return;
   }
 
-  if (event.key != getLeftMoveCode() && event.key != getRightMoveCode() && event.key != getSoftDropCode()) {
+  const heldKey = getHeldKey(event.key);
+  if (heldKey == null) {
     return;
   }
 
   if (keySet.has(event.key)) {
     keySet.delete(event.key);
-    removeIntervals();
+    invoke("key_released", { key: heldKey });
   }
 }
 /**
  * Handles the keydown event.
  *
- * This function is called when a key is pressed. It adds the key to the keySet and pressedSet,
- * and sets up an interval to repeatedly call the manageInput function while the key is held down.
+ * This function is called when a key is pressed. It adds the key to the keySet or pressedSet.
+ * The keys whose held state matters are sent to the backend, which runs DAS and ARR with its own clock
+ * and applies the initial rotation and hold.
  * @param event The keyboard event.
  */
 function keyDown(event: KeyboardEvent) {
@@ -75,41 +72,53 @@ function keyDown(event: KeyboardEvent) {
     return;
   }
 
-  manageInput(event.key);
-  if (event.key == getHardDropCode()) {
-    removeIntervals();
-  }
-  if (event.key != getLeftMoveCode() && event.key != getRightMoveCode() && event.key != getSoftDropCode()) {
-    pressedSet.add(event.key);
+  const heldKey = getHeldKey(event.key);
+  if (heldKey != null) {
+    keySet.add(event.key);
+    invoke("key_pressed", { key: heldKey });
     return;
   }
 
-
-  keySet.add(event.key);
-
-
-  setTimeout(() => {
-    if (keySet.has(event.key) && !keyIntervals.hasOwnProperty(event.key)) {
-      keyIntervals[event.key] = setInterval(() => {
-        manageInput(event.key);
-      }, customRepeatInterval);
-    }
-  }, customStartRepeatInterval);
+  manageInput(event.key);
+  pressedSet.add(event.key);
 }
 
 /**
- * Removes all active intervals.
+ * Gets the backend name of a key whose held state is tracked by the backend.
+ * @param keyCode The key code of the key.
+ * @returns The name of the held key, or null if the key isn't tracked.
  */
-function removeIntervals() {
-  for (const interval in keyIntervals) {
-    if (Object.prototype.hasOwnProperty.call(keyIntervals, interval)) {
-      const element = keyIntervals[interval];
-      clearInterval(element);
-      delete keyIntervals[interval];
-    }
+function getHeldKey(keyCode: string): string | null {
+  switch (keyCode) {
+    case getLeftMoveCode():
+      return "Left";
+    case getRightMoveCode():
+      return "Right";
+    case getSoftDropCode():
+      return "SoftDrop";
+    case getClockwiseCode():
+      return "ClockWiseRotation";
+    case getCounterClockwiseCode():
+      return "CounterClockWiseRotation";
+    case getFullRotationCode():
+      return "FullRotation";
+    case getSavePieceCode():
+      return "SavePiece";
+    default:
+      return null;
   }
 }
 
+/**
+ * Tells the backend to stop repeating all the held keys.
+ */
+function releaseHeldKeys() {
+  keySet.forEach(keyCode => {
+    invoke("key_released", { key: getHeldKey(keyCode) });
+  });
+  keySet.clear();
+}
+
 /**
  * Manages the input based on the key code.
  * @param keyCode The key code of the key that was pressed.
@@ -119,27 +128,6 @@ function manageInput(keyCode: string) {
     case getHardDropCode():
       hardDrop();
       break;
-    case getLeftMoveCode():
-      leftMove();
-      break;
-    case getRightMoveCode():
-      rightMove();
-      break;
-    case getSavePieceCode():
-      savePiece();
-      break;
-    case getSoftDropCode():
-      softDrop();
-      break;
-    case getCounterClockwiseCode():
-      counterClockWise();
-      break;
-    case getFullRotationCode():
-      fullRotation();
-      break;
-    case getClockwiseCode():
-      clockwise();
-      break;
     case getTargetingRandomCode():
       targetingRandom();
       break;
@@ -158,33 +146,28 @@ function manageInput(keyCode: string) {
     case getRetryCode():
       retryGame();
       break;
+    case getPauseCode():
+      togglePause();
+      break;
   }
 }
 
-async function clockwise() {
-  await invoke("clockwise_rotation");
-}
-
-async function counterClockWise() {
-  await invoke("counter_clockwise_rotation");
-}
-
 async function forfeit() {
   await invoke("forfeit_game")
   removeInputListeners();
 }
 
-async function fullRotation() {
-  await invoke("full_rotation");
-}
-
 async function hardDrop() {
   await invoke("hard_drop");
   hardDropEffect();
 }
 
-async function leftMove() {
-  await invoke("left_move");
+async function togglePause() {
+  if (currentGameOptions.number_of_players > 1) {
+    return;
+  }
+  paused = !paused;
+  await invoke(paused ? "pause_game" : "resume_game");
 }
 
 async function retryGame() {
@@ -201,18 +184,6 @@ async function retryGame() {
 
 }
 
-async function rightMove() {
-  await invoke("right_move");
-}
-
-async function savePiece() {
-  await invoke("save_piece");
-}
-
-async function softDrop() {
-  await invoke("soft_drop");
-}
-
 async function targetingEliminations() {
   await invoke("targeting_strategy_eliminations");
   changeStrategy(2);
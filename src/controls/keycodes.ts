@@ -12,6 +12,7 @@ export const eliminationCodeKey = "eliminations";
 export const paybackCodeKey = "payback";
 export const forfeitCodeKey = "forfeit";
 export const retryCodeKey = "retry";
+export const pauseCodeKey = "pause";
 const hardDropCodeDefault = "ArrowDown";
 const softDropCodeDefault = " ";
 const leftMoveCodeDefault = "ArrowLeft";
@@ -26,6 +27,7 @@ const eliminationCodeDefault = "3";
 const paybackCodeDefault = "4";
 const forfeitCodeDefault = "Escape";
 const retryCodeDefault = "p";
+const pauseCodeDefault = "Enter";
 
 let hardDropCode = "";
 let softDropCode = "";
@@ -41,6 +43,7 @@ let targetingEliminationsCode = "";
 let targetingPaybackCode = "";
 let forfeitCode = "";
 let retryCode = "";
+let pauseCode = "";
 
 /**
  * Gets the hard drop key code.
@@ -140,6 +143,13 @@ export function getForfeitCode(): string {
 export function getRetryCode(): string {
   return check(retryCode, retryCodeKey, retryCodeDefault);
 }
+/**
+ * Gets the pause action key code.
+ * @returns The pause action key code.
+ */
+export function getPauseCode(): string {
+  return check(pauseCode, pauseCodeKey, pauseCodeDefault);
+}
 
 export function isValid(action: string, key: string): boolean {
   if (getHardDropCode() == key && action != hardDropCodeKey) {
@@ -184,6 +194,9 @@ export function isValid(action: string, key: string): boolean {
   if (getForfeitCode() == key && action != forfeitCodeKey) {
     return false
   }
+  if (getPauseCode() == key && action != pauseCodeKey) {
+    return false
+  }
   return true;
 }
 
@@ -1,14 +1,17 @@
 /**
- * Formats a number of seconds into a HH:MM:SS string.
- * @param secondsMark The number of seconds to format.
+ * Formats a number of seconds into a HH:MM:SS.mmm string.
+ * @param secondsMark The number of seconds to format, with their fractional part.
  * @returns The formatted time string.
  */
 export function formatSecondsToHHMMSS(secondsMark: number): string {
-    const hours = Math.floor(secondsMark / 3600);
-    const minutes = Math.floor((secondsMark % 3600) / 60);
-    const seconds = secondsMark % 60;
+    const totalMillis = Math.round(secondsMark * 1000);
+    const totalSeconds = Math.floor(totalMillis / 1000);
+    const hours = Math.floor(totalSeconds / 3600);
+    const minutes = Math.floor((totalSeconds % 3600) / 60);
+    const seconds = totalSeconds % 60;
+    const millis = totalMillis % 1000;
 
-    const pad = (n: number) => n.toString().padStart(2, "0");
+    const pad = (n: number, length = 2) => n.toString().padStart(length, "0");
 
-    return `${pad(hours)}:${pad(minutes)}:${pad(seconds)}`;
+    return `${pad(hours)}:${pad(minutes)}:${pad(seconds)}.${pad(millis, 3)}`;
 }
\ No newline at end of file
@@ -29,6 +29,9 @@ const routes = [
     { path: '/classic', component: Board },
     { path: '/lines', component: Board },
     { path: '/blitz', component: Board },
+    { path: '/cheese', component: Board },
+    { path: '/survival', component: Board },
+    { path: '/master', component: Board },
     { path: '/stats', component: Stats },
     { path: '/again', component: Again },
     { path: '/internet', component: Internet },
@@ -93,14 +93,17 @@ export interface GameInfo {
 export type GameTypeInfo =
     | { Classic: ClassicGameInfo }
     | { Lines: LinesGameInfo }
-    | { Blitz: BlitzGameInfo };
+    | { Blitz: BlitzGameInfo }
+    | { Cheese: CheeseGameInfo }
+    | { Survival: SurvivalGameInfo }
+    | { Master: MasterGameInfo };
 
 /**
  * `ClassicGameInfo` provides details specific to a classic Tetris game.
  */
 export interface ClassicGameInfo {
     /**
-     * Time endured in the game (in seconds).
+     * Time endured in the game (in seconds, with milliseconds as the fractional part).
      */
     time_endured: number;
     /**
@@ -114,13 +117,17 @@ export interface ClassicGameInfo {
 }
 
 /**
- * `LinesGameInfo` provides details specific to a 40-lines Tetris game.
+ * `LinesGameInfo` provides details specific to a lines (sprint) Tetris game.
  */
 export interface LinesGameInfo {
     /**
-     * Time endured in the game (in seconds).
+     * Time endured in the game (in seconds, with milliseconds as the fractional part).
      */
     time_endured: number;
+    /**
+     * Lines to clear in the game.
+     */
+    lines_goal: number;
 }
 
 /**
@@ -131,6 +138,77 @@ export interface BlitzGameInfo {
      * Points scored in the game.
      */
     points: number;
+    /**
+     * Seconds the game lasted.
+     */
+    duration_secs: number;
+}
+/**
+ * `CheeseGameInfo` provides details specific to a cheese race.
+ */
+export interface CheeseGameInfo {
+    /**
+     * Time endured in the game (in seconds, with milliseconds as the fractional part).
+     */
+    time_endured: number;
+    /**
+     * Garbage lines to clear in the game.
+     */
+    garbage_goal: number;
+    /**
+     * Garbage lines kept on the board.
+     */
+    height: number;
+    /**
+     * Percentage of garbage lines whose hole moves.
+     */
+    messiness: number;
+    /**
+     * Garbage lines cleared in the game.
+     */
+    garbage_cleared: number;
+    /**
+     * Pieces used to clear the garbage lines.
+     */
+    pieces_used: number;
+}
+/**
+ * `SurvivalGameInfo` provides details specific to a survival game.
+ */
+export interface SurvivalGameInfo {
+    /**
+     * Time survived in the game (in seconds, with milliseconds as the fractional part).
+     */
+    time_endured: number;
+    /**
+     * Lines sent back by the clears, countering the rising garbage.
+     */
+    lines_sent: number;
+}
+/**
+ * `MasterGameInfo` provides details specific to a master game.
+ */
+export interface MasterGameInfo {
+    /**
+     * Time endured in the game (in seconds, with milliseconds as the fractional part).
+     */
+    time_endured: number;
+    /**
+     * Level reached during the game, from 0 to 999.
+     */
+    level_reached: number;
+    /**
+     * Grade awarded in the game, from "9" to "1", then "S1" to "S9" and "GM".
+     */
+    grade: string;
+    /**
+     * Points scored in the game.
+     */
+    points: number;
+    /**
+     * Milliseconds each section of 100 levels took, 0 for the sections not completed.
+     */
+    section_times_millis: number[];
 }
 export function isClassic(info: GameTypeInfo): info is { Classic: ClassicGameInfo } {
     return "Classic" in info;
@@ -143,3 +221,15 @@ export function isLines(info: GameTypeInfo): info is { Lines: LinesGameInfo } {
 export function isBlitz(info: GameTypeInfo): info is { Blitz: BlitzGameInfo } {
     return "Blitz" in info;
 }
+
+export function isCheese(info: GameTypeInfo): info is { Cheese: CheeseGameInfo } {
+    return "Cheese" in info;
+}
+
+export function isSurvival(info: GameTypeInfo): info is { Survival: SurvivalGameInfo } {
+    return "Survival" in info;
+}
+
+export function isMaster(info: GameTypeInfo): info is { Master: MasterGameInfo } {
+    return "Master" in info;
+}
@@ -0,0 +1,35 @@
+/**
+ * `GameMode` defines the rules of a single-player game, used for communication with the backend.
+ */
+export type GameMode =
+    | "Classic"
+    | {
+        /**
+         * A game won once the goal of lines has been cleared (sprint).
+         */
+        Lines: { goal: number }
+    }
+    | {
+        /**
+         * A game won once its duration has passed, scoring as many points as possible (ultra).
+         */
+        Blitz: { duration_secs: number }
+    }
+    | {
+        /**
+         * A game won once the goal of garbage lines has been cleared, with the garbage refilled
+         * up to its height (cheese race). The messiness is the percentage of garbage lines whose
+         * hole moves.
+         */
+        Cheese: { goal: number; height: number; messiness: number }
+    }
+    /**
+     * A game in which garbage lines rise on a schedule that speeds up over time, lasting until
+     * the player tops out.
+     */
+    | "Survival"
+    /**
+     * A game in which the level advances with each piece and each line up to 999, in sections
+     * that bring the gravity up to 20G and shorten the delays, graded on the points scored.
+     */
+    | "Master";
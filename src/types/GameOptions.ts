@@ -1,3 +1,8 @@
+import type { GameMode } from "./GameMode";
+import type { Handling } from "./Handling";
+import type { Marathon } from "./Marathon";
+import type { Visibility } from "./Visibility";
+
 /**
  * `GameOptions` defines the options for a game session, used for communication with the backend.
  */
@@ -7,15 +12,29 @@ export type GameOptions =  {
      */
     number_of_players: number,
     /**
-     * Indicates whether the game is in 40-lines mode.
+     * The mode of the game.
      */
-    lines_40: boolean
+    mode: GameMode
     /**
-     * Indicates whether the game is in blitz mode.
+     * The starting level, goal system and end of a classic game, the backend defaults are
+     * used if missing.
      */
-    blitz: boolean
+    marathon?: Marathon
     /**
-     * Indicates whether the game is in normal mode.
+     * How the held keys repeat their action, the backend defaults are used if missing.
      */
-    normal: boolean
+    handling?: Handling
+    /**
+     * Indicates whether the rotation and hold keys held while a piece spawns act on it.
+     */
+    initial_actions?: boolean
+    /**
+     * How the locked cells of the board are shown, all of them if missing.
+     */
+    visibility?: Visibility
+    /**
+     * Indicates whether the pieces are played at double scale, on a field of half the width
+     * and height.
+     */
+    big?: boolean
 };
@@ -0,0 +1,21 @@
+/**
+ * `Handling` defines how the held keys repeat their action, used for communication with the backend.
+ */
+export type Handling = {
+    /**
+     * Milliseconds a direction has to be held before it starts repeating (DAS).
+     */
+    das_millis: number,
+    /**
+     * Milliseconds between the repeated shifts, with 0 the piece shifts to the wall (ARR).
+     */
+    arr_millis: number,
+    /**
+     * Milliseconds the repetition waits after a rotation or a new piece (DCD).
+     */
+    dcd_millis: number,
+    /**
+     * How many times faster than gravity the piece falls while soft drop is held.
+     */
+    soft_drop_factor: number
+};
@@ -0,0 +1,19 @@
+/**
+ * `Marathon` defines the options of a classic game, used for communication with the backend.
+ */
+export type Marathon = {
+    /**
+     * The level the game starts at.
+     */
+    starting_level: number,
+    /**
+     * How the lines needed to level up are counted: five times the level in lines awarded
+     * ("Variable") or ten lines cleared ("Fixed").
+     */
+    goal: "Variable" | "Fixed",
+    /**
+     * When the game is won: never ("Endless"), with 150 lines or level 15 completed
+     * ("Lines150"), or with 200 lines ("Lines200").
+     */
+    end: "Endless" | "Lines150" | "Lines200"
+};
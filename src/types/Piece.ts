@@ -11,5 +11,6 @@ export enum Piece {
     Z = "Z",
     Ghost = "G",
     Trash = "R",
+    Monochrome = "M",
     Empty = "E"
 };
@@ -0,0 +1,9 @@
+/**
+ * `Visibility` defines how the locked cells of the board are shown, used for communication
+ * with the backend.
+ */
+export type Visibility =
+    | "Visible"
+    | { Invisible: { delay_millis: number } }
+    | { Fading: { duration_millis: number } }
+    | "Monochrome";